@@ -100,6 +100,8 @@ impl ThreadTracker {
 
 pub struct MemoryTracker {
     memory_usage: AtomicI64,
+    // The high-water mark of memory_usage.
+    peak_memory_usage: AtomicI64,
     parent_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
@@ -108,12 +110,15 @@ impl MemoryTracker {
         Arc::new(MemoryTracker {
             parent_memory_tracker,
             memory_usage: AtomicI64::new(0),
+            peak_memory_usage: AtomicI64::new(0),
         })
     }
 
     #[inline]
     pub fn alloc_memory(&self, size: i64) {
-        self.memory_usage.fetch_add(size, Ordering::Relaxed);
+        let memory_usage = self.memory_usage.fetch_add(size, Ordering::Relaxed) + size;
+        self.peak_memory_usage
+            .fetch_max(memory_usage, Ordering::Relaxed);
 
         if let Some(parent_memory_tracker) = &self.parent_memory_tracker {
             parent_memory_tracker.alloc_memory(size);
@@ -144,6 +149,11 @@ impl MemoryTracker {
     pub fn get_memory_usage(&self) -> i64 {
         self.memory_usage.load(Ordering::Relaxed)
     }
+
+    #[inline]
+    pub fn get_peak_memory_usage(&self) -> i64 {
+        self.peak_memory_usage.load(Ordering::Relaxed)
+    }
 }
 
 pub struct RuntimeTracker {
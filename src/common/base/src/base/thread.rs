@@ -14,6 +14,7 @@
 
 use std::thread::Builder;
 use std::thread::JoinHandle;
+use std::time::Duration;
 
 use super::runtime_tracker::ThreadTracker;
 
@@ -51,4 +52,22 @@ impl Thread {
     {
         Self::named_spawn(None, f)
     }
+
+    /// The CPU time consumed by the current thread.
+    #[cfg(unix)]
+    pub fn cpu_time() -> Duration {
+        let mut tp = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // Safety: `tp` is a valid timespec, CLOCK_THREAD_CPUTIME_ID is always supported.
+        unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut tp) };
+        Duration::new(tp.tv_sec as u64, tp.tv_nsec as u32)
+    }
+
+    /// The CPU time consumed by the current thread, not supported on this platform.
+    #[cfg(not(unix))]
+    pub fn cpu_time() -> Duration {
+        Duration::ZERO
+    }
 }
@@ -212,4 +212,8 @@ pub struct TableStatistics {
     pub data_size: Option<u64>,
     pub data_size_compressed: Option<u64>,
     pub index_size: Option<u64>,
+    /// The segments and blocks of the current snapshot, 0 if the table has no data yet,
+    /// None if they are unknown.
+    pub number_of_segments: Option<u64>,
+    pub number_of_blocks: Option<u64>,
 }
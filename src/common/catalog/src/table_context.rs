@@ -14,6 +14,7 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
@@ -46,6 +47,8 @@ use crate::table::Table;
 pub struct ProcessInfo {
     pub id: String,
     pub typ: String,
+    /// The protocol handler of the session, None for the internal sessions.
+    pub handler: Option<String>,
     pub state: String,
     pub database: String,
     pub user: Option<UserInfo>,
@@ -53,9 +56,20 @@ pub struct ProcessInfo {
     pub client_address: Option<SocketAddr>,
     pub session_extra_info: Option<String>,
     pub memory_usage: i64,
+    /// The peak memory usage of the running query, None if the session is idle.
+    pub peak_memory_usage: Option<i64>,
+    /// The thread CPU time the executors spent on the running query, None if the session is idle
+    /// or the query is not executed by a pipeline executor with `enable_query_profiling`.
+    pub cpu_time_ms: Option<u64>,
     pub dal_metrics: Option<DalMetrics>,
     pub scan_progress_value: Option<ProgressValues>,
+    /// The estimated total of `scan_progress_value`, None if the session is idle.
+    pub total_scan_progress_value: Option<ProgressValues>,
     pub mysql_connection_id: Option<u32>,
+    pub session_started_on: SystemTime,
+    pub last_query_started_on: Option<SystemTime>,
+    /// The SQL text of the running query, or of the last one if the session is idle.
+    pub query: Option<String>,
 }
 
 #[async_trait::async_trait]
@@ -67,6 +81,9 @@ pub trait TableContext: Send + Sync {
     fn build_table_from_source_plan(&self, plan: &ReadDataSourcePlan) -> Result<Arc<dyn Table>>;
     fn get_scan_progress(&self) -> Arc<Progress>;
     fn get_scan_progress_value(&self) -> ProgressValues;
+    /// The estimated total of the scan progress, accumulated from the statistics of the read plans.
+    fn get_total_scan_progress(&self) -> Arc<Progress>;
+    fn get_total_scan_progress_value(&self) -> ProgressValues;
     fn get_write_progress(&self) -> Arc<Progress>;
     fn get_write_progress_value(&self) -> ProgressValues;
     fn get_result_progress(&self) -> Arc<Progress>;
@@ -92,6 +109,8 @@ pub trait TableContext: Send + Sync {
     fn get_config(&self) -> Config;
     fn get_current_user(&self) -> Result<UserInfo>;
     fn set_current_user(&self, user: UserInfo);
+    /// The role the session is acting as, None if it has none.
+    fn get_current_role(&self) -> Option<String>;
     fn get_fuse_version(&self) -> String;
     fn get_changed_settings(&self) -> Arc<Settings>;
     fn apply_changed_settings(&self, changed_settings: Arc<Settings>) -> Result<()>;
@@ -119,4 +138,6 @@ pub trait TableContext: Send + Sync {
     fn get_user_manager(&self) -> Arc<UserApiProvider>;
     fn get_cluster(&self) -> Arc<Cluster>;
     async fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    /// Whether the query has been killed, the sources should stop reading.
+    fn is_aborting(&self) -> bool;
 }
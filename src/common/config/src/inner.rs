@@ -129,6 +129,10 @@ pub struct QueryConfig {
     pub database_engine_github_enabled: bool,
     pub wait_timeout_mills: u64,
     pub max_query_log_size: usize,
+    /// Max number of purges kept in system.vacuum_history
+    pub max_vacuum_history_size: usize,
+    /// Max number of recluster operations kept in system.clustering_history
+    pub max_clustering_history_size: usize,
     /// Table Cached enabled
     pub table_cache_enabled: bool,
     /// Max number of cached table snapshot
@@ -149,6 +153,8 @@ pub struct QueryConfig {
     pub async_insert_max_data_size: u64,
     pub async_insert_busy_timeout: u64,
     pub async_insert_stale_timeout: u64,
+    /// The databases whose objects can not be granted to a share.
+    pub share_protected_databases: Vec<String>,
 }
 
 impl Default for QueryConfig {
@@ -182,6 +188,8 @@ impl Default for QueryConfig {
             database_engine_github_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10000,
+            max_vacuum_history_size: 10000,
+            max_clustering_history_size: 10000,
             table_cache_enabled: false,
             table_cache_snapshot_count: 256,
             table_cache_segment_count: 10240,
@@ -194,6 +202,7 @@ impl Default for QueryConfig {
             async_insert_max_data_size: 10000,
             async_insert_busy_timeout: 200,
             async_insert_stale_timeout: 0,
+            share_protected_databases: vec!["system".to_string()],
         }
     }
 }
@@ -25,9 +25,12 @@
 /// - `From<inner::Config> for outer_v0::Config`
 mod inner;
 mod outer_v0;
+mod redact;
 mod version;
 
 pub use inner::Config;
 pub use inner::QueryConfig;
+pub use redact::is_sensitive_config;
+pub use redact::redact_config_value;
 pub use version::DATABEND_COMMIT_VERSION;
 pub use version::QUERY_SEMVER;
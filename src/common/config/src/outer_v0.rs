@@ -676,6 +676,14 @@ pub struct QueryConfig {
     #[clap(long, default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Max number of purges kept in system.vacuum_history
+    #[clap(long, default_value = "10000")]
+    pub max_vacuum_history_size: usize,
+
+    /// Max number of recluster operations kept in system.clustering_history
+    #[clap(long, default_value = "10000")]
+    pub max_clustering_history_size: usize,
+
     /// Table Cached enabled
     #[clap(long)]
     pub table_cache_enabled: bool,
@@ -722,6 +730,10 @@ pub struct QueryConfig {
     /// The maximum timeout in milliseconds since the last insert before inserting collected data.
     #[clap(long, default_value = "0")]
     pub async_insert_stale_timeout: u64,
+
+    /// The comma separated databases whose objects can not be granted to a share.
+    #[clap(long, default_value = "system")]
+    pub share_protected_databases: String,
 }
 
 impl Default for QueryConfig {
@@ -763,6 +775,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             database_engine_github_enabled: self.database_engine_github_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
+            max_vacuum_history_size: self.max_vacuum_history_size,
+            max_clustering_history_size: self.max_clustering_history_size,
             table_cache_enabled: self.table_cache_enabled,
             table_cache_snapshot_count: self.table_cache_snapshot_count,
             table_cache_segment_count: self.table_cache_segment_count,
@@ -775,6 +789,13 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             async_insert_max_data_size: self.async_insert_max_data_size,
             async_insert_busy_timeout: self.async_insert_busy_timeout,
             async_insert_stale_timeout: self.async_insert_stale_timeout,
+            share_protected_databases: self
+                .share_protected_databases
+                .split(',')
+                .map(|db| db.trim())
+                .filter(|db| !db.is_empty())
+                .map(|db| db.to_string())
+                .collect(),
         })
     }
 }
@@ -816,6 +837,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             database_engine_github_enabled: inner.database_engine_github_enabled,
             wait_timeout_mills: inner.wait_timeout_mills,
             max_query_log_size: inner.max_query_log_size,
+            max_vacuum_history_size: inner.max_vacuum_history_size,
+            max_clustering_history_size: inner.max_clustering_history_size,
             table_cache_enabled: inner.table_cache_enabled,
             table_cache_snapshot_count: inner.table_cache_snapshot_count,
             table_cache_segment_count: inner.table_cache_segment_count,
@@ -828,6 +851,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             async_insert_max_data_size: inner.async_insert_max_data_size,
             async_insert_busy_timeout: inner.async_insert_busy_timeout,
             async_insert_stale_timeout: inner.async_insert_stale_timeout,
+            share_protected_databases: inner.share_protected_databases.join(","),
         }
     }
 }
@@ -0,0 +1,44 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::mask_string;
+
+/// The config names containing one of these are secrets.
+///
+/// The match is by name, so a new secret config is masked as long as it is named like one.
+const SENSITIVE_CONFIG_NAMES: [&str; 7] = [
+    "password",
+    "secret",
+    "credential",
+    "token",
+    "access_key_id",
+    "account_",
+    "master_key",
+];
+
+/// Returns true if the config, named like `s3.secret_access_key`, holds a secret.
+pub fn is_sensitive_config(name: &str) -> bool {
+    let name = name.to_lowercase();
+    SENSITIVE_CONFIG_NAMES
+        .iter()
+        .any(|sensitive| name.contains(sensitive))
+}
+
+/// Returns the value of the config to show, the secrets are masked.
+pub fn redact_config_value(name: &str, value: &str) -> String {
+    match is_sensitive_config(name) {
+        true => mask_string(value, 3),
+        false => value.to_string(),
+    }
+}
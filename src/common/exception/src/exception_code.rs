@@ -229,6 +229,14 @@ build_exceptions! {
     UnknownShareAccounts(2709),
     WrongShareObject(2710),
     WrongShare(2711),
+    ShareCycleDetected(2712),
+    ShareDatabaseNotGranted(2713),
+    ShareObjectDatabaseMismatch(2714),
+    CannotShareDroppedObject(2715),
+    CannotShareView(2716),
+    InvalidSharePrivilege(2717),
+    CannotShareProtectedObject(2718),
+    ShareFrozen(2719),
 
     // Variable error codes.
     UnknownVariable(2801),
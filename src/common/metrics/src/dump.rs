@@ -18,7 +18,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use metrics_exporter_prometheus::PrometheusHandle;
 
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct MetricSample {
     pub name: String,
     pub kind: String,
@@ -26,7 +26,7 @@ pub struct MetricSample {
     pub value: MetricValue,
 }
 
-#[derive(Debug, PartialEq, serde::Serialize)]
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum MetricValue {
     Counter(f64),
     Gauge(f64),
@@ -75,13 +75,13 @@ impl From<prometheus_parse::Value> for MetricValue {
     }
 }
 
-#[derive(Debug, PartialEq, serde::Serialize)]
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct HistogramCount {
     pub less_than: f64,
     pub count: f64,
 }
 
-#[derive(Debug, PartialEq, serde::Serialize)]
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct SummaryCount {
     pub quantile: f64,
     pub count: f64,
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicPtr;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
@@ -219,6 +221,10 @@ impl InputPort {
 pub struct OutputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    // Rows and bytes of the data blocks pushed through the port, only counted if profiling.
+    profiling: AtomicBool,
+    pushed_rows: AtomicUsize,
+    pushed_bytes: AtomicUsize,
 }
 
 impl OutputPort {
@@ -226,6 +232,9 @@ impl OutputPort {
         Arc::new(OutputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            profiling: AtomicBool::new(false),
+            pushed_rows: AtomicUsize::new(0),
+            pushed_bytes: AtomicUsize::new(0),
         })
     }
 
@@ -234,6 +243,15 @@ impl OutputPort {
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
 
+            if self.profiling.load(Ordering::Relaxed) {
+                if let Ok(block) = &data {
+                    self.pushed_rows
+                        .fetch_add(block.num_rows(), Ordering::Relaxed);
+                    self.pushed_bytes
+                        .fetch_add(block.memory_size(), Ordering::Relaxed);
+                }
+            }
+
             let data = Box::into_raw(Box::new(SharedData::Data(data)));
             self.shared.swap(data, HAS_DATA, HAS_DATA);
         }
@@ -271,6 +289,19 @@ impl OutputPort {
         ((flags & NEED_DATA) == NEED_DATA) && ((flags & HAS_DATA) == 0)
     }
 
+    /// Start counting the rows and bytes pushed through the port.
+    pub fn enable_profiling(&self) {
+        self.profiling.store(true, Ordering::Relaxed);
+    }
+
+    pub fn pushed_rows(&self) -> usize {
+        self.pushed_rows.load(Ordering::Relaxed)
+    }
+
+    pub fn pushed_bytes(&self) -> usize {
+        self.pushed_bytes.load(Ordering::Relaxed)
+    }
+
     /// # Safety
     ///
     /// Method is thread unsafe and require thread safe call
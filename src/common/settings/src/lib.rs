@@ -251,6 +251,41 @@ impl Settings {
                 desc: "SQL dialect, support \"PostgreSQL\" and \"MySQL\", default value: \"PostgreSQL\"",
                 possible_values: Some(vec!["PostgreSQL", "MySQL"]),
             },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("tracing_cluster", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Whether to read the logs of all the cluster nodes in system.tracing, default value: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("metrics_cluster", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Whether to read the metrics of all the cluster nodes in system.metrics, default value: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("metrics_cluster_sum_counters", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Whether to sum the counters of all the cluster nodes in system.metrics, default value: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create("enable_query_profiling", DataValue::UInt64(0)),
+                level: ScopeLevel::Session,
+                desc: "Whether to collect the operator profiles into system.query_profile, default value: 0",
+                possible_values: None,
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(1024),
+                user_setting: UserSetting::create("max_process_query_length", DataValue::UInt64(1024)),
+                level: ScopeLevel::Session,
+                desc: "The maximum length of the query text in system.processes, default value: 1024",
+                possible_values: None,
+            },
         ];
 
         let settings = Arc::new(RwLock::new(HashMap::default()));
@@ -414,6 +449,64 @@ impl Settings {
         self.try_set_u64(key, val, false)
     }
 
+    pub fn get_tracing_cluster(&self) -> Result<bool> {
+        static KEY: &str = "tracing_cluster";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn set_tracing_cluster(&self, val: bool) -> Result<()> {
+        static KEY: &str = "tracing_cluster";
+        let v = if val { 1 } else { 0 };
+        self.try_set_u64(KEY, v, false)
+    }
+
+    pub fn get_metrics_cluster(&self) -> Result<bool> {
+        static KEY: &str = "metrics_cluster";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn set_metrics_cluster(&self, val: bool) -> Result<()> {
+        static KEY: &str = "metrics_cluster";
+        let v = if val { 1 } else { 0 };
+        self.try_set_u64(KEY, v, false)
+    }
+
+    pub fn get_metrics_cluster_sum_counters(&self) -> Result<bool> {
+        static KEY: &str = "metrics_cluster_sum_counters";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn set_metrics_cluster_sum_counters(&self, val: bool) -> Result<()> {
+        static KEY: &str = "metrics_cluster_sum_counters";
+        let v = if val { 1 } else { 0 };
+        self.try_set_u64(KEY, v, false)
+    }
+
+    pub fn get_enable_query_profiling(&self) -> Result<bool> {
+        static KEY: &str = "enable_query_profiling";
+        let v = self.try_get_u64(KEY)?;
+        Ok(v != 0)
+    }
+
+    pub fn set_enable_query_profiling(&self, val: bool) -> Result<()> {
+        static KEY: &str = "enable_query_profiling";
+        let v = if val { 1 } else { 0 };
+        self.try_set_u64(KEY, v, false)
+    }
+
+    pub fn get_max_process_query_length(&self) -> Result<u64> {
+        static KEY: &str = "max_process_query_length";
+        self.try_get_u64(KEY)
+    }
+
+    pub fn set_max_process_query_length(&self, val: u64) -> Result<()> {
+        static KEY: &str = "max_process_query_length";
+        self.try_set_u64(KEY, val, false)
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         static KEY: &str = "unquoted_ident_case_sensitive";
         let v = self.try_get_u64(KEY)?;
@@ -387,17 +387,23 @@ impl Table for FuseTable {
 
     #[tracing::instrument(level = "debug", name = "fuse_table_optimize", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn optimize(&self, ctx: Arc<dyn TableContext>, keep_last_snapshot: bool) -> Result<()> {
-        self.check_mutable()?;
-        self.do_gc(&ctx, keep_last_snapshot).await
+        self.purge(ctx, keep_last_snapshot).await?;
+        Ok(())
     }
 
     async fn statistics(&self, _ctx: Arc<dyn TableContext>) -> Result<Option<TableStatistics>> {
         let s = &self.table_info.meta.statistics;
+        let (number_of_segments, number_of_blocks) = match self.snapshot_loc() {
+            Some(_) => (s.number_of_segments, s.number_of_blocks),
+            None => (Some(0), Some(0)),
+        };
         Ok(Some(TableStatistics {
             num_rows: Some(s.number_of_rows),
             data_size: Some(s.data_bytes),
             data_size_compressed: Some(s.compressed_data_bytes),
             index_size: Some(s.index_data_bytes),
+            number_of_segments,
+            number_of_blocks,
         }))
     }
 
@@ -205,6 +205,8 @@ impl FuseTable {
             data_bytes: new_snapshot.summary.uncompressed_byte_size,
             compressed_data_bytes: new_snapshot.summary.compressed_byte_size,
             index_data_bytes: new_snapshot.summary.index_size,
+            number_of_segments: Some(new_snapshot.segments.len() as u64),
+            number_of_blocks: Some(new_snapshot.summary.block_count),
         };
 
         self.update_table_meta(
@@ -257,7 +259,7 @@ impl FuseTable {
         catalog_name: &str,
         table_info: &TableInfo,
         new_snapshot_location: String,
-        stats: &Statistics,
+        new_snapshot: &TableSnapshot,
     ) -> Result<UpdateTableMetaReply> {
         let catalog = ctx.get_catalog(catalog_name)?;
 
@@ -275,11 +277,14 @@ impl FuseTable {
         self::utils::remove_legacy_options(&mut new_table_meta.options);
 
         // update statistics
+        let stats = &new_snapshot.summary;
         new_table_meta.statistics = TableStatistics {
             number_of_rows: stats.row_count,
             data_bytes: stats.uncompressed_byte_size,
             compressed_data_bytes: stats.compressed_byte_size,
             index_data_bytes: stats.index_size,
+            number_of_segments: Some(new_snapshot.segments.len() as u64),
+            number_of_blocks: Some(stats.block_count),
         };
 
         let req = UpdateTableMetaReq {
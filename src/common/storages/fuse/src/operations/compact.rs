@@ -64,6 +64,8 @@ impl FuseTable {
             data_bytes: new_snapshot.summary.uncompressed_byte_size,
             compressed_data_bytes: new_snapshot.summary.compressed_byte_size,
             index_data_bytes: new_snapshot.summary.index_size,
+            number_of_segments: Some(new_snapshot.segments.len() as u64),
+            number_of_blocks: Some(new_snapshot.summary.block_count),
         };
         let ctx: &dyn TableContext = ctx.as_ref();
         self.update_table_meta(ctx, &plan.catalog, &new_snapshot, &mut new_table_meta)
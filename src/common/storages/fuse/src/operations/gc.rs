@@ -28,8 +28,30 @@ use tracing::warn;
 use crate::io::MetaReaders;
 use crate::FuseTable;
 
+/// What a gc of the table has removed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PurgeStatistics {
+    pub purged_snapshots: u64,
+    /// The size of the removed blocks and their bloom indexes.
+    pub purged_bytes: u64,
+}
+
 impl FuseTable {
-    pub async fn do_gc(&self, ctx: &Arc<dyn TableContext>, keep_last_snapshot: bool) -> Result<()> {
+    /// Removes the snapshots before the last one, and the last one too unless `keep_last_snapshot`.
+    pub async fn purge(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        keep_last_snapshot: bool,
+    ) -> Result<PurgeStatistics> {
+        self.check_mutable()?;
+        self.do_gc(&ctx, keep_last_snapshot).await
+    }
+
+    pub async fn do_gc(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        keep_last_snapshot: bool,
+    ) -> Result<PurgeStatistics> {
         let r = self.read_table_snapshot(ctx.clone()).await;
         let snapshot_opt = match r {
             Err(e) if e.code() == ErrorCode::storage_not_found_code() => {
@@ -40,7 +62,7 @@ impl FuseTable {
                     self.table_info.desc,
                     self.table_info.ident,
                 );
-                return Ok(());
+                return Ok(PurgeStatistics::default());
             }
             Err(e) => return Err(e),
             Ok(v) => v,
@@ -50,7 +72,7 @@ impl FuseTable {
             s
         } else {
             // empty table, have nothing to do here
-            return Ok(());
+            return Ok(PurgeStatistics::default());
         };
 
         let reader = MetaReaders::table_snapshot_reader(ctx.clone());
@@ -61,7 +83,7 @@ impl FuseTable {
             return if keep_last_snapshot {
                 // short cut:
                 // we do not have previous snapshot, and should keep the last snapshot, nothing to do
-                Ok(())
+                Ok(PurgeStatistics::default())
             } else {
                 // short cut:
                 // - no previous snapshot
@@ -69,9 +91,14 @@ impl FuseTable {
                 // just drop the whole snapshot,
                 let snapshots = vec![(last_snapshot.snapshot_id, self.snapshot_format_version())];
                 let segments = HashSet::from_iter(last_snapshot.segments.clone());
-                self.purge_blocks(ctx.as_ref(), segments.iter(), &HashSet::new())
+                let purged_bytes = self
+                    .purge_blocks(ctx.as_ref(), segments.iter(), &HashSet::new())
                     .await?;
-                self.collect(ctx.as_ref(), segments, snapshots).await
+                self.collect(ctx.as_ref(), segments, snapshots).await?;
+                Ok(PurgeStatistics {
+                    purged_snapshots: 1,
+                    purged_bytes,
+                })
             };
         };
 
@@ -121,19 +148,26 @@ impl FuseTable {
             .await?;
 
         // removed un-referenced blocks
-        self.purge_blocks(
-            ctx.as_ref(),
-            segments_to_be_deleted.iter(),
-            &blocks_referenced_by_gc_root,
-        )
-        .await?;
+        let purged_bytes = self
+            .purge_blocks(
+                ctx.as_ref(),
+                segments_to_be_deleted.iter(),
+                &blocks_referenced_by_gc_root,
+            )
+            .await?;
 
+        let purged_snapshots = snapshots_to_be_deleted.len() as u64;
         self.collect(
             ctx.as_ref(),
             segments_to_be_deleted,
             snapshots_to_be_deleted,
         )
-        .await
+        .await?;
+
+        Ok(PurgeStatistics {
+            purged_snapshots,
+            purged_bytes,
+        })
     }
 
     async fn blocks_of(
@@ -168,12 +202,15 @@ impl FuseTable {
     /// rm all the blocks, which are
     /// - referenced by any one of `segments`
     /// - but NOT referenced by `root`
+    ///
+    /// returns the size of the removed blocks and their bloom indexes
     async fn purge_blocks(
         &self,
         ctx: &dyn TableContext,
         segments: impl Iterator<Item = &Location>,
         root: &HashSet<String>,
-    ) -> Result<()> {
+    ) -> Result<u64> {
+        let mut purged_bytes = 0;
         let reader = MetaReaders::segment_info_reader(ctx);
         let accessor = ctx.get_storage_operator()?;
         for l in segments {
@@ -191,13 +228,15 @@ impl FuseTable {
                         }
                         self.remove_location(&accessor, bloom_index_location.0.as_str())
                             .await?;
+                        purged_bytes += block_meta.bloom_filter_index_size;
                     }
                     self.remove_location(&accessor, block_meta.location.0.as_str())
                         .await?;
+                    purged_bytes += block_meta.file_size;
                 }
             }
         }
-        Ok(())
+        Ok(purged_bytes)
     }
 
     async fn remove_location(
@@ -29,6 +29,7 @@ mod truncate;
 pub mod util;
 
 pub use fuse_sink::FuseTableSink;
+pub use gc::PurgeStatistics;
 pub use mutation::delete_from_block;
 pub use mutation::DeletionMutator;
 pub use mutation::ReclusterMutator;
@@ -23,6 +23,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
 use common_fuse_meta::meta::SegmentInfo;
+use common_fuse_meta::meta::Statistics;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
 use common_meta_app::schema::TableInfo;
@@ -195,7 +196,13 @@ impl ReclusterMutator {
         Ok(false)
     }
 
-    pub async fn commit_recluster(self, catalog_name: &str) -> Result<()> {
+    /// The number of segments the selected blocks belong to.
+    pub fn segments_reclustered(&self) -> usize {
+        self.base_mutator.mutations.len()
+    }
+
+    /// Commits the recluster, returns the statistics of the blocks written by it.
+    pub async fn commit_recluster(self, catalog_name: &str) -> Result<Statistics> {
         let ctx = self.base_mutator.ctx.clone();
         let (mut segments, mut summary) = self.base_mutator.generate_segments().await?;
 
@@ -226,9 +233,9 @@ impl ReclusterMutator {
             catalog_name,
             &self.table_info,
             loc,
-            &new_snapshot.summary,
+            &new_snapshot,
         )
         .await?;
-        Ok(())
+        Ok(merged_summary)
     }
 }
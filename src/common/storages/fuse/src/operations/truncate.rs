@@ -55,7 +55,7 @@ impl FuseTable {
 
             if purge {
                 let keep_last_snapshot = false;
-                self.do_gc(&ctx, keep_last_snapshot).await?
+                self.do_gc(&ctx, keep_last_snapshot).await?;
             }
 
             let mut new_table_meta = self.table_info.meta.clone();
@@ -65,7 +65,11 @@ impl FuseTable {
                 .insert(OPT_KEY_SNAPSHOT_LOCATION.to_owned(), new_snapshot_loc);
 
             // update table statistics, all zeros
-            new_table_meta.statistics = TableStatistics::default();
+            new_table_meta.statistics = TableStatistics {
+                number_of_segments: Some(0),
+                number_of_blocks: Some(0),
+                ..Default::default()
+            };
 
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
@@ -32,15 +32,15 @@ impl ColumnsTable {
             database AS table_schema,
             table AS table_name,
             name AS column_name,
-            1 AS ordinal_position,
+            ordinal_position AS ordinal_position,
             NULL AS column_default,
             is_nullable AS is_nullable,
             type AS data_type,
-            NULL AS character_maximum_length,
+            character_maximum_length AS character_maximum_length,
             NULL AS character_octet_length,
-            NULL AS numeric_precision,
+            numeric_precision AS numeric_precision,
             NULL AS numeric_precision_radix,
-            NULL AS numeric_scale,
+            numeric_scale AS numeric_scale,
             NULL AS datetime_precision,
             NULL AS character_set_catalog,
             NULL AS character_set_schema,
@@ -0,0 +1,182 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_planners::TruncateTablePlan;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::SyncSource;
+use crate::pipelines::processors::SyncSourcer;
+use crate::pipelines::Pipeline;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+/// The recent clustering operations kept in memory, bounded by `max_rows`.
+///
+/// The store is local to the query node: it only has the reclusters run by this node
+/// and it is lost when the node restarts.
+pub struct ClusteringHistoryMemoryStore {
+    max_rows: usize,
+    // One block for each recluster, the newest is at the back.
+    blocks: RwLock<VecDeque<DataBlock>>,
+}
+
+impl ClusteringHistoryMemoryStore {
+    pub fn create(max_rows: usize) -> Self {
+        ClusteringHistoryMemoryStore {
+            max_rows,
+            blocks: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    pub fn append(&self, block: DataBlock) {
+        let mut blocks = self.blocks.write();
+        blocks.push_back(block);
+
+        // Check overflow.
+        while blocks.len() > self.max_rows {
+            blocks.pop_front();
+        }
+    }
+
+    pub fn clear(&self) {
+        self.blocks.write().clear();
+    }
+
+    pub fn all(&self) -> VecDeque<DataBlock> {
+        self.blocks.read().clone()
+    }
+}
+
+pub struct ClusteringHistoryTable {
+    table_info: TableInfo,
+    store: Arc<ClusteringHistoryMemoryStore>,
+}
+
+impl ClusteringHistoryTable {
+    pub fn create(table_id: u64, max_rows: usize) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("start_time", TimestampType::new_impl(3)),
+            DataField::new("end_time", TimestampType::new_impl(3)),
+            DataField::new("segments_reclustered", u64::to_data_type()),
+            DataField::new("bytes_written", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'clustering_history'".to_string(),
+            name: "clustering_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemClusteringHistory".to_string(),
+                ..Default::default()
+            },
+        };
+
+        ClusteringHistoryTable {
+            table_info,
+            store: Arc::new(ClusteringHistoryMemoryStore::create(max_rows)),
+        }
+    }
+
+    pub fn store(&self) -> Arc<ClusteringHistoryMemoryStore> {
+        self.store.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for ClusteringHistoryTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        let mut source_builder = SourcePipeBuilder::create();
+
+        source_builder.add_source(
+            output.clone(),
+            ClusteringHistorySource::create(ctx, output, self.store.all())?,
+        );
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    async fn truncate(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _truncate_plan: TruncateTablePlan,
+    ) -> Result<()> {
+        self.store.clear();
+        Ok(())
+    }
+}
+
+struct ClusteringHistorySource {
+    data: VecDeque<DataBlock>,
+}
+
+impl ClusteringHistorySource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        data: VecDeque<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, ClusteringHistorySource { data })
+    }
+}
+
+impl SyncSource for ClusteringHistorySource {
+    const NAME: &'static str = "system.clustering_history";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.data.pop_front())
+    }
+}
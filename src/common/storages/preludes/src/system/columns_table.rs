@@ -12,17 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_catalog::catalog::CATALOG_DEFAULT;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_planners::Extras;
+use common_planners::RequireColumnsVisitor;
+use tracing::warn;
 
+use crate::catalogs::Catalog;
 use crate::sessions::TableContext;
+use crate::storages::system::table::find_eq_filter;
+use crate::storages::system::table::list_catalogs;
 use crate::storages::system::table::AsyncOneBlockSystemTable;
 use crate::storages::system::table::AsyncSystemTable;
 use crate::storages::Table;
@@ -40,8 +48,24 @@ impl AsyncSystemTable for ColumnsTable {
     }
 
     async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let rows = self.dump_table_columns(ctx).await?;
+        self.get_data_with_push_downs(ctx, None).await
+    }
+
+    async fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        let filters = push_downs.map(|extras| extras.filters).unwrap_or_default();
+        let catalog = find_eq_filter(&filters, "catalog");
+        let database = find_eq_filter(&filters, "database");
+        let table = find_eq_filter(&filters, "table");
+
+        let rows = self
+            .dump_table_columns(ctx, catalog, database, table)
+            .await?;
         let mut names: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
+        let mut catalogs: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut tables: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut databases: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut data_types: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
@@ -49,8 +73,23 @@ impl AsyncSystemTable for ColumnsTable {
         let mut default_exprs: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
         let mut is_nullables: Vec<bool> = Vec::with_capacity(rows.len());
         let mut comments: Vec<Vec<u8>> = Vec::with_capacity(rows.len());
-        for (database_name, table_name, field) in rows.into_iter() {
+        let mut ordinal_positions: Vec<u64> = Vec::with_capacity(rows.len());
+        let mut character_maximum_lengths: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        let mut numeric_precisions: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        let mut numeric_scales: Vec<Option<u64>> = Vec::with_capacity(rows.len());
+        let mut is_cluster_keys: Vec<bool> = Vec::with_capacity(rows.len());
+        for (
+            catalog_name,
+            database_name,
+            table_name,
+            ordinal_position,
+            field,
+            comment,
+            is_cluster_key,
+        ) in rows.into_iter()
+        {
             names.push(field.name().clone().into_bytes());
+            catalogs.push(catalog_name.into_bytes());
             tables.push(table_name.into_bytes());
             databases.push(database_name.into_bytes());
 
@@ -58,6 +97,13 @@ impl AsyncSystemTable for ColumnsTable {
             let data_type = format_data_type_sql(&non_null_type);
             data_types.push(data_type.into_bytes());
 
+            let (numeric_precision, numeric_scale) = Self::numeric_precision_scale(&non_null_type);
+            ordinal_positions.push(ordinal_position);
+            // The string types have no declared length.
+            character_maximum_lengths.push(None);
+            numeric_precisions.push(numeric_precision);
+            numeric_scales.push(numeric_scale);
+
             let mut default_kind = "".to_string();
             let mut default_expr = "".to_string();
             if let Some(expr) = field.default_expr() {
@@ -67,7 +113,8 @@ impl AsyncSystemTable for ColumnsTable {
             default_kinds.push(default_kind.into_bytes());
             default_exprs.push(default_expr.into_bytes());
             is_nullables.push(field.is_nullable());
-            comments.push("".to_string().into_bytes());
+            comments.push(comment.into_bytes());
+            is_cluster_keys.push(is_cluster_key);
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -79,6 +126,12 @@ impl AsyncSystemTable for ColumnsTable {
             Series::from_data(default_exprs),
             Series::from_data(is_nullables),
             Series::from_data(comments),
+            Series::from_data(ordinal_positions),
+            Series::from_data(character_maximum_lengths),
+            Series::from_data(numeric_precisions),
+            Series::from_data(numeric_scales),
+            Series::from_data(catalogs),
+            Series::from_data(is_cluster_keys),
         ]))
     }
 }
@@ -94,6 +147,12 @@ impl ColumnsTable {
             DataField::new("default_expression", Vu8::to_data_type()),
             DataField::new("is_nullable", bool::to_data_type()),
             DataField::new("comment", Vu8::to_data_type()),
+            DataField::new("ordinal_position", u64::to_data_type()),
+            DataField::new_nullable("character_maximum_length", u64::to_data_type()),
+            DataField::new_nullable("numeric_precision", u64::to_data_type()),
+            DataField::new_nullable("numeric_scale", u64::to_data_type()),
+            DataField::new("catalog", Vu8::to_data_type()),
+            DataField::new("is_cluster_key", bool::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -110,22 +169,119 @@ impl ColumnsTable {
         AsyncOneBlockSystemTable::create(ColumnsTable { table_info })
     }
 
+    /// Returns the decimal precision and scale of the numeric types, the same as MySQL reports.
+    ///
+    /// The scale of the floating types is unknown, so is NULL.
+    fn numeric_precision_scale(data_type: &DataTypeImpl) -> (Option<u64>, Option<u64>) {
+        match data_type.data_type_id() {
+            TypeID::Int8 | TypeID::UInt8 => (Some(3), Some(0)),
+            TypeID::Int16 | TypeID::UInt16 => (Some(5), Some(0)),
+            TypeID::Int32 | TypeID::UInt32 => (Some(10), Some(0)),
+            TypeID::Int64 => (Some(19), Some(0)),
+            TypeID::UInt64 => (Some(20), Some(0)),
+            TypeID::Float32 => (Some(12), None),
+            TypeID::Float64 => (Some(22), None),
+            _ => (None, None),
+        }
+    }
+
+    /// Enumerates the columns, only of the catalog, the database and the table if they are given.
+    ///
+    /// The catalogs except the default one failing to list are skipped.
     async fn dump_table_columns(
         &self,
         ctx: Arc<dyn TableContext>,
-    ) -> Result<Vec<(String, String, DataField)>> {
+        catalog: Option<String>,
+        database: Option<String>,
+        table: Option<String>,
+    ) -> Result<Vec<(String, String, String, u64, DataField, String, bool)>> {
+        let mut rows: Vec<(String, String, String, u64, DataField, String, bool)> = vec![];
+        for (catalog_name, catalog) in list_catalogs(&ctx, catalog) {
+            let res = Self::dump_catalog_columns(&ctx, &catalog, &database, &table).await;
+            match res {
+                Ok(columns) => {
+                    for (database, table, ordinal_position, field, comment, is_cluster_key) in
+                        columns
+                    {
+                        rows.push((
+                            catalog_name.clone(),
+                            database,
+                            table,
+                            ordinal_position,
+                            field,
+                            comment,
+                            is_cluster_key,
+                        ));
+                    }
+                }
+                Err(cause) if catalog_name != CATALOG_DEFAULT => {
+                    warn!("Skip the columns of catalog {}: {}", catalog_name, cause);
+                }
+                Err(cause) => return Err(cause),
+            }
+        }
+
+        Ok(rows)
+    }
+
+    async fn dump_catalog_columns(
+        ctx: &Arc<dyn TableContext>,
+        catalog: &Arc<dyn Catalog>,
+        database: &Option<String>,
+        table: &Option<String>,
+    ) -> Result<Vec<(String, String, u64, DataField, String, bool)>> {
         let tenant = ctx.get_tenant();
-        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
-        let databases = catalog.list_databases(tenant.as_str()).await?;
 
-        let mut rows: Vec<(String, String, DataField)> = vec![];
-        for database in databases {
-            for table in catalog
-                .list_tables(tenant.as_str(), database.name())
+        let databases = match database {
+            Some(database) => match catalog.exists_database(tenant.as_str(), database).await? {
+                true => vec![database.clone()],
+                false => vec![],
+            },
+            None => catalog
+                .list_databases(tenant.as_str())
                 .await?
-            {
-                for field in table.schema().fields() {
-                    rows.push((database.name().into(), table.name().into(), field.clone()))
+                .iter()
+                .map(|database| database.name().to_string())
+                .collect(),
+        };
+
+        let mut rows: Vec<(String, String, u64, DataField, String, bool)> = vec![];
+        for database in databases {
+            let tables = match table {
+                Some(table) => match catalog.get_table(tenant.as_str(), &database, table).await {
+                    Ok(table) => vec![table],
+                    Err(e) if e.code() == ErrorCode::UnknownTableCode() => vec![],
+                    Err(e) => return Err(e),
+                },
+                None => catalog.list_tables(tenant.as_str(), &database).await?,
+            };
+
+            for table in tables {
+                // The columns referenced by the cluster key expressions.
+                let mut cluster_key_columns = HashSet::new();
+                for expr in table.cluster_keys() {
+                    cluster_key_columns
+                        .extend(RequireColumnsVisitor::collect_columns_from_expr(&expr)?);
+                }
+
+                // The comments are kept only if there is one for each field, as SHOW CREATE TABLE does.
+                let fields = table.schema().fields().clone();
+                let field_comments = table.field_comments();
+                let has_comments = field_comments.len() == fields.len();
+
+                for (index, field) in fields.iter().enumerate() {
+                    let comment = match has_comments {
+                        true => field_comments[index].clone(),
+                        false => "".to_string(),
+                    };
+                    rows.push((
+                        database.clone(),
+                        table.name().into(),
+                        index as u64 + 1,
+                        field.clone(),
+                        comment,
+                        cluster_key_columns.contains(field.name()),
+                    ))
                 }
             }
         }
@@ -14,17 +14,19 @@
 
 use std::sync::Arc;
 
-use common_base::base::mask_string;
+use common_config::redact_config_value;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_planners::Extras;
 use itertools::Itertools;
 use serde_json::Value;
 
 use crate::sessions::TableContext;
+use crate::storages::system::table::find_like_prefix_filter;
 use crate::storages::system::table::SyncOneBlockSystemTable;
 use crate::storages::system::table::SyncSystemTable;
 use crate::storages::Table;
@@ -41,6 +43,16 @@ impl SyncSystemTable for ConfigsTable {
     }
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        self.get_data_with_push_downs(ctx, None)
+    }
+
+    fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        let filters = push_downs.map(|extras| extras.filters).unwrap_or_default();
+        let name_prefix = find_like_prefix_filter(&filters, "name");
         let config = ctx.get_config().into_outer();
 
         let mut names: Vec<String> = vec![];
@@ -81,16 +93,7 @@ impl SyncSystemTable for ConfigsTable {
             meta_config_value,
         );
 
-        // Clone storage config to avoid change it's value.
-        //
-        // TODO(xuanwo):
-        // Refactor into config so that config can  decide which value needs mask.
-        let mut storage_config = config.storage;
-        storage_config.s3.access_key_id = mask_string(&storage_config.s3.access_key_id, 3);
-        storage_config.s3.secret_access_key = mask_string(&storage_config.s3.secret_access_key, 3);
-        storage_config.gcs.credential = mask_string(&storage_config.gcs.credential, 3);
-        storage_config.azblob.account_name = mask_string(&storage_config.azblob.account_name, 3);
-        storage_config.azblob.account_key = mask_string(&storage_config.azblob.account_key, 3);
+        let storage_config = config.storage;
         let storage_config_value = serde_json::to_value(storage_config)?;
         ConfigsTable::extract_config(
             &mut names,
@@ -101,10 +104,18 @@ impl SyncSystemTable for ConfigsTable {
             storage_config_value,
         );
 
-        let names: Vec<&str> = names.iter().map(|x| x.as_str()).collect();
-        let values: Vec<&str> = values.iter().map(|x| x.as_str()).collect();
-        let groups: Vec<&str> = groups.iter().map(|x| x.as_str()).collect();
-        let descs: Vec<&str> = descs.iter().map(|x| x.as_str()).collect();
+        // Only the configs under the prefix of `name LIKE '<prefix>%'` are returned.
+        let rows = (0..names.len())
+            .filter(|row| {
+                name_prefix
+                    .as_ref()
+                    .map_or(true, |prefix| names[*row].starts_with(prefix.as_str()))
+            })
+            .collect::<Vec<_>>();
+        let names: Vec<&str> = rows.iter().map(|row| names[*row].as_str()).collect();
+        let values: Vec<&str> = rows.iter().map(|row| values[*row].as_str()).collect();
+        let groups: Vec<&str> = rows.iter().map(|row| groups[*row].as_str()).collect();
+        let descs: Vec<&str> = rows.iter().map(|row| descs[*row].as_str()).collect();
         Ok(DataBlock::create(self.table_info.schema(), vec![
             Series::from_data(groups),
             Series::from_data(names),
@@ -237,12 +248,13 @@ impl ConfigsTable {
         desc: String,
         name_prefix: Option<String>,
     ) {
-        if let Some(prefix) = name_prefix {
-            names.push(format!("{}.{}", prefix, name));
-        } else {
-            names.push(name);
-        }
-        values.push(value);
+        let name = match name_prefix {
+            Some(prefix) => format!("{}.{}", prefix, name),
+            None => name,
+        };
+        // The secrets are masked, see `common_config::is_sensitive_config`.
+        values.push(redact_config_value(&name, &value));
+        names.push(name);
         groups.push(group);
         descs.push(desc);
     }
@@ -20,8 +20,11 @@ use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_planners::Extras;
 
 use crate::sessions::TableContext;
+use crate::storages::system::table::find_eq_filter;
+use crate::storages::system::table::list_catalogs;
 use crate::storages::system::table::AsyncOneBlockSystemTable;
 use crate::storages::system::table::AsyncSystemTable;
 use crate::storages::Table;
@@ -39,24 +42,56 @@ impl AsyncSystemTable for DatabasesTable {
     }
 
     async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        self.get_data_with_push_downs(ctx, None).await
+    }
+
+    async fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        let filters = push_downs.map(|extras| extras.filters).unwrap_or_default();
+        // `SHOW DATABASES` lists the current catalog only, the others are listed by name.
+        let catalog =
+            find_eq_filter(&filters, "catalog").unwrap_or_else(|| ctx.get_current_catalog());
+
         let tenant = ctx.get_tenant();
-        let catalog = ctx.get_catalog(ctx.get_current_catalog().as_str())?;
-        let databases = catalog.list_databases(tenant.as_str()).await?;
+        let mut catalog_databases = vec![];
+        for (catalog_name, catalog) in list_catalogs(&ctx, Some(catalog)) {
+            for database in catalog.list_databases(tenant.as_str()).await? {
+                catalog_databases.push((catalog_name.clone(), database));
+            }
+        }
 
-        let db_names: Vec<&[u8]> = databases
+        let db_names: Vec<&[u8]> = catalog_databases
+            .iter()
+            .map(|(_, database)| database.name().as_bytes())
+            .collect();
+        let catalogs: Vec<&[u8]> = catalog_databases
             .iter()
-            .map(|database| database.name().as_bytes())
+            .map(|(catalog, _)| catalog.as_bytes())
             .collect();
+        let engines: Vec<String> = catalog_databases
+            .iter()
+            .map(|(_, database)| Self::engine_name(database.engine()))
+            .collect();
+        let engines: Vec<&[u8]> = engines.iter().map(|s| s.as_bytes()).collect();
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
             Series::from_data(db_names),
+            Series::from_data(catalogs),
+            Series::from_data(engines),
         ]))
     }
 }
 
 impl DatabasesTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
-        let schema = DataSchemaRefExt::create(vec![DataField::new("name", Vu8::to_data_type())]);
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("catalog", Vu8::to_data_type()),
+            DataField::new("engine", Vu8::to_data_type()),
+        ]);
 
         let table_info = TableInfo {
             desc: "'system'.'databases'".to_string(),
@@ -71,4 +106,12 @@ impl DatabasesTable {
 
         AsyncOneBlockSystemTable::create(DatabasesTable { table_info })
     }
+
+    /// The databases without engine are of the `DEFAULT` engine, the same as `DatabaseFactory` resolves.
+    fn engine_name(engine: &str) -> String {
+        match engine.is_empty() {
+            true => "DEFAULT".to_string(),
+            false => engine.to_uppercase(),
+        }
+    }
 }
@@ -78,6 +78,20 @@ impl AsyncSystemTable for FunctionsTable {
             .map(|i| i >= func_names.len() && i < builtin_func_len)
             .collect::<Vec<bool>>();
 
+        // The aggregate functions are deterministic on their input,
+        // a UDF is not known to be without checking its definition.
+        let is_deterministic = (0..names.len())
+            .map(|i| {
+                if i < func_names.len() {
+                    function_factory
+                        .get_features(names[i])
+                        .map_or(false, |features| features.is_deterministic)
+                } else {
+                    i < builtin_func_len
+                }
+            })
+            .collect::<Vec<bool>>();
+
         let definitions = (0..names.len())
             .map(|i| {
                 if i < builtin_func_len {
@@ -135,6 +149,7 @@ impl AsyncSystemTable for FunctionsTable {
             Series::from_data(names),
             Series::from_data(is_builtin),
             Series::from_data(is_aggregate),
+            Series::from_data(is_deterministic),
             Series::from_data(definitions),
             Series::from_data(categorys),
             Series::from_data(descriptions),
@@ -150,6 +165,7 @@ impl FunctionsTable {
             DataField::new("name", Vu8::to_data_type()),
             DataField::new("is_builtin", bool::to_data_type()),
             DataField::new("is_aggregate", bool::to_data_type()),
+            DataField::new("is_deterministic", bool::to_data_type()),
             DataField::new("definition", Vu8::to_data_type()),
             DataField::new("category", Vu8::to_data_type()),
             DataField::new("description", Vu8::to_data_type()),
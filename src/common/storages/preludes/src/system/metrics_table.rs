@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -22,40 +23,91 @@ use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_metrics::MetricSample;
 use common_metrics::MetricValue;
+use common_planners::Extras;
 use serde_json;
 
 use crate::sessions::TableContext;
-use crate::storages::system::table::SyncOneBlockSystemTable;
-use crate::storages::system::table::SyncSystemTable;
+use crate::storages::system::remote_nodes::read_remote_nodes;
+use crate::storages::system::remote_nodes::RemoteNodeReader;
+use crate::storages::system::table::find_like_prefix_filter;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
 use crate::storages::Table;
 
 pub struct MetricsTable {
     table_info: TableInfo,
+    remote_reader: Option<Arc<dyn RemoteNodeReader>>,
 }
 
-impl SyncSystemTable for MetricsTable {
+#[async_trait::async_trait]
+impl AsyncSystemTable for MetricsTable {
     const NAME: &'static str = "system.metrics";
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
     }
 
-    fn get_full_data(&self, _: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let prometheus_handle = common_metrics::try_handle().ok_or_else(|| {
-            ErrorCode::InitPrometheusFailure("Prometheus recorder is not initialized yet.")
-        })?;
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        self.get_data_with_push_downs(ctx, None).await
+    }
+
+    async fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        let filters = push_downs.map(|extras| extras.filters).unwrap_or_default();
+        let metric_prefix = find_like_prefix_filter(&filters, "metric");
+        let settings = ctx.get_settings();
+        let cluster = ctx.get_cluster();
+
+        // (node, sample), the local samples first.
+        let mut samples = Self::local_samples()?
+            .into_iter()
+            .map(|sample| (cluster.local_id.clone(), sample))
+            .collect::<Vec<_>>();
+
+        if let Some(remote_reader) = &self.remote_reader {
+            if settings.get_metrics_cluster()? {
+                let nodes_samples = read_remote_nodes(&ctx, |node| {
+                    let (ctx, remote_reader) = (ctx.clone(), remote_reader.clone());
+                    async move { remote_reader.read_metric_samples(ctx, &node).await }
+                })
+                .await;
+                for (node, node_samples) in nodes_samples {
+                    samples.extend(
+                        node_samples
+                            .into_iter()
+                            .map(|sample| (node.clone(), sample)),
+                    );
+                }
+
+                if settings.get_metrics_cluster_sum_counters()? {
+                    samples = Self::sum_counters(samples);
+                }
+            }
+        }
 
-        let samples = common_metrics::dump_metric_samples(prometheus_handle)?;
         let mut metrics: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
         let mut labels: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
         let mut kinds: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
         let mut values: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
-        for sample in samples.into_iter() {
+        let mut nodes: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
+        for (node, sample) in samples.into_iter() {
+            // Only the metrics under the prefix of `metric LIKE '<prefix>%'` are returned.
+            if let Some(prefix) = &metric_prefix {
+                if !sample.name.starts_with(prefix.as_str()) {
+                    continue;
+                }
+            }
+
             metrics.push(sample.name.clone().into_bytes());
             kinds.push(sample.kind.clone().into_bytes());
             labels.push(self.display_sample_labels(&sample.labels)?.into_bytes());
             values.push(self.display_sample_value(&sample.value)?.into_bytes());
+            nodes.push(node.into_bytes());
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -63,17 +115,35 @@ impl SyncSystemTable for MetricsTable {
             Series::from_data(kinds),
             Series::from_data(labels),
             Series::from_data(values),
+            Series::from_data(nodes),
         ]))
     }
 }
 
 impl MetricsTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
+        AsyncOneBlockSystemTable::create(Self::create_table(table_id, None))
+    }
+
+    /// Read the metrics of the other cluster nodes with the reader when `metrics_cluster` is enabled.
+    pub fn create_with_remote_reader(
+        table_id: u64,
+        remote_reader: Arc<dyn RemoteNodeReader>,
+    ) -> Arc<dyn Table> {
+        AsyncOneBlockSystemTable::create(Self::create_table(table_id, Some(remote_reader)))
+    }
+
+    fn create_table(
+        table_id: u64,
+        remote_reader: Option<Arc<dyn RemoteNodeReader>>,
+    ) -> MetricsTable {
         let schema = DataSchemaRefExt::create(vec![
             DataField::new("metric", Vu8::to_data_type()),
             DataField::new("kind", Vu8::to_data_type()),
             DataField::new("labels", Vu8::to_data_type()),
             DataField::new("value", Vu8::to_data_type()),
+            // The id of the cluster node, empty for the counters summed over the nodes.
+            DataField::new("node", Vu8::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -87,7 +157,56 @@ impl MetricsTable {
             },
         };
 
-        SyncOneBlockSystemTable::create(MetricsTable { table_info })
+        MetricsTable {
+            table_info,
+            remote_reader,
+        }
+    }
+
+    /// Returns the metric samples of this node, it also serves the `RemoteNodeReader`
+    /// of the other nodes.
+    pub fn local_samples() -> Result<Vec<MetricSample>> {
+        let prometheus_handle = common_metrics::try_handle().ok_or_else(|| {
+            ErrorCode::InitPrometheusFailure("Prometheus recorder is not initialized yet.")
+        })?;
+
+        common_metrics::dump_metric_samples(prometheus_handle)
+    }
+
+    /// Sums the counters with the same name and labels into one sample without node,
+    /// the other kinds of samples are kept for each node.
+    fn sum_counters(samples: Vec<(String, MetricSample)>) -> Vec<(String, MetricSample)> {
+        let mut res = Vec::with_capacity(samples.len());
+        // The index in `res` of the summed counter.
+        let mut counters: HashMap<(String, BTreeMap<String, String>), usize> = HashMap::new();
+
+        for (node, sample) in samples {
+            let value = match sample.value {
+                MetricValue::Counter(value) => value,
+                _ => {
+                    res.push((node, sample));
+                    continue;
+                }
+            };
+
+            let key = (
+                sample.name.clone(),
+                sample.labels.clone().into_iter().collect(),
+            );
+            match counters.get(&key) {
+                Some(index) => {
+                    if let MetricValue::Counter(sum) = &mut res[*index].1.value {
+                        *sum += value;
+                    }
+                }
+                None => {
+                    counters.insert(key, res.len());
+                    res.push((String::new(), sample));
+                }
+            }
+        }
+
+        res
     }
 
     fn display_sample_labels(&self, labels: &HashMap<String, String>) -> Result<String> {
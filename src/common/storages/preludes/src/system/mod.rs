@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod clustering_history_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
@@ -23,15 +24,22 @@ mod metrics_table;
 mod one_table;
 mod processes_table;
 mod query_log_table;
+mod query_profile_table;
+mod remote_nodes;
 mod roles_table;
 mod settings_table;
+mod share_privileges_table;
 mod stages_table;
 mod table;
+mod table_options_table;
 mod tables_table;
 mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
+mod vacuum_history_table;
 
+pub use clustering_history_table::ClusteringHistoryMemoryStore;
+pub use clustering_history_table::ClusteringHistoryTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
@@ -42,15 +50,28 @@ pub use functions_table::FunctionsTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
+pub use query_log_table::QueryLogMemoryStore;
+pub use query_log_table::QueryLogSink;
 pub use query_log_table::QueryLogTable;
+pub use query_log_table::QueryLogTimeWindow;
+pub use query_profile_table::QueryProfileMemoryStore;
+pub use query_profile_table::QueryProfileTable;
+pub use remote_nodes::read_remote_nodes;
+pub use remote_nodes::RemoteNodeReader;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
+pub use share_privileges_table::SharePrivilegesTable;
 pub use stages_table::StagesTable;
+pub use table::AsyncOneBlockSystemTable;
+pub use table::AsyncSystemTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
+pub use table_options_table::TableOptionsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
 pub use tracing_table::TracingTable;
 pub use tracing_table_stream::TracingTableStream;
 pub use users_table::UsersTable;
+pub use vacuum_history_table::VacuumHistoryMemoryStore;
+pub use vacuum_history_table::VacuumHistoryTable;
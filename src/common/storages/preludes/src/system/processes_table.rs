@@ -14,6 +14,8 @@
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 use common_base::base::ProgressValues;
 use common_contexts::DalMetrics;
@@ -44,6 +46,7 @@ impl AsyncSystemTable for ProcessesTable {
 
     async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
         let processes_info = ctx.get_processes_info().await;
+        let max_query_length = ctx.get_settings().get_max_process_query_length()? as usize;
 
         let mut processes_id = Vec::with_capacity(processes_info.len());
         let mut processes_type = Vec::with_capacity(processes_info.len());
@@ -58,12 +61,19 @@ impl AsyncSystemTable for ProcessesTable {
         let mut processes_scan_progress_read_rows = Vec::with_capacity(processes_info.len());
         let mut processes_scan_progress_read_bytes = Vec::with_capacity(processes_info.len());
         let mut processes_mysql_connection_id = Vec::with_capacity(processes_info.len());
+        let mut processes_session_started_on = Vec::with_capacity(processes_info.len());
+        let mut processes_last_query_start_time = Vec::with_capacity(processes_info.len());
+        let mut processes_peak_memory_usage = Vec::with_capacity(processes_info.len());
+        let mut processes_query = Vec::with_capacity(processes_info.len());
+        let mut processes_handler = Vec::with_capacity(processes_info.len());
+        let mut processes_cpu_time_ms = Vec::with_capacity(processes_info.len());
+        let mut processes_progress_percent = Vec::with_capacity(processes_info.len());
 
         for process_info in &processes_info {
             processes_id.push(process_info.id.clone().into_bytes());
             processes_type.push(process_info.typ.clone().into_bytes());
             processes_state.push(process_info.state.clone().into_bytes());
-            processes_database.push(process_info.database.clone().into_bytes());
+            processes_database.push(ProcessesTable::process_database(&process_info.database));
             processes_host.push(ProcessesTable::process_host(&process_info.client_address));
             processes_user.push(ProcessesTable::process_user_info(&process_info.user));
             processes_extra_info.push(ProcessesTable::process_extra_info(
@@ -79,6 +89,31 @@ impl AsyncSystemTable for ProcessesTable {
             processes_scan_progress_read_rows.push(scan_progress_read_rows);
             processes_scan_progress_read_bytes.push(scan_progress_read_bytes);
             processes_mysql_connection_id.push(process_info.mysql_connection_id);
+            processes_session_started_on.push(ProcessesTable::process_time(
+                process_info.session_started_on,
+            ));
+            // None if the session has not run any query yet.
+            processes_last_query_start_time.push(
+                process_info
+                    .last_query_started_on
+                    .map(ProcessesTable::process_time),
+            );
+            processes_peak_memory_usage.push(process_info.peak_memory_usage);
+            processes_query.push(ProcessesTable::process_query(
+                &process_info.query,
+                max_query_length,
+            ));
+            processes_handler.push(
+                process_info
+                    .handler
+                    .as_ref()
+                    .map(|handler| handler.as_bytes().to_vec()),
+            );
+            processes_cpu_time_ms.push(process_info.cpu_time_ms);
+            processes_progress_percent.push(ProcessesTable::process_progress_percent(
+                &process_info.scan_progress_value,
+                &process_info.total_scan_progress_value,
+            ));
         }
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
@@ -95,6 +130,13 @@ impl AsyncSystemTable for ProcessesTable {
             Series::from_data(processes_scan_progress_read_rows),
             Series::from_data(processes_scan_progress_read_bytes),
             Series::from_data(processes_mysql_connection_id),
+            Series::from_data(processes_session_started_on),
+            Series::from_data(processes_last_query_start_time),
+            Series::from_data(processes_peak_memory_usage),
+            Series::from_data(processes_query),
+            Series::from_data(processes_handler),
+            Series::from_data(processes_cpu_time_ms),
+            Series::from_data(processes_progress_percent),
         ]))
     }
 }
@@ -107,7 +149,7 @@ impl ProcessesTable {
             DataField::new_nullable("host", Vu8::to_data_type()),
             DataField::new_nullable("user", Vu8::to_data_type()),
             DataField::new("state", Vu8::to_data_type()),
-            DataField::new("database", Vu8::to_data_type()),
+            DataField::new_nullable("database", Vu8::to_data_type()),
             DataField::new_nullable("extra_info", Vu8::to_data_type()),
             DataField::new("memory_usage", i64::to_data_type()),
             DataField::new_nullable("dal_metrics_read_bytes", u64::to_data_type()),
@@ -115,6 +157,13 @@ impl ProcessesTable {
             DataField::new_nullable("scan_progress_read_rows", u64::to_data_type()),
             DataField::new_nullable("scan_progress_read_bytes", u64::to_data_type()),
             DataField::new_nullable("mysql_connection_id", u32::to_data_type()),
+            DataField::new("session_started_on", TimestampType::new_impl(3)),
+            DataField::new_nullable("last_query_start_time", TimestampType::new_impl(3)),
+            DataField::new_nullable("peak_memory_usage", i64::to_data_type()),
+            DataField::new_nullable("query", Vu8::to_data_type()),
+            DataField::new_nullable("handler", Vu8::to_data_type()),
+            DataField::new_nullable("cpu_time_ms", u64::to_data_type()),
+            DataField::new_nullable("progress_percent", f64::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -132,6 +181,12 @@ impl ProcessesTable {
         AsyncOneBlockSystemTable::create(ProcessesTable { table_info })
     }
 
+    /// Returns the microseconds since the unix epoch.
+    fn process_time(time: SystemTime) -> i64 {
+        time.duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_micros() as i64)
+    }
+
     fn process_host(client_address: &Option<SocketAddr>) -> Option<Vec<u8>> {
         client_address.as_ref().map(|s| s.to_string().into_bytes())
     }
@@ -140,10 +195,28 @@ impl ProcessesTable {
         user_info.as_ref().map(|s| s.name.clone().into_bytes())
     }
 
+    /// None if the session has no current database selected.
+    fn process_database(database: &str) -> Option<Vec<u8>> {
+        match database.is_empty() {
+            true => None,
+            false => Some(database.as_bytes().to_vec()),
+        }
+    }
+
     fn process_extra_info(session_extra_info: &Option<String>) -> Option<Vec<u8>> {
         session_extra_info.clone().map(|s| s.into_bytes())
     }
 
+    /// Truncates the query text to at most `max_length` characters.
+    fn process_query(query: &Option<String>, max_length: usize) -> Option<Vec<u8>> {
+        query
+            .as_ref()
+            .map(|query| match query.char_indices().nth(max_length) {
+                Some((end, _)) => query[..end].as_bytes().to_vec(),
+                None => query.clone().into_bytes(),
+            })
+    }
+
     fn process_dal_metrics(dal_metrics_opt: &Option<DalMetrics>) -> (Option<u64>, Option<u64>) {
         if dal_metrics_opt.is_some() {
             let dal_metrics = dal_metrics_opt.as_ref().unwrap();
@@ -169,4 +242,26 @@ impl ProcessesTable {
             (None, None)
         }
     }
+
+    /// The scanned percentage of the estimated total, by rows and else by bytes.
+    /// None if the read plans give no estimate.
+    fn process_progress_percent(
+        scan_progress_opt: &Option<ProgressValues>,
+        total_scan_progress_opt: &Option<ProgressValues>,
+    ) -> Option<f64> {
+        let (scan_progress, total_scan_progress) = (
+            scan_progress_opt.as_ref()?,
+            total_scan_progress_opt.as_ref()?,
+        );
+        let (scanned, total) = if total_scan_progress.rows != 0 {
+            (scan_progress.rows, total_scan_progress.rows)
+        } else if total_scan_progress.bytes != 0 {
+            (scan_progress.bytes, total_scan_progress.bytes)
+        } else {
+            return None;
+        };
+
+        // The estimate may be short of what is actually scanned.
+        Some((scanned as f64 * 100.0 / total as f64).min(100.0))
+    }
 }
@@ -13,15 +13,23 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::sync::Arc;
 
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::ipc::write::StreamWriter;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_base::base::tokio;
+use common_base::base::tokio::sync::mpsc;
+use common_base::base::tokio::sync::mpsc::error::TrySendError;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
@@ -30,6 +38,7 @@ use common_planners::TruncateTablePlan;
 use common_streams::SendableDataBlockStream;
 use futures::StreamExt;
 use parking_lot::RwLock;
+use tracing::warn;
 
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::ProcessorPtr;
@@ -40,15 +49,323 @@ use crate::pipelines::SourcePipeBuilder;
 use crate::sessions::TableContext;
 use crate::storages::Table;
 
+const LOG_TYPE: &str = "log_type";
+const QUERY_ID: &str = "query_id";
+const EVENT_TIME: &str = "event_time";
+const QUERY_DURATION_MS: &str = "query_duration_ms";
+
+// The log_type of the row written when a query starts.
+const LOG_TYPE_START: i64 = 1;
+
+/// An inclusive `event_time` window in microseconds, unbounded side is None.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QueryLogTimeWindow {
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
+
+impl QueryLogTimeWindow {
+    pub fn contains(&self, event_time: i64) -> bool {
+        self.since.map_or(true, |since| event_time >= since)
+            && self.until.map_or(true, |until| event_time <= until)
+    }
+
+    /// Narrow the window by `event_time <op> value`, return false if the op is not a comparison.
+    fn narrow(&mut self, op: &str, value: i64) -> bool {
+        let (since, until) = match op {
+            ">" => (value.checked_add(1), None),
+            ">=" => (Some(value), None),
+            "<" => (None, value.checked_sub(1)),
+            "<=" => (None, Some(value)),
+            "=" => (Some(value), Some(value)),
+            _ => return false,
+        };
+
+        if let Some(since) = since {
+            self.since = Some(self.since.map_or(since, |v| v.max(since)));
+        }
+        if let Some(until) = until {
+            self.until = Some(self.until.map_or(until, |v| v.min(until)));
+        }
+        true
+    }
+
+    /// Returns the window and whether every filter is fully evaluated by the window.
+    ///
+    /// Only comparisons between `event_time` and an integer literal are recognized,
+    /// other filters are left to the filter executor after the scan.
+    ///
+    /// The filters are not constant folded, so a bound such as `now() - INTERVAL 1 HOUR`
+    /// is not recognized: the whole store is scanned and the limit is not pushed down.
+    pub fn from_filters(filters: &[Expression]) -> (QueryLogTimeWindow, bool) {
+        let mut window = QueryLogTimeWindow::default();
+        let mut all_consumed = true;
+
+        for filter in filters {
+            let consumed = match filter {
+                Expression::BinaryExpression { left, op, right } => {
+                    match (left.as_ref(), right.as_ref()) {
+                        (Expression::Column(name), Expression::Literal { value, .. })
+                            if name == EVENT_TIME =>
+                        {
+                            match value.as_i64() {
+                                Ok(v) => window.narrow(op, v),
+                                Err(_) => false,
+                            }
+                        }
+                        (Expression::Literal { value, .. }, Expression::Column(name))
+                            if name == EVENT_TIME =>
+                        {
+                            let op = match op.as_str() {
+                                ">" => "<",
+                                ">=" => "<=",
+                                "<" => ">",
+                                "<=" => ">=",
+                                other => other,
+                            };
+                            match value.as_i64() {
+                                Ok(v) => window.narrow(op, v),
+                                Err(_) => false,
+                            }
+                        }
+                        _ => false,
+                    }
+                }
+                _ => false,
+            };
+            all_consumed &= consumed;
+        }
+
+        (window, all_consumed)
+    }
+}
+
+/// An external target the query logs are forwarded to, such as a table or a channel.
+///
+/// The sink is called on a background task, the blocks have the `log_schema` of `QueryLogTable`.
+#[async_trait::async_trait]
+pub trait QueryLogSink: Send + Sync {
+    fn name(&self) -> &str;
+
+    async fn write(&self, block: DataBlock) -> Result<()>;
+}
+
+// The bounded queue in front of a sink, the blocks are dropped if it is full.
+struct QueryLogSinkQueue {
+    name: String,
+    tx: mpsc::Sender<DataBlock>,
+}
+
+impl QueryLogSinkQueue {
+    fn create(sink: Arc<dyn QueryLogSink>, queue_size: usize) -> Self {
+        let name = sink.name().to_string();
+        let (tx, mut rx) = mpsc::channel::<DataBlock>(queue_size.max(1));
+
+        tokio::spawn(async move {
+            while let Some(block) = rx.recv().await {
+                if let Err(cause) = sink.write(block).await {
+                    warn!("Query log sink {} failed to write: {}", sink.name(), cause);
+                }
+            }
+        });
+
+        QueryLogSinkQueue { name, tx }
+    }
+
+    fn send(&self, block: DataBlock) {
+        match self.tx.try_send(block) {
+            Ok(_) => {}
+            Err(TrySendError::Full(_)) => {
+                warn!("Query log sink {} is full, the log is dropped", self.name);
+            }
+            Err(TrySendError::Closed(_)) => {
+                warn!("Query log sink {} is closed, the log is dropped", self.name);
+            }
+        }
+    }
+}
+
+/// The recent query logs kept in memory, bounded by `max_rows` blocks.
+///
+/// Blocks are appended in the order the queries are logged,
+/// so the newest `event_time` is at the back.
+/// The appended blocks are also forwarded to the registered sinks.
+pub struct QueryLogMemoryStore {
+    max_rows: usize,
+    data: RwLock<QueryLogData>,
+    sinks: RwLock<Vec<QueryLogSinkQueue>>,
+}
+
+#[derive(Default)]
+struct QueryLogData {
+    blocks: VecDeque<DataBlock>,
+    // query_id -> event_time of the start log still kept in `blocks`.
+    start_times: HashMap<Vec<u8>, i64>,
+}
+
+impl QueryLogData {
+    /// Calls `f(query_id, event_time)` on every start log of the block.
+    fn for_each_start(block: &DataBlock, mut f: impl FnMut(&[u8], i64)) -> Result<()> {
+        let log_types = block.try_column_by_name(LOG_TYPE)?;
+        let query_ids = block.try_column_by_name(QUERY_ID)?;
+        let query_ids = Series::check_get_scalar::<Vu8>(query_ids)?;
+        let event_times = block.try_column_by_name(EVENT_TIME)?;
+
+        for row in 0..block.num_rows() {
+            if log_types.get_i64(row)? == LOG_TYPE_START {
+                f(query_ids.get_data(row), event_times.get_i64(row)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl QueryLogMemoryStore {
+    pub fn create(max_rows: usize) -> Self {
+        QueryLogMemoryStore {
+            max_rows,
+            data: RwLock::new(QueryLogData::default()),
+            sinks: RwLock::new(vec![]),
+        }
+    }
+
+    /// Forwards the appended logs to the sink, at most `queue_size` blocks are buffered for it.
+    ///
+    /// It must be called within a tokio runtime, the sink is called on a spawned task
+    /// so a slow sink never blocks the query.
+    pub fn add_sink(&self, sink: Arc<dyn QueryLogSink>, queue_size: usize) {
+        self.sinks
+            .write()
+            .push(QueryLogSinkQueue::create(sink, queue_size));
+    }
+
+    pub fn append(&self, block: DataBlock) -> Result<()> {
+        for sink in self.sinks.read().iter() {
+            sink.send(block.clone());
+        }
+
+        let mut data = self.data.write();
+        let start_times = &mut data.start_times;
+        QueryLogData::for_each_start(&block, |query_id, event_time| {
+            start_times.insert(query_id.to_vec(), event_time);
+        })?;
+        data.blocks.push_back(block);
+
+        // Check overflow.
+        while data.blocks.len() > self.max_rows {
+            if let Some(evicted) = data.blocks.pop_front() {
+                let start_times = &mut data.start_times;
+                QueryLogData::for_each_start(&evicted, |query_id, event_time| {
+                    if start_times.get(query_id) == Some(&event_time) {
+                        start_times.remove(query_id);
+                    }
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn clear(&self) {
+        let mut data = self.data.write();
+        *data = QueryLogData::default();
+    }
+
+    /// Returns all the blocks, oldest first.
+    pub fn all(&self) -> VecDeque<DataBlock> {
+        self.data.read().blocks.clone()
+    }
+
+    /// Serializes all the logs as an Arrow IPC stream of `schema`, one record batch per block.
+    ///
+    /// The blocks are taken under a single read lock,
+    /// so the stream is a consistent snapshot of the store.
+    pub fn export_arrow_ipc(&self, schema: &DataSchemaRef) -> Result<Vec<u8>> {
+        let blocks = self.all();
+
+        let mut writer = StreamWriter::new(vec![], WriteOptions { compression: None });
+        writer.start(&schema.to_arrow(), None)?;
+        for block in blocks {
+            writer.write(&Chunk::try_from(block)?, None)?;
+        }
+        writer.finish()?;
+        Ok(writer.into_inner())
+    }
+
+    /// Returns at most `limit` rows within the window, newest first.
+    ///
+    /// The scan walks from the newest block and stops as soon as `limit` rows are collected.
+    pub fn recent(
+        &self,
+        window: &QueryLogTimeWindow,
+        limit: Option<usize>,
+    ) -> Result<VecDeque<DataBlock>> {
+        let mut result = VecDeque::new();
+        let mut remain = limit.unwrap_or(usize::MAX);
+
+        let data = self.data.read();
+        for block in data.blocks.iter().rev() {
+            if remain == 0 {
+                break;
+            }
+
+            let event_times = block.try_column_by_name(EVENT_TIME)?;
+            let mut indices = vec![];
+            for row in (0..block.num_rows()).rev() {
+                if indices.len() == remain {
+                    break;
+                }
+                if window.contains(event_times.get_i64(row)?) {
+                    indices.push(row as u32);
+                }
+            }
+
+            if !indices.is_empty() {
+                remain -= indices.len();
+                result.push_back(DataBlock::block_take_by_indices(block, &indices)?);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the duration in milliseconds of each row of the block.
+    ///
+    /// It is computed from the start log of the same query,
+    /// NULL for start logs and for queries whose start log is no longer kept.
+    pub fn query_durations(&self, block: &DataBlock) -> Result<ColumnRef> {
+        let log_types = block.try_column_by_name(LOG_TYPE)?;
+        let query_ids = block.try_column_by_name(QUERY_ID)?;
+        let query_ids = Series::check_get_scalar::<Vu8>(query_ids)?;
+        let event_times = block.try_column_by_name(EVENT_TIME)?;
+
+        let data = self.data.read();
+        let mut durations = Vec::with_capacity(block.num_rows());
+        for row in 0..block.num_rows() {
+            let duration = match log_types.get_i64(row)? {
+                LOG_TYPE_START => None,
+                _ => {
+                    let event_time = event_times.get_i64(row)?;
+                    data.start_times
+                        .get(query_ids.get_data(row))
+                        .map(|start| (event_time - start).max(0) as u64 / 1000)
+                }
+            };
+            durations.push(duration);
+        }
+
+        Ok(Series::from_data(durations))
+    }
+}
+
 pub struct QueryLogTable {
     table_info: TableInfo,
-    max_rows: i32,
-    data: Arc<RwLock<VecDeque<DataBlock>>>,
+    log_schema: DataSchemaRef,
+    store: Arc<QueryLogMemoryStore>,
 }
 
 impl QueryLogTable {
     pub fn create(table_id: u64, max_rows: i32) -> Self {
-        let schema = DataSchemaRefExt::create(vec![
+        let log_schema = DataSchemaRefExt::create(vec![
             // Type.
             DataField::new("log_type", i8::to_data_type()),
             DataField::new("handler_type", Vu8::to_data_type()),
@@ -61,6 +378,7 @@ impl QueryLogTable {
             // Query.
             DataField::new("query_id", Vu8::to_data_type()),
             DataField::new("query_kind", Vu8::to_data_type()),
+            DataField::new("query_category", Vu8::to_data_type()),
             DataField::new("query_text", Vu8::to_data_type()),
             DataField::new("event_date", DateType::new_impl()),
             DataField::new("event_time", TimestampType::new_impl(3)),
@@ -88,10 +406,12 @@ impl QueryLogTable {
             // Client.
             DataField::new("client_info", Vu8::to_data_type()),
             DataField::new("client_address", Vu8::to_data_type()),
+            DataField::new("client_user_agent", Vu8::to_data_type()),
             // Exception.
-            DataField::new("exception_code", i32::to_data_type()),
-            DataField::new("exception_text", Vu8::to_data_type()),
+            DataField::new_nullable("exception_code", i32::to_data_type()),
+            DataField::new_nullable("exception_text", Vu8::to_data_type()),
             DataField::new("stack_trace", Vu8::to_data_type()),
+            DataField::new_nullable("aborted_by", Vu8::to_data_type()),
             // Server.
             DataField::new("server_version", Vu8::to_data_type()),
             // Session settings
@@ -100,6 +420,14 @@ impl QueryLogTable {
             DataField::new("extra", Vu8::to_data_type()),
         ]);
 
+        // Columns computed at read time.
+        let mut fields = log_schema.fields().clone();
+        fields.push(DataField::new_nullable(
+            QUERY_DURATION_MS,
+            u64::to_data_type(),
+        ));
+        let schema = DataSchemaRefExt::create(fields);
+
         let table_info = TableInfo {
             desc: "'system'.'query_log'".to_string(),
             name: "query_log".to_string(),
@@ -113,9 +441,63 @@ impl QueryLogTable {
 
         QueryLogTable {
             table_info,
-            max_rows,
-            data: Arc::new(RwLock::new(VecDeque::new())),
+            log_schema,
+            store: Arc::new(QueryLogMemoryStore::create(max_rows.max(0) as usize)),
+        }
+    }
+
+    /// The schema of the blocks appended by `append_data`,
+    /// it is the table schema without the columns computed at read time.
+    pub fn log_schema(&self) -> DataSchemaRef {
+        self.log_schema.clone()
+    }
+
+    pub fn store(&self) -> Arc<QueryLogMemoryStore> {
+        self.store.clone()
+    }
+
+    /// Appends the columns computed at read time to the stored blocks.
+    fn complete_blocks(&self, blocks: VecDeque<DataBlock>) -> Result<VecDeque<DataBlock>> {
+        let schema = self.table_info.schema();
+        blocks
+            .into_iter()
+            .map(|block| {
+                let mut columns = block.columns().to_vec();
+                columns.push(self.store.query_durations(&block)?);
+                Ok(DataBlock::create(schema.clone(), columns))
+            })
+            .collect()
+    }
+
+    /// Returns the blocks to read for the push downs.
+    ///
+    /// If the push downs carry an `event_time` window, only the rows inside it are returned,
+    /// newest first, and a pushed down limit is applied when it is safe to do so.
+    fn read_blocks(&self, push_downs: &Option<Extras>) -> Result<VecDeque<DataBlock>> {
+        let push_downs = match push_downs {
+            None => return self.complete_blocks(self.store.all()),
+            Some(push_downs) => push_downs,
+        };
+
+        let (window, all_consumed) = QueryLogTimeWindow::from_filters(&push_downs.filters);
+        let ordered_by_time_desc = match push_downs.order_by.as_slice() {
+            [] => true,
+            [Expression::Sort { expr, asc, .. }] => {
+                !*asc && matches!(expr.as_ref(), Expression::Column(name) if name == EVENT_TIME)
+            }
+            _ => false,
+        };
+
+        // The limit can be applied only if no other filter would drop the rows we return.
+        let limit = match (all_consumed, ordered_by_time_desc) {
+            (true, true) => push_downs.limit,
+            _ => None,
+        };
+
+        if window == QueryLogTimeWindow::default() && limit.is_none() {
+            return self.complete_blocks(self.store.all());
         }
+        self.complete_blocks(self.store.recent(&window, limit)?)
     }
 
     pub async fn append_data(
@@ -125,15 +507,7 @@ impl QueryLogTable {
     ) -> Result<()> {
         while let Some(block) = stream.next().await {
             let block = block?;
-            self.data.write().push_back(block);
-        }
-
-        // Check overflow.
-        let over = self.data.read().len() as i32 - self.max_rows;
-        if over > 0 {
-            for _x in 0..over {
-                self.data.write().pop_front();
-            }
+            self.store.append(block)?;
         }
 
         Ok(())
@@ -161,17 +535,15 @@ impl Table for QueryLogTable {
     fn read2(
         &self,
         ctx: Arc<dyn TableContext>,
-        _: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         // TODO: split data for multiple threads
         let output = OutputPort::create();
         let mut source_builder = SourcePipeBuilder::create();
 
-        source_builder.add_source(
-            output.clone(),
-            QueryLogSource::create(ctx, output, &self.data.read())?,
-        );
+        let data = self.read_blocks(&plan.push_downs)?;
+        source_builder.add_source(output.clone(), QueryLogSource::create(ctx, output, data)?);
 
         pipeline.add_pipe(source_builder.finalize());
         Ok(())
@@ -182,13 +554,13 @@ impl Table for QueryLogTable {
         _ctx: Arc<dyn TableContext>,
         _truncate_plan: TruncateTablePlan,
     ) -> Result<()> {
-        let mut data = self.data.write();
-        *data = VecDeque::new();
+        self.store.clear();
         Ok(())
     }
 }
 
 struct QueryLogSource {
+    ctx: Arc<dyn TableContext>,
     data: VecDeque<DataBlock>,
 }
 
@@ -196,9 +568,9 @@ impl QueryLogSource {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
-        data: &VecDeque<DataBlock>,
+        data: VecDeque<DataBlock>,
     ) -> Result<ProcessorPtr> {
-        SyncSourcer::create(ctx, output, QueryLogSource { data: data.clone() })
+        SyncSourcer::create(ctx.clone(), output, QueryLogSource { ctx, data })
     }
 }
 
@@ -206,6 +578,11 @@ impl SyncSource for QueryLogSource {
     const NAME: &'static str = "system.query_log";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
+        // A killed query stops here, the blocks not read yet are released at once.
+        if self.ctx.is_aborting() {
+            self.data.clear();
+            return Ok(None);
+        }
         Ok(self.data.pop_front())
     }
 }
@@ -0,0 +1,229 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+use common_planners::TruncateTablePlan;
+use parking_lot::RwLock;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::SyncSource;
+use crate::pipelines::processors::SyncSourcer;
+use crate::pipelines::Pipeline;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+const QUERY_ID: &str = "query_id";
+
+/// The operator profiles of the recent queries kept in memory, bounded by `max_queries`.
+pub struct QueryProfileMemoryStore {
+    max_queries: usize,
+    // (query_id, profile block), the newest query is at the back.
+    profiles: RwLock<VecDeque<(String, DataBlock)>>,
+}
+
+impl QueryProfileMemoryStore {
+    pub fn create(max_queries: usize) -> Self {
+        QueryProfileMemoryStore {
+            max_queries,
+            profiles: RwLock::new(VecDeque::new()),
+        }
+    }
+
+    pub fn append(&self, query_id: &str, block: DataBlock) {
+        let mut profiles = self.profiles.write();
+        profiles.push_back((query_id.to_string(), block));
+
+        // Check overflow.
+        while profiles.len() > self.max_queries {
+            profiles.pop_front();
+        }
+    }
+
+    pub fn clear(&self) {
+        self.profiles.write().clear();
+    }
+
+    /// Returns the profile blocks of all the queries, oldest first.
+    pub fn all(&self) -> VecDeque<DataBlock> {
+        let profiles = self.profiles.read();
+        profiles.iter().map(|(_, block)| block.clone()).collect()
+    }
+
+    /// Returns the profile blocks of the query.
+    pub fn get(&self, query_id: &str) -> VecDeque<DataBlock> {
+        let profiles = self.profiles.read();
+        profiles
+            .iter()
+            .filter(|(id, _)| id == query_id)
+            .map(|(_, block)| block.clone())
+            .collect()
+    }
+}
+
+pub struct QueryProfileTable {
+    table_info: TableInfo,
+    store: Arc<QueryProfileMemoryStore>,
+}
+
+impl QueryProfileTable {
+    pub fn create(table_id: u64, max_queries: usize) -> Self {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("processor_id", u64::to_data_type()),
+            DataField::new("operator", Vu8::to_data_type()),
+            DataField::new("output_rows", u64::to_data_type()),
+            DataField::new("output_bytes", u64::to_data_type()),
+            DataField::new("wall_time_us", u64::to_data_type()),
+            DataField::new("cpu_time_us", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_profile'".to_string(),
+            name: "query_profile".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryProfile".to_string(),
+                ..Default::default()
+            },
+        };
+
+        QueryProfileTable {
+            table_info,
+            store: Arc::new(QueryProfileMemoryStore::create(max_queries)),
+        }
+    }
+
+    pub fn store(&self) -> Arc<QueryProfileMemoryStore> {
+        self.store.clone()
+    }
+
+    /// Returns the query id of a `query_id = '...'` filter, if any.
+    fn query_id_of_filters(filters: &[Expression]) -> Option<String> {
+        filters.iter().find_map(|filter| match filter {
+            Expression::BinaryExpression { left, op, right } if op == "=" => {
+                match (left.as_ref(), right.as_ref()) {
+                    (Expression::Column(name), Expression::Literal { value, .. })
+                    | (Expression::Literal { value, .. }, Expression::Column(name))
+                        if name == QUERY_ID =>
+                    {
+                        match value {
+                            DataValue::String(v) => Some(String::from_utf8_lossy(v).to_string()),
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the blocks to read, only the profile of one query if the push downs filter on it.
+    fn read_blocks(&self, push_downs: &Option<Extras>) -> VecDeque<DataBlock> {
+        let query_id = push_downs
+            .as_ref()
+            .and_then(|extras| Self::query_id_of_filters(&extras.filters));
+
+        match query_id {
+            Some(query_id) => self.store.get(&query_id),
+            None => self.store.all(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryProfileTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::default(), vec![]))
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        let mut source_builder = SourcePipeBuilder::create();
+
+        let data = self.read_blocks(&plan.push_downs);
+        source_builder.add_source(
+            output.clone(),
+            QueryProfileSource::create(ctx, output, data)?,
+        );
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    async fn truncate(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _truncate_plan: TruncateTablePlan,
+    ) -> Result<()> {
+        self.store.clear();
+        Ok(())
+    }
+}
+
+struct QueryProfileSource {
+    data: VecDeque<DataBlock>,
+}
+
+impl QueryProfileSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        data: VecDeque<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        SyncSourcer::create(ctx, output, QueryProfileSource { data })
+    }
+}
+
+impl SyncSource for QueryProfileSource {
+    const NAME: &'static str = "system.query_profile";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.data.pop_front())
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use common_metrics::MetricSample;
+use futures::future::join_all;
+use tracing::warn;
+
+use crate::sessions::TableContext;
+
+/// Reads the rows of the system tables from another node of the cluster, see the
+/// `tracing_cluster` and `metrics_cluster` settings.
+#[async_trait::async_trait]
+pub trait RemoteNodeReader: Send + Sync {
+    /// Reads the lines of the log files of the node, at most `limit` lines.
+    async fn read_log_lines(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        node: &NodeInfo,
+        limit: Option<usize>,
+    ) -> Result<Vec<String>>;
+
+    /// Reads the metric samples of the node.
+    async fn read_metric_samples(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        node: &NodeInfo,
+    ) -> Result<Vec<MetricSample>>;
+}
+
+/// Reads the other nodes of the cluster with `read` concurrently, in the order of the nodes.
+///
+/// A node which can not be read is skipped with a warning, so the system tables still return
+/// the rows of the others. Returns the id of each node which is read with its result.
+pub async fn read_remote_nodes<T, F, Fut>(ctx: &Arc<dyn TableContext>, read: F) -> Vec<(String, T)>
+where
+    F: Fn(Arc<NodeInfo>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let cluster = ctx.get_cluster();
+    let reads = cluster
+        .nodes
+        .iter()
+        .filter(|node| node.id != cluster.local_id)
+        .map(|node| {
+            let res = read(node.clone());
+            async move { (node.id.clone(), res.await) }
+        });
+
+    join_all(reads)
+        .await
+        .into_iter()
+        .filter_map(|(node, res)| match res {
+            Ok(value) => Some((node, value)),
+            Err(cause) => {
+                warn!("Cannot read node {}, its rows are omitted: {}", node, cause);
+                None
+            }
+        })
+        .collect()
+}
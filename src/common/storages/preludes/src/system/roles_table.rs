@@ -53,9 +53,16 @@ impl AsyncSystemTable for RolesTable {
             .iter()
             .map(|x| x.grants.roles().len() as u64)
             .collect();
+        // At most one role is the one the session is acting as.
+        let current_role = ctx.get_current_role();
+        let is_currents: Vec<bool> = roles
+            .iter()
+            .map(|x| current_role.as_ref() == Some(&x.name))
+            .collect();
         Ok(DataBlock::create(self.table_info.schema(), vec![
             Series::from_data(names),
             Series::from_data(inherited_roles),
+            Series::from_data(is_currents),
         ]))
     }
 }
@@ -65,6 +72,7 @@ impl RolesTable {
         let schema = DataSchemaRefExt::create(vec![
             DataField::new("name", Vu8::to_data_type()),
             DataField::new("inherited_roles", u64::to_data_type()),
+            DataField::new("is_current", bool::to_data_type()),
         ]);
 
         let table_info = TableInfo {
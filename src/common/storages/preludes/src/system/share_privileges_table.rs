@@ -0,0 +1,80 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::share::valid_privileges_for;
+use common_meta_app::share::ShareGrantObjectKind;
+
+use crate::sessions::TableContext;
+use crate::storages::system::table::SyncOneBlockSystemTable;
+use crate::storages::system::table::SyncSystemTable;
+use crate::storages::Table;
+
+/// The privileges that can be granted to a share, one row for each kind of object and privilege.
+pub struct SharePrivilegesTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for SharePrivilegesTable {
+    const NAME: &'static str = "system.share_privileges";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let mut object_kinds: Vec<Vec<u8>> = vec![];
+        let mut privileges: Vec<Vec<u8>> = vec![];
+        for kind in [ShareGrantObjectKind::Database, ShareGrantObjectKind::Table] {
+            for privilege in valid_privileges_for(kind).iter() {
+                object_kinds.push(kind.to_string().into_bytes());
+                privileges.push(privilege.to_string().into_bytes());
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(object_kinds),
+            Series::from_data(privileges),
+        ]))
+    }
+}
+
+impl SharePrivilegesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("object_kind", Vu8::to_data_type()),
+            DataField::new("privilege", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'share_privileges'".to_string(),
+            name: "share_privileges".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemSharePrivileges".to_string(),
+                ..Default::default()
+            },
+        };
+
+        SyncOneBlockSystemTable::create(SharePrivilegesTable { table_info })
+    }
+}
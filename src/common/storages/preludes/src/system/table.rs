@@ -16,13 +16,17 @@ use std::any::Any;
 use std::sync::Arc;
 
 use common_datablocks::DataBlock;
+use common_datavalues::DataValue;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
+use common_planners::Expression;
 use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
+use itertools::Itertools;
 
+use crate::catalogs::Catalog;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::ProcessorPtr;
 use crate::pipelines::processors::AsyncSource;
@@ -40,6 +44,17 @@ pub trait SyncSystemTable: Send + Sync {
     fn get_table_info(&self) -> &TableInfo;
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock>;
 
+    /// Returns the data narrowed by the push downs, defaults to the full data.
+    ///
+    /// The filters are still evaluated after the scan, so the rows are not required to match them.
+    fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        self.get_full_data(ctx)
+    }
+
     fn get_partitions(
         &self,
         _ctx: Arc<dyn TableContext>,
@@ -84,7 +99,7 @@ impl<TTable: 'static + SyncSystemTable> Table for SyncOneBlockSystemTable<TTable
     fn read2(
         &self,
         ctx: Arc<dyn TableContext>,
-        _: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let output = OutputPort::create();
@@ -94,6 +109,7 @@ impl<TTable: 'static + SyncSystemTable> Table for SyncOneBlockSystemTable<TTable
                 ctx,
                 output.clone(),
                 inner_table,
+                plan.push_downs.clone(),
             )?],
             inputs_port: vec![],
             outputs_port: vec![output],
@@ -107,6 +123,7 @@ struct SystemTableSyncSource<TTable: 'static + SyncSystemTable> {
     finished: bool,
     inner: Arc<TTable>,
     context: Arc<dyn TableContext>,
+    push_downs: Option<Extras>,
 }
 
 impl<TTable: 'static + SyncSystemTable> SystemTableSyncSource<TTable>
@@ -116,10 +133,12 @@ where Self: SyncSource
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
         inner: Arc<TTable>,
+        push_downs: Option<Extras>,
     ) -> Result<ProcessorPtr> {
         SyncSourcer::create(ctx.clone(), output, SystemTableSyncSource::<TTable> {
             inner,
             context: ctx,
+            push_downs,
             finished: false,
         })
     }
@@ -134,7 +153,11 @@ impl<TTable: 'static + SyncSystemTable> SyncSource for SystemTableSyncSource<TTa
         }
 
         self.finished = true;
-        Ok(Some(self.inner.get_full_data(self.context.clone())?))
+        let push_downs = self.push_downs.take();
+        Ok(Some(self.inner.get_data_with_push_downs(
+            self.context.clone(),
+            push_downs,
+        )?))
     }
 }
 
@@ -145,6 +168,17 @@ pub trait AsyncSystemTable: Send + Sync {
     fn get_table_info(&self) -> &TableInfo;
     async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock>;
 
+    /// Returns the data narrowed by the push downs, defaults to the full data.
+    ///
+    /// The filters are still evaluated after the scan, so the rows are not required to match them.
+    async fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        self.get_full_data(ctx).await
+    }
+
     async fn get_partitions(
         &self,
         _ctx: Arc<dyn TableContext>,
@@ -189,7 +223,7 @@ impl<TTable: 'static + AsyncSystemTable> Table for AsyncOneBlockSystemTable<TTab
     fn read2(
         &self,
         ctx: Arc<dyn TableContext>,
-        _: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let output = OutputPort::create();
@@ -199,6 +233,7 @@ impl<TTable: 'static + AsyncSystemTable> Table for AsyncOneBlockSystemTable<TTab
                 output.clone(),
                 inner_table,
                 ctx,
+                plan.push_downs.clone(),
             )?],
             inputs_port: vec![],
             outputs_port: vec![output],
@@ -212,6 +247,7 @@ struct SystemTableAsyncSource<TTable: 'static + AsyncSystemTable> {
     finished: bool,
     inner: Arc<TTable>,
     context: Arc<dyn TableContext>,
+    push_downs: Option<Extras>,
 }
 
 impl<TTable: 'static + AsyncSystemTable> SystemTableAsyncSource<TTable>
@@ -221,10 +257,12 @@ where Self: AsyncSource
         output: Arc<OutputPort>,
         inner: Arc<TTable>,
         context: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
     ) -> Result<ProcessorPtr> {
         AsyncSourcer::create(context.clone(), output, SystemTableAsyncSource::<TTable> {
             inner,
             context,
+            push_downs,
             finished: false,
         })
     }
@@ -241,6 +279,79 @@ impl<TTable: 'static + AsyncSystemTable> AsyncSource for SystemTableAsyncSource<
         }
 
         self.finished = true;
-        Ok(Some(self.inner.get_full_data(self.context.clone()).await?))
+        let push_downs = self.push_downs.take();
+        Ok(Some(
+            self.inner
+                .get_data_with_push_downs(self.context.clone(), push_downs)
+                .await?,
+        ))
     }
 }
+
+/// Returns the value of a `<column> = '<value>'` filter, the filters are conjunctive.
+pub(crate) fn find_eq_filter(filters: &[Expression], column: &str) -> Option<String> {
+    filters.iter().find_map(|filter| match filter {
+        Expression::BinaryExpression { left, op, right } if op == "and" => {
+            find_eq_filter(&[left.as_ref().clone(), right.as_ref().clone()], column)
+        }
+        Expression::BinaryExpression { left, op, right } if op == "=" => {
+            match (left.as_ref(), right.as_ref()) {
+                (Expression::Column(name), Expression::Literal { value, .. })
+                | (Expression::Literal { value, .. }, Expression::Column(name))
+                    if name == column =>
+                {
+                    match value {
+                        DataValue::String(v) => Some(String::from_utf8_lossy(v).to_string()),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// Returns the literal prefix of a `<column> LIKE '<pattern>'` filter, the filters are conjunctive.
+///
+/// The prefix stops at the first wildcard or escape, every value matching the pattern starts with it.
+pub(crate) fn find_like_prefix_filter(filters: &[Expression], column: &str) -> Option<String> {
+    filters.iter().find_map(|filter| match filter {
+        Expression::BinaryExpression { left, op, right } if op == "and" => {
+            find_like_prefix_filter(&[left.as_ref().clone(), right.as_ref().clone()], column)
+        }
+        Expression::BinaryExpression { left, op, right } if op.eq_ignore_ascii_case("like") => {
+            match (left.as_ref(), right.as_ref()) {
+                (Expression::Column(name), Expression::Literal { value, .. }) if name == column => {
+                    match value {
+                        DataValue::String(v) => {
+                            let pattern = String::from_utf8_lossy(v);
+                            let prefix = pattern
+                                .split(|c| c == '%' || c == '_' || c == '\\')
+                                .next()
+                                .unwrap_or_default();
+                            Some(prefix.to_string())
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    })
+}
+
+/// Returns the catalogs ordered by name, only the named one if it is given.
+pub(crate) fn list_catalogs(
+    ctx: &Arc<dyn TableContext>,
+    catalog: Option<String>,
+) -> Vec<(String, Arc<dyn Catalog>)> {
+    ctx.get_catalogs()
+        .catalogs
+        .iter()
+        .filter(|(name, _)| catalog.as_ref().map_or(true, |catalog| catalog == *name))
+        .map(|(name, catalog)| (name.clone(), catalog.clone()))
+        .sorted_by(|(a, _), (b, _)| a.cmp(b))
+        .collect()
+}
@@ -0,0 +1,141 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::mask_string;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_planners::Extras;
+use common_storages_util::table_option_keys::is_credential_opt_key;
+use common_storages_util::table_option_keys::is_internal_opt_key;
+
+use crate::sessions::TableContext;
+use crate::storages::system::table::find_eq_filter;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+/// The options of the tables of the default catalog, one row for each option.
+pub struct TableOptionsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableOptionsTable {
+    const NAME: &'static str = "system.table_options";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        self.get_data_with_push_downs(ctx, None).await
+    }
+
+    async fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        let filters = push_downs.map(|extras| extras.filters).unwrap_or_default();
+        let database = find_eq_filter(&filters, "database");
+        let table = find_eq_filter(&filters, "table");
+
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+        let databases = match database {
+            Some(database) => match catalog.exists_database(tenant.as_str(), &database).await? {
+                true => vec![database],
+                false => vec![],
+            },
+            None => catalog
+                .list_databases(tenant.as_str())
+                .await?
+                .iter()
+                .map(|database| database.name().to_string())
+                .collect(),
+        };
+
+        let mut databases_col: Vec<Vec<u8>> = vec![];
+        let mut tables_col: Vec<Vec<u8>> = vec![];
+        let mut keys_col: Vec<Vec<u8>> = vec![];
+        let mut values_col: Vec<Vec<u8>> = vec![];
+        for database in databases {
+            let tables = match &table {
+                Some(table) => match catalog.get_table(tenant.as_str(), &database, table).await {
+                    Ok(table) => vec![table],
+                    Err(e) if e.code() == ErrorCode::UnknownTableCode() => vec![],
+                    Err(e) => return Err(e),
+                },
+                None => catalog.list_tables(tenant.as_str(), &database).await?,
+            };
+
+            for table in tables {
+                // Same as `SHOW CREATE TABLE`, the internal options are not shown.
+                let options = table
+                    .options()
+                    .iter()
+                    .filter(|(key, _)| !is_internal_opt_key(key));
+                for (key, value) in options {
+                    let value = match is_credential_opt_key(key) {
+                        true => mask_string(value, 3),
+                        false => value.clone(),
+                    };
+                    databases_col.push(database.clone().into_bytes());
+                    tables_col.push(table.name().as_bytes().to_vec());
+                    keys_col.push(key.clone().into_bytes());
+                    values_col.push(value.into_bytes());
+                }
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases_col),
+            Series::from_data(tables_col),
+            Series::from_data(keys_col),
+            Series::from_data(values_col),
+        ]))
+    }
+}
+
+impl TableOptionsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("option_key", Vu8::to_data_type()),
+            DataField::new("option_value", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'table_options'".to_string(),
+            name: "table_options".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTableOptions".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(TableOptionsTable { table_info })
+    }
+}
@@ -21,9 +21,16 @@ use common_exception::Result;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_planners::Extras;
+use common_storages_util::table_option_keys::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use common_storages_util::table_option_keys::OPT_KEY_LOCATION;
+use common_storages_util::table_option_keys::OPT_KEY_SNAPSHOT_LOCATION;
+use tracing::warn;
 
 use crate::catalogs::Catalog;
 use crate::sessions::TableContext;
+use crate::storages::system::table::find_eq_filter;
+use crate::storages::system::table::list_catalogs;
 use crate::storages::system::table::AsyncOneBlockSystemTable;
 use crate::storages::system::table::AsyncSystemTable;
 use crate::storages::Table;
@@ -80,16 +87,31 @@ where TablesTable<T>: HistoryAware
     }
 
     async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
-        let tenant = ctx.get_tenant();
-        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
-        let databases = catalog.list_databases(tenant.as_str()).await?;
+        self.get_data_with_push_downs(ctx, None).await
+    }
+
+    async fn get_data_with_push_downs(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<DataBlock> {
+        let filters = push_downs.map(|extras| extras.filters).unwrap_or_default();
+        let catalog = find_eq_filter(&filters, "catalog");
 
+        let tenant = ctx.get_tenant();
         let mut database_tables = vec![];
-        for database in databases {
-            let name = database.name();
-            let tables = Self::list_tables(&catalog, tenant.as_str(), name).await?;
-            for table in tables {
-                database_tables.push((name.to_string(), table));
+        for (catalog_name, catalog) in list_catalogs(&ctx, catalog) {
+            match Self::list_catalog_tables(&catalog, tenant.as_str()).await {
+                Ok(tables) => {
+                    for (database, table) in tables {
+                        database_tables.push((catalog_name.clone(), database, table));
+                    }
+                }
+                // The external catalogs may be unreachable.
+                Err(cause) if catalog_name != CATALOG_DEFAULT => {
+                    warn!("Skip the tables of catalog {}: {}", catalog_name, cause);
+                }
+                Err(cause) => return Err(cause),
             }
         }
 
@@ -97,27 +119,38 @@ where TablesTable<T>: HistoryAware
         let mut data_size: Vec<Option<u64>> = Vec::new();
         let mut data_compressed_size: Vec<Option<u64>> = Vec::new();
         let mut index_size: Vec<Option<u64>> = Vec::new();
+        let mut number_of_segments: Vec<Option<u64>> = Vec::new();
+        let mut number_of_blocks: Vec<Option<u64>> = Vec::new();
 
-        for (_, tbl) in &database_tables {
+        for (_, _, tbl) in &database_tables {
             let stats = tbl.statistics(ctx.clone()).await?;
             num_rows.push(stats.as_ref().and_then(|v| v.num_rows));
             data_size.push(stats.as_ref().and_then(|v| v.data_size));
             data_compressed_size.push(stats.as_ref().and_then(|v| v.data_size_compressed));
-            index_size.push(stats.and_then(|v| v.index_size));
+            index_size.push(stats.as_ref().and_then(|v| v.index_size));
+            number_of_segments.push(stats.as_ref().and_then(|v| v.number_of_segments));
+            number_of_blocks.push(stats.and_then(|v| v.number_of_blocks));
         }
 
-        let databases: Vec<&[u8]> = database_tables.iter().map(|(d, _)| d.as_bytes()).collect();
+        let catalogs: Vec<&[u8]> = database_tables
+            .iter()
+            .map(|(c, _, _)| c.as_bytes())
+            .collect();
+        let databases: Vec<&[u8]> = database_tables
+            .iter()
+            .map(|(_, d, _)| d.as_bytes())
+            .collect();
         let names: Vec<&[u8]> = database_tables
             .iter()
-            .map(|(_, v)| v.name().as_bytes())
+            .map(|(_, _, v)| v.name().as_bytes())
             .collect();
         let engines: Vec<&[u8]> = database_tables
             .iter()
-            .map(|(_, v)| v.engine().as_bytes())
+            .map(|(_, _, v)| v.engine().as_bytes())
             .collect();
         let created_ons: Vec<String> = database_tables
             .iter()
-            .map(|(_, v)| {
+            .map(|(_, _, v)| {
                 v.get_table_info()
                     .meta
                     .created_on
@@ -127,7 +160,7 @@ where TablesTable<T>: HistoryAware
             .collect();
         let dropped_ons: Vec<String> = database_tables
             .iter()
-            .map(|(_, v)| {
+            .map(|(_, _, v)| {
                 v.get_table_info()
                     .meta
                     .drop_on
@@ -138,7 +171,7 @@ where TablesTable<T>: HistoryAware
         let created_ons: Vec<&[u8]> = created_ons.iter().map(|s| s.as_bytes()).collect();
         let cluster_bys: Vec<String> = database_tables
             .iter()
-            .map(|(_, v)| {
+            .map(|(_, _, v)| {
                 v.get_table_info()
                     .meta
                     .default_cluster_key
@@ -146,6 +179,34 @@ where TablesTable<T>: HistoryAware
                     .unwrap_or_else(|| "".to_owned())
             })
             .collect();
+        let snapshot_locations: Vec<Option<String>> = database_tables
+            .iter()
+            .map(|(_, _, v)| Self::snapshot_location(v.as_ref()))
+            .collect();
+        let snapshot_ids: Vec<Option<Vec<u8>>> = snapshot_locations
+            .iter()
+            .map(|location| {
+                location
+                    .as_deref()
+                    .map(|location| snapshot_id_of_location(location).into_bytes())
+            })
+            .collect();
+        let snapshot_locations: Vec<Option<Vec<u8>>> = snapshot_locations
+            .into_iter()
+            .map(|location| location.map(String::into_bytes))
+            .collect();
+        let storage_locations: Vec<Option<Vec<u8>>> = database_tables
+            .iter()
+            .map(|(_, _, v)| {
+                v.options()
+                    .get(OPT_KEY_LOCATION)
+                    .map(|location| redact_location(location).into_bytes())
+            })
+            .collect();
+        let is_externals: Vec<bool> = storage_locations
+            .iter()
+            .map(|location| location.is_some())
+            .collect();
 
         Ok(DataBlock::create(self.table_info.schema(), vec![
             Series::from_data(databases),
@@ -158,6 +219,13 @@ where TablesTable<T>: HistoryAware
             Series::from_data(data_size),
             Series::from_data(data_compressed_size),
             Series::from_data(index_size),
+            Series::from_data(catalogs),
+            Series::from_data(snapshot_ids),
+            Series::from_data(snapshot_locations),
+            Series::from_data(is_externals),
+            Series::from_data(storage_locations),
+            Series::from_data(number_of_segments),
+            Series::from_data(number_of_blocks),
         ]))
     }
 }
@@ -165,6 +233,38 @@ where TablesTable<T>: HistoryAware
 impl<const T: bool> TablesTable<T>
 where TablesTable<T>: HistoryAware
 {
+    /// Returns the (database, table) of all the databases of the catalog.
+    async fn list_catalog_tables(
+        catalog: &Arc<dyn Catalog>,
+        tenant: &str,
+    ) -> Result<Vec<(String, Arc<dyn Table>)>> {
+        let databases = catalog.list_databases(tenant).await?;
+
+        let mut database_tables = vec![];
+        for database in databases {
+            let name = database.name();
+            let tables = Self::list_tables(catalog, tenant, name).await?;
+            for table in tables {
+                database_tables.push((name.to_string(), table));
+            }
+        }
+        Ok(database_tables)
+    }
+
+    /// The current snapshot of a fuse table, none for the other engines or a table without data.
+    fn snapshot_location(table: &dyn Table) -> Option<String> {
+        if !table.engine().eq_ignore_ascii_case("FUSE") {
+            return None;
+        }
+
+        let options = table.options();
+        options
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            // for backward compatibility, we check the legacy table option
+            .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+            .cloned()
+    }
+
     pub fn schema() -> Arc<DataSchema> {
         DataSchemaRefExt::create(vec![
             DataField::new("database", Vu8::to_data_type()),
@@ -177,6 +277,13 @@ where TablesTable<T>: HistoryAware
             DataField::new_nullable("data_size", u64::to_data_type()),
             DataField::new_nullable("data_compressed_size", u64::to_data_type()),
             DataField::new_nullable("index_size", u64::to_data_type()),
+            DataField::new("catalog", Vu8::to_data_type()),
+            DataField::new_nullable("snapshot_id", Vu8::to_data_type()),
+            DataField::new_nullable("snapshot_location", Vu8::to_data_type()),
+            DataField::new("is_external", bool::to_data_type()),
+            DataField::new_nullable("storage_location", Vu8::to_data_type()),
+            DataField::new_nullable("number_of_segments", u64::to_data_type()),
+            DataField::new_nullable("number_of_blocks", u64::to_data_type()),
         ])
     }
 
@@ -197,3 +304,23 @@ where TablesTable<T>: HistoryAware
         AsyncOneBlockSystemTable::create(TablesTable::<T> { table_info })
     }
 }
+
+/// A snapshot is named after its id, e.g. `<prefix>/_ss/<id>_v1.json`.
+fn snapshot_id_of_location(location: &str) -> String {
+    let file_name = location.rsplit('/').next().unwrap_or(location);
+    file_name.split('_').next().unwrap_or(file_name).to_string()
+}
+
+/// Masks the credentials in the user info of a location,
+/// e.g. `s3://key:secret@bucket/path` is shown as `s3://******@bucket/path`.
+fn redact_location(location: &str) -> String {
+    let (scheme, rest) = match location.split_once("://") {
+        Some(v) => v,
+        None => return location.to_string(),
+    };
+    let authority = rest.split('/').next().unwrap_or(rest);
+    match authority.rfind('@') {
+        Some(at) => format!("{}://******{}", scheme, &rest[at..]),
+        None => location.to_string(),
+    }
+}
@@ -18,6 +18,7 @@ use std::fs::File;
 use std::io::BufRead;
 use std::io::BufReader;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
@@ -30,21 +31,33 @@ use common_planners::Extras;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
 use common_planners::Statistics;
+use futures::StreamExt;
 use tracing::debug;
 use walkdir::WalkDir;
 
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::AsyncSourcer;
 use crate::pipelines::processors::SyncSource;
 use crate::pipelines::processors::SyncSourcer;
 use crate::pipelines::Pipe;
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
+use crate::storages::system::remote_nodes::read_remote_nodes;
+use crate::storages::system::remote_nodes::RemoteNodeReader;
+use crate::storages::system::tracing_table_stream::log_entries_to_block;
 use crate::storages::system::tracing_table_stream::LogEntry;
+use crate::storages::system::TracingTableStream;
 use crate::storages::Table;
 
+// How often the active log file is checked for appended lines in the follow mode.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
 pub struct TracingTable {
     table_info: TableInfo,
+    remote_reader: Option<Arc<dyn RemoteNodeReader>>,
+    follow: bool,
 }
 
 impl TracingTable {
@@ -58,7 +71,11 @@ impl TracingTable {
             DataField::new("level", i8::to_data_type()),
             DataField::new("hostname", Vu8::to_data_type()),
             DataField::new("pid", i64::to_data_type()),
-            DataField::new("time", Vu8::to_data_type()),
+            DataField::new_nullable("time", TimestampType::new_impl(6)),
+            // The structured fields of the record besides the columns above.
+            DataField::new("fields", VariantType::new_impl()),
+            // The id of the cluster node which wrote the record.
+            DataField::new("node", Vu8::to_data_type()),
         ]);
 
         let table_info = TableInfo {
@@ -72,12 +89,47 @@ impl TracingTable {
             },
         };
 
-        TracingTable { table_info }
+        TracingTable {
+            table_info,
+            remote_reader: None,
+            follow: false,
+        }
+    }
+
+    /// Keep streaming the lines appended to the active log file of the local node,
+    /// it serves the `tracing_follow()` table function.
+    pub fn with_follow(mut self) -> Self {
+        self.follow = true;
+        self
+    }
+
+    /// Read the logs of the other cluster nodes with the reader when `tracing_cluster` is enabled.
+    pub fn with_remote_reader(mut self, remote_reader: Arc<dyn RemoteNodeReader>) -> Self {
+        self.remote_reader = Some(remote_reader);
+        self
+    }
+
+    /// Reads the lines of the log files in `log_dir`, oldest first, at most `limit` lines.
+    ///
+    /// It serves the `RemoteNodeReader` of the other nodes.
+    pub fn read_log_lines(log_dir: &str, limit: Option<usize>) -> Result<Vec<String>> {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut lines = vec![];
+        for file_name in Self::log_files(log_dir)? {
+            let buffer = BufReader::new(File::open(file_name)?);
+            for line in buffer.lines() {
+                if lines.len() >= limit {
+                    return Ok(lines);
+                }
+                lines.push(line?);
+            }
+        }
+        Ok(lines)
     }
 
-    fn log_files(ctx: Arc<dyn TableContext>) -> Result<VecDeque<String>> {
-        debug!("list log files from {}", ctx.get_config().log.file.dir);
-        WalkDir::new(ctx.get_config().log.file.dir.as_str())
+    fn log_files(log_dir: &str) -> Result<VecDeque<String>> {
+        debug!("list log files from {}", log_dir);
+        WalkDir::new(log_dir)
             // NOTE:(everpcpc) ignore log files in subdir with different format
             .max_depth(1)
             .sort_by_key(|file| file.file_name().to_owned())
@@ -112,54 +164,74 @@ impl Table for TracingTable {
     fn read2(
         &self,
         ctx: Arc<dyn TableContext>,
-        _: &ReadDataSourcePlan,
+        plan: &ReadDataSourcePlan,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let settings = ctx.get_settings();
 
         let output = OutputPort::create();
-        let log_files = Self::log_files(ctx.clone())?;
+        let log_files = Self::log_files(ctx.get_config().log.file.dir.as_str())?;
         debug!("listed log files: {:?}", log_files);
         let schema = self.table_info.schema();
-        let max_block_size = settings.get_max_block_size()? as usize;
+        let cluster = ctx.get_cluster();
+        let limit = plan.push_downs.as_ref().and_then(|extras| extras.limit);
+
+        let mut outputs_port = vec![output.clone()];
+        let mut processors = vec![];
+
+        let stream =
+            TracingTableStream::try_create(schema.clone(), log_files, limit.unwrap_or(usize::MAX))?
+                .with_node(cluster.local_id.clone());
+        if self.follow {
+            let stream = stream.with_follow(FOLLOW_POLL_INTERVAL);
+            processors.push(TracingFollowSource::create(ctx.clone(), output, stream)?);
+        } else {
+            let max_block_size = settings.get_max_block_size()? as usize;
+            let stream = stream.with_max_block_size(max_block_size);
+            processors.push(TracingSource::create(ctx.clone(), output, stream)?);
+        }
+
+        if let Some(remote_reader) = &self.remote_reader {
+            let has_remote_nodes = cluster.nodes.iter().any(|node| node.id != cluster.local_id);
+
+            if settings.get_tracing_cluster()? && has_remote_nodes {
+                let output = OutputPort::create();
+                outputs_port.push(output.clone());
+                processors.push(TracingClusterSource::create(
+                    ctx,
+                    output,
+                    remote_reader.clone(),
+                    schema,
+                    limit,
+                )?);
+            }
+        }
 
         pipeline.add_pipe(Pipe::SimplePipe {
             inputs_port: vec![],
-            outputs_port: vec![output.clone()],
-            processors: vec![TracingSource::create(
-                ctx,
-                output,
-                max_block_size,
-                log_files,
-                schema,
-            )?],
+            outputs_port,
+            processors,
         });
 
         Ok(())
     }
 }
 
+/// Reads the log files of the local node once, up to the EOF of the active one.
+///
+/// The files are read through `TracingTableStream`, so a rotation of the active log file
+/// in the middle of the read does not lose or duplicate lines.
 struct TracingSource {
-    rows_pre_block: usize,
-    schema: DataSchemaRef,
-    tracing_files: VecDeque<String>,
-    data_blocks: VecDeque<DataBlock>,
+    stream: TracingTableStream,
 }
 
 impl TracingSource {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         output: Arc<OutputPort>,
-        rows: usize,
-        log_files: VecDeque<String>,
-        schema: DataSchemaRef,
+        stream: TracingTableStream,
     ) -> Result<ProcessorPtr> {
-        SyncSourcer::create(ctx, output, TracingSource {
-            schema,
-            rows_pre_block: rows,
-            tracing_files: log_files,
-            data_blocks: Default::default(),
-        })
+        SyncSourcer::create(ctx, output, TracingSource { stream })
     }
 }
 
@@ -167,72 +239,98 @@ impl SyncSource for TracingSource {
     const NAME: &'static str = "system.tracing";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        loop {
-            if let Some(data_block) = self.data_blocks.pop_front() {
-                return Ok(Some(data_block));
-            }
+        // Without the follow mode, no block means all the files are read.
+        self.stream.try_get_one_block()
+    }
+}
 
-            if self.tracing_files.is_empty() {
-                return Ok(None);
-            }
+/// Streams the existing log lines and then the lines appended to the active log file.
+///
+/// The query is long-lived, it ends when the pushed down limit is reached or the query is killed.
+struct TracingFollowSource {
+    stream: TracingTableStream,
+}
 
-            if let Some(file_name) = self.tracing_files.pop_front() {
-                let max_rows = self.rows_pre_block;
-                let buffer = BufReader::new(File::open(file_name)?);
-
-                let mut time_column = MutableStringColumn::with_capacity(max_rows);
-                let mut host_column = MutableStringColumn::with_capacity(max_rows);
-                let mut msg_column = MutableStringColumn::with_capacity(max_rows);
-                let mut name_column = MutableStringColumn::with_capacity(max_rows);
-                let mut level_column = MutablePrimitiveColumn::<i8>::with_capacity(max_rows);
-                let mut pid_column = MutablePrimitiveColumn::<i64>::with_capacity(max_rows);
-                let mut version_column = MutablePrimitiveColumn::<i64>::with_capacity(max_rows);
-
-                for (index, line) in buffer.lines().enumerate() {
-                    if index != 0 && index % max_rows == 0 {
-                        self.data_blocks
-                            .push_back(DataBlock::create(self.schema.clone(), vec![
-                                Arc::new(version_column.finish()),
-                                Arc::new(name_column.finish()),
-                                Arc::new(msg_column.finish()),
-                                Arc::new(level_column.finish()),
-                                Arc::new(host_column.finish()),
-                                Arc::new(pid_column.finish()),
-                                Arc::new(time_column.finish()),
-                            ]));
-
-                        time_column = MutableStringColumn::with_capacity(max_rows);
-                        host_column = MutableStringColumn::with_capacity(max_rows);
-                        msg_column = MutableStringColumn::with_capacity(max_rows);
-                        name_column = MutableStringColumn::with_capacity(max_rows);
-                        level_column = MutablePrimitiveColumn::<i8>::with_capacity(max_rows);
-                        pid_column = MutablePrimitiveColumn::<i64>::with_capacity(max_rows);
-                        version_column = MutablePrimitiveColumn::<i64>::with_capacity(max_rows);
-                    }
-
-                    let entry: LogEntry = serde_json::from_str(line.unwrap().as_str())?;
-                    pid_column.push(entry.pid);
-                    version_column.push(entry.v);
-                    level_column.push(entry.level);
-                    msg_column.push(entry.msg.as_bytes());
-                    name_column.push(entry.name.as_bytes());
-                    time_column.push(entry.time.as_bytes());
-                    host_column.push(entry.hostname.as_bytes());
-                }
+impl TracingFollowSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        stream: TracingTableStream,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output, TracingFollowSource { stream })
+    }
+}
 
-                if !pid_column.is_empty() {
-                    self.data_blocks
-                        .push_back(DataBlock::create(self.schema.clone(), vec![
-                            Arc::new(version_column.finish()),
-                            Arc::new(name_column.finish()),
-                            Arc::new(msg_column.finish()),
-                            Arc::new(level_column.finish()),
-                            Arc::new(host_column.finish()),
-                            Arc::new(pid_column.finish()),
-                            Arc::new(time_column.finish()),
-                        ]));
-                }
-            }
+#[async_trait::async_trait]
+impl AsyncSource for TracingFollowSource {
+    const NAME: &'static str = "system.tracing.follow";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        self.stream.next().await.transpose()
+    }
+}
+
+/// Reads the log lines of the other cluster nodes concurrently, one block for each node.
+///
+/// A node which can not be reached is skipped with a warning, so the query still returns
+/// the rows of the others.
+struct TracingClusterSource {
+    ctx: Arc<dyn TableContext>,
+    remote_reader: Arc<dyn RemoteNodeReader>,
+    schema: DataSchemaRef,
+    limit: Option<usize>,
+    // None until the nodes are read.
+    data_blocks: Option<VecDeque<DataBlock>>,
+}
+
+impl TracingClusterSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        remote_reader: Arc<dyn RemoteNodeReader>,
+        schema: DataSchemaRef,
+        limit: Option<usize>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, TracingClusterSource {
+            ctx,
+            remote_reader,
+            schema,
+            limit,
+            data_blocks: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for TracingClusterSource {
+    const NAME: &'static str = "system.tracing.cluster";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.data_blocks.is_none() {
+            let (ctx, limit) = (self.ctx.clone(), self.limit);
+            let remote_reader = self.remote_reader.clone();
+            let nodes_lines = read_remote_nodes(&self.ctx, |node| {
+                let (ctx, remote_reader) = (ctx.clone(), remote_reader.clone());
+                async move { remote_reader.read_log_lines(ctx, &node, limit).await }
+            })
+            .await;
+
+            let data_blocks = nodes_lines
+                .into_iter()
+                .filter(|(_, lines)| !lines.is_empty())
+                .map(|(node, lines)| {
+                    let entries = lines.iter().map(|line| LogEntry::parse(line)).collect();
+                    log_entries_to_block(self.schema.clone(), &node, entries)
+                })
+                .collect();
+            self.data_blocks = Some(data_blocks);
         }
+
+        Ok(self
+            .data_blocks
+            .as_mut()
+            .and_then(|data_blocks| data_blocks.pop_front()))
     }
 }
@@ -14,16 +14,26 @@
 
 use std::collections::VecDeque;
 use std::fs::File;
+use std::fs::Metadata;
+use std::future::Future;
 use std::io::BufRead;
 use std::io::BufReader;
+use std::io::ErrorKind;
+use std::pin::Pin;
 use std::task::Poll;
+use std::time::Duration;
 
+use chrono::DateTime;
+use common_base::base::tokio::time::sleep;
+use common_base::base::tokio::time::Sleep;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_exception::Result;
 use futures::Stream;
+use serde_json::Map;
+use serde_json::Value;
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default)]
 pub struct LogEntry {
     pub v: i64,
     pub name: String,
@@ -32,95 +42,327 @@ pub struct LogEntry {
     pub hostname: String,
     pub pid: i64,
     pub time: String,
+    /// The other key-value fields of the record, such as the fields of the tracing event.
+    #[serde(flatten)]
+    pub fields: Map<String, Value>,
+}
+
+impl LogEntry {
+    /// Parses a log line, a line which is not a JSON record is kept as a plain text message.
+    pub fn parse(line: &str) -> LogEntry {
+        serde_json::from_str(line).unwrap_or_else(|_| LogEntry {
+            msg: line.to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// The time of the record in microseconds, None for a plain text line or an invalid time.
+    pub fn time_micros(&self) -> Option<i64> {
+        DateTime::parse_from_rfc3339(&self.time)
+            .ok()
+            .map(|time| time.timestamp_micros())
+    }
+}
+
+#[cfg(unix)]
+fn file_identity(meta: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((meta.dev(), meta.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_identity(_meta: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// An opened log file, it keeps reading the same file even if the path is renamed by a rotation.
+struct LogFileReader {
+    path: String,
+    reader: BufReader<File>,
+    identity: Option<(u64, u64)>,
+    read_bytes: u64,
+    // The tail of the file which is not terminated by a line feed yet.
+    partial_line: String,
+    // The path refers to another file, read the rest of this one and switch to it.
+    rotated: bool,
+}
+
+impl LogFileReader {
+    /// Opens the log file, returns None if it is removed already.
+    fn open(path: String) -> Result<Option<LogFileReader>> {
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(cause) if cause.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(cause) => return Err(cause.into()),
+        };
+
+        let identity = file_identity(&file.metadata()?);
+        Ok(Some(LogFileReader {
+            path,
+            reader: BufReader::new(file),
+            identity,
+            read_bytes: 0,
+            partial_line: String::new(),
+            rotated: false,
+        }))
+    }
+
+    /// Returns the next line terminated by a line feed, None if there is no more for now.
+    fn next_line(&mut self) -> Result<Option<String>> {
+        let read = self.reader.read_line(&mut self.partial_line)?;
+        self.read_bytes += read as u64;
+
+        if read == 0 || !self.partial_line.ends_with('\n') {
+            return Ok(None);
+        }
+        Ok(Some(std::mem::take(&mut self.partial_line)))
+    }
+
+    /// Takes the unterminated last line once the file is known to be complete.
+    fn take_partial_line(&mut self) -> Option<String> {
+        match self.partial_line.trim().is_empty() {
+            true => None,
+            false => Some(std::mem::take(&mut self.partial_line)),
+        }
+    }
+
+    /// Whether the path is taken by another file, which is the successor after a rotation.
+    ///
+    /// A file truncated in place is also treated as a successor.
+    fn check_rotated(&self) -> bool {
+        match std::fs::metadata(&self.path) {
+            Ok(meta) => file_identity(&meta) != self.identity || meta.len() < self.read_bytes,
+            // Renamed, but the successor is not created yet.
+            Err(_) => false,
+        }
+    }
 }
 
 pub struct TracingTableStream {
     schema: DataSchemaRef,
-    file_idx: usize,
+    // The id of the cluster node the log files belong to.
+    node: String,
+    // The log files not opened yet, oldest first, without the active one.
     log_files: VecDeque<String>,
+    // The last log file, which is the one still being written.
+    active: Option<LogFileReader>,
+    current: Option<LogFileReader>,
     limit: usize,
     limit_offset: usize,
+    max_block_size: usize,
+    follow: Option<Duration>,
+    delay: Option<Pin<Box<Sleep>>>,
 }
 
 impl TracingTableStream {
     pub fn try_create(
         schema: DataSchemaRef,
-        log_files: VecDeque<String>,
+        mut log_files: VecDeque<String>,
         limit: usize,
     ) -> Result<Self> {
+        // Open the active file at once, so the lines written before a rotation are not missed.
+        let active = match log_files.pop_back() {
+            None => None,
+            Some(path) => LogFileReader::open(path)?,
+        };
+
         Ok(TracingTableStream {
             schema,
+            node: String::new(),
             log_files,
-            file_idx: 0,
+            active,
+            current: None,
             limit,
             limit_offset: 0,
+            max_block_size: usize::MAX,
+            follow: None,
+            delay: None,
         })
     }
 
-    pub fn try_get_one_block(&mut self) -> Result<Option<DataBlock>> {
-        if self.file_idx >= self.log_files.len() {
-            return Ok(None);
-        }
+    /// Keep streaming the lines appended to the active log file instead of ending at its EOF.
+    ///
+    /// The file is checked for new lines every `poll_interval`, the stream still ends once
+    /// `limit` lines are read, or when it is dropped.
+    pub fn with_follow(mut self, poll_interval: Duration) -> Self {
+        self.follow = Some(poll_interval);
+        self
+    }
 
-        if self.limit_offset >= self.limit {
-            return Ok(None);
+    /// Return at most `max_block_size` lines in a block.
+    pub fn with_max_block_size(mut self, max_block_size: usize) -> Self {
+        self.max_block_size = max_block_size;
+        self
+    }
+
+    /// Tag the rows with the id of the cluster node.
+    pub fn with_node(mut self, node: impl Into<String>) -> Self {
+        self.node = node.into();
+        self
+    }
+
+    fn is_finished(&self) -> bool {
+        self.limit_offset >= self.limit
+            || (self.current.is_none() && self.active.is_none() && self.log_files.is_empty())
+    }
+
+    // Opens the next log file as the current one, returns false if there is no more.
+    fn open_next_file(&mut self) -> Result<bool> {
+        while let Some(path) = self.log_files.pop_front() {
+            if let Some(reader) = LogFileReader::open(path)? {
+                self.current = Some(reader);
+                return Ok(true);
+            }
         }
 
-        let mut version_col = vec![];
-        let mut name_col = vec![];
-        let mut msg_col = vec![];
-        let mut level_col = vec![];
-        let mut host_col = vec![];
-        let mut pid_col = vec![];
-        let mut time_col = vec![];
+        self.current = self.active.take();
+        Ok(self.current.is_some())
+    }
 
-        let file = File::open(self.log_files[self.file_idx].clone())?;
-        self.file_idx += 1;
+    /// Returns the lines read from the current log file, None if there is no more for now.
+    ///
+    /// When the active log file is rotated, the rest of it is read before
+    /// the successor file, so the lines are returned without gaps or duplicates.
+    pub fn try_get_one_block(&mut self) -> Result<Option<DataBlock>> {
+        let mut lines = vec![];
 
-        let reader = BufReader::new(file);
-        for line in reader.lines() {
-            if self.limit_offset >= self.limit {
+        while self.limit_offset + lines.len() < self.limit && lines.len() < self.max_block_size {
+            if self.current.is_none() && !self.open_next_file()? {
                 break;
             }
 
-            let entry: LogEntry = serde_json::from_str(line.unwrap().as_str())?;
-            version_col.push(entry.v);
-            name_col.push(entry.name);
-            msg_col.push(entry.msg);
-            level_col.push(entry.level);
-            host_col.push(entry.hostname);
-            pid_col.push(entry.pid);
-            time_col.push(entry.time);
-            self.limit_offset += 1;
+            let is_active = self.log_files.is_empty() && self.active.is_none();
+            let current = match self.current.as_mut() {
+                Some(current) => current,
+                None => break,
+            };
+
+            if let Some(line) = current.next_line()? {
+                lines.push(line);
+                continue;
+            }
+
+            // Reached the EOF of the current file.
+            if !is_active {
+                lines.extend(current.take_partial_line());
+                self.current = None;
+                match lines.is_empty() {
+                    true => continue,
+                    false => break,
+                }
+            }
+
+            if current.rotated {
+                // The rotated file is complete, continue with its successor.
+                lines.extend(current.take_partial_line());
+                let path = current.path.clone();
+                self.current = None;
+                self.active = LogFileReader::open(path)?;
+                continue;
+            }
+
+            if current.check_rotated() {
+                // Read again for the lines written just before the rotation.
+                current.rotated = true;
+                continue;
+            }
+
+            if self.follow.is_none() {
+                lines.extend(current.take_partial_line());
+                self.current = None;
+            }
+            break;
+        }
+
+        if lines.is_empty() {
+            return Ok(None);
         }
 
-        let names: Vec<&[u8]> = name_col.iter().map(|x| x.as_bytes()).collect();
-        let msgs: Vec<&[u8]> = msg_col.iter().map(|x| x.as_bytes()).collect();
-        let hosts: Vec<&[u8]> = host_col.iter().map(|x| x.as_bytes()).collect();
-        let times: Vec<&[u8]> = time_col.iter().map(|x| x.as_bytes()).collect();
-
-        let block = DataBlock::create(self.schema.clone(), vec![
-            Series::from_data(version_col),
-            Series::from_data(names),
-            Series::from_data(msgs),
-            Series::from_data(level_col),
-            Series::from_data(hosts),
-            Series::from_data(pid_col),
-            Series::from_data(times),
-        ]);
-
-        Ok(Some(block))
+        let entries = lines
+            .iter()
+            .take(self.limit - self.limit_offset)
+            .map(|line| LogEntry::parse(line.trim_end()))
+            .collect::<Vec<_>>();
+        self.limit_offset += entries.len();
+
+        Ok(Some(log_entries_to_block(
+            self.schema.clone(),
+            &self.node,
+            entries,
+        )))
     }
 }
 
+/// Builds the block of `system.tracing` from the log entries of the cluster node.
+pub fn log_entries_to_block(
+    schema: DataSchemaRef,
+    node: &str,
+    entries: Vec<LogEntry>,
+) -> DataBlock {
+    let mut version_col = vec![];
+    let mut name_col = vec![];
+    let mut msg_col = vec![];
+    let mut level_col = vec![];
+    let mut host_col = vec![];
+    let mut pid_col = vec![];
+    let mut time_col = vec![];
+    let mut fields_col = vec![];
+
+    for entry in entries {
+        version_col.push(entry.v);
+        name_col.push(entry.name);
+        msg_col.push(entry.msg);
+        level_col.push(entry.level);
+        host_col.push(entry.hostname);
+        pid_col.push(entry.pid);
+        time_col.push(entry.time_micros());
+        fields_col.push(VariantValue::from(Value::Object(entry.fields)));
+    }
+
+    let names: Vec<&[u8]> = name_col.iter().map(|x| x.as_bytes()).collect();
+    let msgs: Vec<&[u8]> = msg_col.iter().map(|x| x.as_bytes()).collect();
+    let hosts: Vec<&[u8]> = host_col.iter().map(|x| x.as_bytes()).collect();
+
+    let nodes = vec![node.as_bytes(); version_col.len()];
+    DataBlock::create(schema, vec![
+        Series::from_data(version_col),
+        Series::from_data(names),
+        Series::from_data(msgs),
+        Series::from_data(level_col),
+        Series::from_data(hosts),
+        Series::from_data(pid_col),
+        Series::from_data(time_col),
+        Series::from_data(fields_col),
+        Series::from_data(nodes),
+    ])
+}
+
 impl Stream for TracingTableStream {
     type Item = Result<DataBlock>;
 
     fn poll_next(
         mut self: std::pin::Pin<&mut Self>,
-        _: &mut std::task::Context<'_>,
+        cx: &mut std::task::Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        let block = self.try_get_one_block()?;
-        Poll::Ready(block.map(Ok))
+        loop {
+            if let Some(delay) = self.delay.as_mut() {
+                if delay.as_mut().poll(cx).is_pending() {
+                    return Poll::Pending;
+                }
+                self.delay = None;
+            }
+
+            if let Some(block) = self.try_get_one_block()? {
+                return Poll::Ready(Some(Ok(block)));
+            }
+
+            match self.follow {
+                // Nothing appended yet, check again after the poll interval.
+                Some(poll_interval) if !self.is_finished() => {
+                    self.delay = Some(Box::pin(sleep(poll_interval)));
+                }
+                _ => return Poll::Ready(None),
+            }
+        }
     }
 }
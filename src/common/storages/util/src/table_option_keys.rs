@@ -18,6 +18,8 @@ use once_cell::sync::Lazy;
 
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
+/// The location of the data of a table backed by external storage, e.g. a hive table
+pub const OPT_KEY_LOCATION: &str = "location";
 
 /// Legacy table snapshot location key
 ///
@@ -54,3 +56,21 @@ pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
 pub fn is_internal_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
+
+/// Fragments of the table option keys whose values are credentials, e.g. `aws_secret_key`
+const CREDENTIAL_OPT_KEY_FRAGMENTS: &[&str] = &[
+    "secret",
+    "password",
+    "credential",
+    "token",
+    "key_id",
+    "account_key",
+];
+
+/// Table option keys whose values should be redacted when shown
+pub fn is_credential_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
+    let opt_key = opt_key.as_ref().to_lowercase();
+    CREDENTIAL_OPT_KEY_FRAGMENTS
+        .iter()
+        .any(|fragment| opt_key.contains(fragment))
+}
@@ -80,6 +80,24 @@ pub trait KVApi: Send + Sync {
 
     async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, MetaError>;
 
+    /// List at most `limit` kvs with the prefix in key order, starting after the key `start_after`.
+    ///
+    /// The default implementation lists the whole prefix,
+    /// the stores able to scan a key range override it to bound the memory used.
+    async fn prefix_list_kv_page(
+        &self,
+        prefix: &str,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> Result<ListKVReply, MetaError> {
+        let res = self.prefix_list_kv(prefix).await?;
+        Ok(res
+            .into_iter()
+            .filter(|(key, _)| start_after.as_ref().map_or(true, |after| key > after))
+            .take(limit)
+            .collect())
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError>;
 }
 
@@ -101,6 +119,17 @@ impl<U: KVApi, T: Deref<Target = U> + Send + Sync> KVApi for T {
         self.deref().prefix_list_kv(prefix).await
     }
 
+    async fn prefix_list_kv_page(
+        &self,
+        prefix: &str,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> Result<ListKVReply, MetaError> {
+        self.deref()
+            .prefix_list_kv_page(prefix, start_after, limit)
+            .await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
         self.deref().transaction(txn).await
     }
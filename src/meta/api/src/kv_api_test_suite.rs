@@ -16,6 +16,7 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use common_base::base::tokio;
+use common_meta_app::share::ShareNameIdent;
 use common_meta_types::txn_condition;
 use common_meta_types::txn_op;
 use common_meta_types::txn_op_response;
@@ -42,8 +43,10 @@ use common_meta_types::UpsertKVReq;
 use tracing::debug;
 use tracing::info;
 
+use crate::list_keys_paged;
 use crate::ApiBuilder;
 use crate::KVApi;
+use crate::KVApiKey;
 
 pub struct KVApiTestSuite {}
 
@@ -60,6 +63,7 @@ impl KVApiTestSuite {
         self.kv_timeout(&builder.build().await).await?;
         self.kv_meta(&builder.build().await).await?;
         self.kv_list(&builder.build().await).await?;
+        self.kv_list_keys_paged(&builder.build().await).await?;
         self.kv_mget(&builder.build().await).await?;
         self.kv_txn_absent_seq_0(&builder.build().await).await?;
         self.kv_transaction(&builder.build().await).await?;
@@ -532,6 +536,51 @@ impl KVApiTestSuite {
         Ok(())
     }
 
+    #[tracing::instrument(level = "info", skip(self, kv))]
+    pub async fn kv_list_keys_paged<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        info!("--- KVApiTestSuite::kv_list_keys_paged() start");
+
+        let share_name = |tenant: &str, i: usize| ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: format!("share_{:03}", i),
+        };
+
+        // The keys of tenant1, surrounded by the keys of other tenants.
+        let mut expected = vec![];
+        for i in 0..100 {
+            for tenant in ["tenant0", "tenant1", "tenant2"] {
+                let key = share_name(tenant, i);
+                kv.upsert_kv(UpsertKVReq::new(
+                    &key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Update(b"v".to_vec()),
+                    None,
+                ))
+                .await?;
+                if tenant == "tenant1" {
+                    expected.push(key);
+                }
+            }
+        }
+
+        let prefix = ShareNameIdent {
+            tenant: "tenant1".to_string(),
+            share_name: "".to_string(),
+        };
+        let mut pager = list_keys_paged(kv, &prefix, 7);
+        let mut pages = 0;
+        let mut keys = vec![];
+        while let Some(page) = pager.next_page().await? {
+            assert!(page.len() <= 7);
+            pages += 1;
+            keys.extend(page);
+        }
+
+        assert_eq!(pages, 15);
+        assert_eq!(keys, expected);
+        Ok(())
+    }
+
     #[tracing::instrument(level = "info", skip(self, kv))]
     pub async fn kv_mget<KV: KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
         info!("--- KVApiTestSuite::kv_mget() start");
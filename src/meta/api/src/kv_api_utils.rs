@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::fmt::Display;
+use std::marker::PhantomData;
 
 use anyerror::AnyError;
 use common_meta_app::schema::DatabaseNameIdent;
@@ -22,12 +23,14 @@ use common_meta_types::app_error::UnknownDatabase;
 use common_meta_types::app_error::UnknownTable;
 use common_meta_types::txn_condition::Target;
 use common_meta_types::txn_op::Request;
+use common_meta_types::txn_op_response::Response;
 use common_meta_types::ConditionResult;
 use common_meta_types::MatchSeq;
 use common_meta_types::MetaError;
 use common_meta_types::Operation;
 use common_meta_types::TxnCondition;
 use common_meta_types::TxnDeleteRequest;
+use common_meta_types::TxnGetRequest;
 use common_meta_types::TxnOp;
 use common_meta_types::TxnOpResponse;
 use common_meta_types::TxnPutRequest;
@@ -104,6 +107,65 @@ pub async fn list_keys<K: KVApiKey>(
     Ok(structured_keys)
 }
 
+/// Lists the structured keys with a prefix page by page, it is created by `list_keys_paged`.
+pub struct KeysPager<'a, K, KV: ?Sized> {
+    kv_api: &'a KV,
+    prefix: String,
+    page_size: usize,
+    // The last listed key, None before the first page.
+    start_after: Option<String>,
+    finished: bool,
+    _key: PhantomData<K>,
+}
+
+impl<'a, K: KVApiKey, KV: KVApi + ?Sized> KeysPager<'a, K, KV> {
+    /// Returns the next page of at most `page_size` keys, or None if all the keys are listed.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<K>>, MetaError> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let res = self
+            .kv_api
+            .prefix_list_kv_page(&self.prefix, self.start_after.take(), self.page_size)
+            .await?;
+        if res.len() < self.page_size {
+            self.finished = true;
+        }
+        if res.is_empty() {
+            return Ok(None);
+        }
+
+        let mut structured_keys = Vec::with_capacity(res.len());
+        for (str_key, _seq_v) in res.iter() {
+            let struct_key = K::from_key(str_key).map_err(meta_encode_err)?;
+            structured_keys.push(struct_key);
+        }
+        self.start_after = res.last().map(|(str_key, _)| str_key.clone());
+
+        Ok(Some(structured_keys))
+    }
+}
+
+/// Same as `list_keys`, but the keys are fetched lazily, at most `page_size` at a time,
+/// to bound the memory used when listing a large prefix.
+pub fn list_keys_paged<'a, K: KVApiKey, KV: KVApi + ?Sized>(
+    kv_api: &'a KV,
+    key: &K,
+    page_size: usize,
+) -> KeysPager<'a, K, KV> {
+    assert!(page_size > 0, "page_size must be positive");
+
+    KeysPager {
+        kv_api,
+        prefix: key.to_key(),
+        page_size,
+        start_after: None,
+        finished: false,
+        _key: PhantomData,
+    }
+}
+
 /// List kvs whose value's type is `u64`.
 ///
 /// It expects the kv-value' type is `u64`, such as:
@@ -199,6 +261,73 @@ pub async fn send_txn(
     Ok((succ, responses))
 }
 
+/// Send a txn and tell which seq conditions failed if it is not committed.
+///
+/// The keys of the conditions are read in `else_then`, thus the txn must have no `else_then`.
+/// It returns (succ, the failed seq conditions if not committed).
+pub async fn send_txn_with_conflict(
+    kv_api: &impl KVApi,
+    mut txn_req: TxnRequest,
+) -> Result<(bool, Option<String>), MetaError> {
+    debug_assert!(txn_req.else_then.is_empty());
+    txn_req.else_then = txn_req
+        .condition
+        .iter()
+        .map(|cond| TxnOp {
+            request: Some(Request::Get(TxnGetRequest {
+                key: cond.key.clone(),
+            })),
+        })
+        .collect();
+    let condition = txn_req.condition.clone();
+
+    let (succ, responses) = send_txn(kv_api, txn_req).await?;
+    if succ {
+        return Ok((true, None));
+    }
+
+    let conflicts: Vec<String> = condition
+        .iter()
+        .zip(responses.iter())
+        .filter_map(|(cond, resp)| seq_condition_conflict(cond, resp))
+        .collect();
+    // The records may have changed back when they are read.
+    if conflicts.is_empty() {
+        return Ok((false, None));
+    }
+    Ok((false, Some(conflicts.join(", "))))
+}
+
+/// Describes a seq condition the read record does not satisfy, None if it does.
+fn seq_condition_conflict(cond: &TxnCondition, resp: &TxnOpResponse) -> Option<String> {
+    let target = match cond.target {
+        Some(Target::Seq(seq)) => seq,
+        _ => return None,
+    };
+    // seq is 0 if the record does not exist.
+    let seq = match &resp.response {
+        Some(Response::Get(get)) => get.value.as_ref().map_or(0, |v| v.seq),
+        _ => return None,
+    };
+
+    let expected = ConditionResult::from_i32(cond.expected)?;
+    let satisfied = match expected {
+        ConditionResult::Eq => seq == target,
+        ConditionResult::Gt => seq > target,
+        ConditionResult::Ge => seq >= target,
+        ConditionResult::Lt => seq < target,
+        ConditionResult::Le => seq <= target,
+        ConditionResult::Ne => seq != target,
+    };
+    if satisfied {
+        return None;
+    }
+    Some(format!(
+        "{}: expect seq {:?} {}, got {}",
+        cond.key, expected, target, seq
+    ))
+}
+
 /// Build a TxnCondition that compares the seq of a record.
 pub fn txn_cond_seq(key: &impl KVApiKey, op: ConditionResult, seq: u64) -> TxnCondition {
     TxnCondition {
@@ -47,21 +47,28 @@ pub use kv_api_utils::fetch_id;
 pub use kv_api_utils::get_struct_value;
 pub use kv_api_utils::get_u64_value;
 pub use kv_api_utils::list_keys;
+pub use kv_api_utils::list_keys_paged;
 pub use kv_api_utils::list_u64_value;
 pub use kv_api_utils::meta_encode_err;
 pub use kv_api_utils::send_txn;
+pub use kv_api_utils::send_txn_with_conflict;
 pub use kv_api_utils::serialize_struct;
 pub use kv_api_utils::serialize_u64;
 pub use kv_api_utils::table_has_to_exist;
 pub use kv_api_utils::txn_cond_seq;
 pub use kv_api_utils::txn_op_del;
 pub use kv_api_utils::txn_op_put;
+pub use kv_api_utils::KeysPager;
 pub use kv_api_utils::TXN_MAX_RETRY_TIMES;
 pub use schema_api::SchemaApi;
 pub(crate) use schema_api_impl::get_db_or_err;
 pub use schema_api_test_suite::SchemaApiTestSuite;
 pub use share_api::ShareApi;
+pub use share_api_impl::get_inbound_shared_accounts_stream;
+pub(crate) use share_api_impl::get_object_name_from_id;
 pub(crate) use share_api_impl::get_share_account_meta_or_err;
 pub(crate) use share_api_impl::get_share_id_to_name_or_err;
 pub(crate) use share_api_impl::get_share_meta_by_id_or_err;
+pub(crate) use share_api_impl::get_share_table_seq_and_id;
+pub(crate) use share_api_impl::resolve_object_names;
 pub use share_api_test_suite::ShareApiTestSuite;
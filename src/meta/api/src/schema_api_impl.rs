@@ -61,6 +61,8 @@ use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
 use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_app::share::ShareId;
+use common_meta_app::share::ShareMeta;
 use common_meta_types::app_error::AppError;
 use common_meta_types::app_error::CreateDatabaseWithDropTime;
 use common_meta_types::app_error::CreateTableWithDropTime;
@@ -504,13 +506,34 @@ impl<KV: KVApi> SchemaApi for KV {
                 }
             };
 
+            // Mark the shares of the database, their consumers have to re-resolve the names.
+            let db_key = DatabaseId { db_id: old_db_id };
+            let (db_meta_seq, db_meta): (_, Option<DatabaseMeta>) =
+                get_struct_value(self, &db_key).await?;
+            let mut share_conditions = vec![];
+            let mut share_ops = vec![];
+            for share_id in db_meta.map(|meta| meta.shared_by).unwrap_or_default() {
+                // A share dropped concurrently has nothing left to refresh.
+                let share_key = ShareId { share_id };
+                let (share_meta_seq, share_meta): (_, Option<ShareMeta>) =
+                    get_struct_value(self, &share_key).await?;
+                let mut share_meta = match share_meta {
+                    Some(share_meta) => share_meta,
+                    None => continue,
+                };
+                share_meta.needs_refresh = true;
+
+                share_conditions.push(txn_cond_seq(&share_key, Eq, share_meta_seq));
+                share_ops.push(txn_op_put(&share_key, serialize_struct(&share_meta)?));
+            }
+
             // rename database
             {
                 // move db id from old db id list to new db id list
                 db_id_list.pop();
                 new_db_id_list.append(old_db_id);
 
-                let txn_req = TxnRequest {
+                let mut txn_req = TxnRequest {
                     condition: vec![
                         // Prevent renaming or deleting in other threads.
                         txn_cond_seq(tenant_dbname, Eq, old_db_id_seq),
@@ -518,6 +541,8 @@ impl<KV: KVApi> SchemaApi for KV {
                         txn_cond_seq(&tenant_newdbname, Eq, 0),
                         txn_cond_seq(&dbid_idlist, Eq, db_id_list_seq),
                         txn_cond_seq(&new_dbid_idlist, Eq, new_db_id_list_seq),
+                        // Prevent granting or revoking the database in other threads.
+                        txn_cond_seq(&db_key, Eq, db_meta_seq),
                     ],
                     if_then: vec![
                         txn_op_del(tenant_dbname), // del old_db_name
@@ -529,6 +554,8 @@ impl<KV: KVApi> SchemaApi for KV {
                     ],
                     else_then: vec![],
                 };
+                txn_req.condition.extend(share_conditions);
+                txn_req.if_then.extend(share_ops);
 
                 let (succ, _responses) = send_txn(self, txn_req).await?;
 
@@ -12,25 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Utc;
 use common_meta_app::share::*;
 use common_meta_types::MetaResult;
+use enumflags2::BitFlags;
 
 #[async_trait::async_trait]
 pub trait ShareApi: Sync + Send {
     async fn show_shares(&self, req: ShowSharesReq) -> MetaResult<ShowSharesReply>;
     async fn create_share(&self, req: CreateShareReq) -> MetaResult<CreateShareReply>;
 
+    // Create a share with the grants of another one, the objects no longer existing are skipped.
+    async fn clone_share(&self, req: CloneShareReq) -> MetaResult<CloneShareReply>;
+
     async fn drop_share(&self, req: DropShareReq) -> MetaResult<DropShareReply>;
 
     async fn grant_share_object(
         &self,
         req: GrantShareObjectReq,
     ) -> MetaResult<GrantShareObjectReply>;
+
+    // Grant one object to all the shares in a single transaction, no share is granted if any fails.
+    async fn grant_object_to_shares(
+        &self,
+        req: GrantObjectToSharesReq,
+    ) -> MetaResult<GrantObjectToSharesReply>;
+
     async fn revoke_share_object(
         &self,
         req: RevokeShareObjectReq,
     ) -> MetaResult<RevokeShareObjectReply>;
 
+    // Revoke all the objects and remove all the accounts of a share in one transaction,
+    // the share itself is kept.
+    async fn purge_share_grants(&self, share_name: ShareNameIdent) -> MetaResult<()>;
+
     async fn add_share_tenants(
         &self,
         req: AddShareAccountsReq,
@@ -40,20 +57,108 @@ pub trait ShareApi: Sync + Send {
         req: RemoveShareAccountsReq,
     ) -> MetaResult<RemoveShareAccountsReply>;
 
+    // Move all the accounts of a share to another one in one transaction.
+    async fn transfer_share_accounts(
+        &self,
+        from: ShareNameIdent,
+        to: ShareNameIdent,
+    ) -> MetaResult<TransferShareAccountsReply>;
+
     async fn get_share_grant_objects(
         &self,
         req: GetShareGrantObjectReq,
     ) -> MetaResult<GetShareGrantObjectReply>;
 
+    // Return the grant objects of a share the consumer is an account of, and record the access,
+    // see `record_share_access`. A share not granted to the consumer is unknown to it.
+    async fn get_inbound_share_grant_objects(
+        &self,
+        consumer: String,
+        share_name: ShareNameIdent,
+    ) -> MetaResult<GetShareGrantObjectReply>;
+
+    // Return the grant of one object in the share, None if the object is not granted to it.
+    async fn get_share_object_privilege(
+        &self,
+        share_name: ShareNameIdent,
+        object: ShareGrantObjectName,
+    ) -> MetaResult<Option<ShareGrantReplyObject>>;
+
     // Return all the grant tenants of the share
     async fn get_grant_tenants_of_share(
         &self,
         req: GetShareGrantTenantsReq,
     ) -> MetaResult<GetShareGrantTenantsReply>;
 
+    // Return a page of the grant tenants of the share ordered by account name, and whether there
+    // are more tenants after this page. All the tenants after `offset` are returned if `limit` is None.
+    async fn get_share_accounts(
+        &self,
+        share_name: ShareNameIdent,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> MetaResult<(Vec<String>, bool)>;
+
+    // Record an access to the share by a consumer, return whether `last_accessed_on` is written.
+    // An access soon after the recorded one is not written, to avoid a write for every read.
+    async fn record_share_access(
+        &self,
+        share_name: ShareNameIdent,
+        access_on: DateTime<Utc>,
+    ) -> MetaResult<bool>;
+
+    // Freeze or unfreeze the share. A frozen share rejects any change of its grants and accounts,
+    // it can still be dropped.
+    async fn alter_share_freeze(&self, share_name: ShareNameIdent, frozen: bool) -> MetaResult<()>;
+
     // Return all the grant privileges of the object
     async fn get_grant_privileges_of_object(
         &self,
         req: GetObjectGrantPrivilegesReq,
     ) -> MetaResult<GetObjectGrantPrivilegesReply>;
+
+    // Return the shares the object is granted to, with the privileges granted on it.
+    async fn get_shares_by_object(
+        &self,
+        object: ShareGrantObjectName,
+        tenant: String,
+    ) -> MetaResult<Vec<(ShareNameIdent, BitFlags<ShareGrantObjectPrivilege>)>>;
+
+    // Check the keys of the share are consistent with each other, without changing any of them.
+    async fn validate_share(&self, share_name: ShareNameIdent)
+        -> MetaResult<ShareValidationReport>;
+
+    // Return the stored share meta and its seq as is, the objects are ids and not resolved to names.
+    async fn get_share_meta_raw(&self, share_name: ShareNameIdent) -> MetaResult<(u64, ShareMeta)>;
+
+    // Return all the shares the tenant provides ordered by name, each with its share meta.
+    // The shares that can not be resolved are skipped, as `show_shares` does.
+    async fn list_outbound_shares(
+        &self,
+        tenant: String,
+    ) -> MetaResult<Vec<(ShareAccountReply, ShareMeta)>>;
+
+    // Compare the resolved grants of two shares, e.g. before replacing one with the other.
+    async fn diff_share_grants(
+        &self,
+        a: ShareNameIdent,
+        b: ShareNameIdent,
+    ) -> MetaResult<ShareGrantDiff>;
+
+    // Return the statements that recreate the share, one per line.
+    // The grants of the dropped objects are commented out.
+    async fn get_share_create_statement(&self, share_name: ShareNameIdent) -> MetaResult<String>;
+
+    // Return the share accounts of the tenant whose share no longer exists, e.g. left by a drop
+    // racing with adding the tenant.
+    async fn list_orphaned_share_accounts(
+        &self,
+        tenant: String,
+    ) -> MetaResult<Vec<ShareAccountNameIdent>>;
+
+    // Remove the orphaned share accounts of the tenant, return the removed ones.
+    async fn cleanup_orphaned_share_accounts(
+        &self,
+        tenant: String,
+    ) -> MetaResult<Vec<ShareAccountNameIdent>>;
 }
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
 use std::fmt::Display;
 
+use common_datavalues::chrono::DateTime;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::Utc;
 use common_meta_app::schema::DBIdTableName;
 use common_meta_app::schema::DatabaseId;
 use common_meta_app::schema::DatabaseIdToName;
+use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
 use common_meta_app::schema::TableId;
 use common_meta_app::schema::TableIdToName;
@@ -24,14 +31,22 @@ use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::share::*;
 use common_meta_types::app_error::AppError;
+use common_meta_types::app_error::CannotShareDroppedObject;
+use common_meta_types::app_error::CannotShareProtectedObject;
+use common_meta_types::app_error::CannotShareView;
+use common_meta_types::app_error::InvalidSharePrivilege;
 use common_meta_types::app_error::ShareAccountsAlreadyExists;
 use common_meta_types::app_error::ShareAlreadyExists;
+use common_meta_types::app_error::ShareCycleDetected;
+use common_meta_types::app_error::ShareDatabaseNotGranted;
+use common_meta_types::app_error::ShareFrozen;
+use common_meta_types::app_error::ShareObjectDatabaseMismatch;
 use common_meta_types::app_error::TxnRetryMaxTimes;
+use common_meta_types::app_error::UnknownDatabase;
 use common_meta_types::app_error::UnknownShare;
 use common_meta_types::app_error::UnknownShareAccounts;
 use common_meta_types::app_error::UnknownShareId;
 use common_meta_types::app_error::WrongShare;
-use common_meta_types::app_error::WrongShareObject;
 use common_meta_types::ConditionResult::Eq;
 use common_meta_types::MetaError;
 use common_meta_types::MetaResult;
@@ -39,16 +54,23 @@ use common_meta_types::TxnCondition;
 use common_meta_types::TxnOp;
 use common_meta_types::TxnRequest;
 use common_tracing::func_name;
+use enumflags2::BitFlags;
+use futures::stream;
+use futures::Stream;
 use tracing::debug;
+use tracing::warn;
 
 use crate::db_has_to_exist;
+use crate::deserialize_struct;
 use crate::fetch_id;
 use crate::get_db_or_err;
 use crate::get_struct_value;
 use crate::get_u64_value;
 use crate::id_generator::IdGenerator;
 use crate::list_keys;
+use crate::list_keys_paged;
 use crate::send_txn;
+use crate::send_txn_with_conflict;
 use crate::serialize_struct;
 use crate::serialize_u64;
 use crate::table_has_to_exist;
@@ -56,9 +78,23 @@ use crate::txn_cond_seq;
 use crate::txn_op_del;
 use crate::txn_op_put;
 use crate::KVApi;
+use crate::KVApiKey;
 use crate::ShareApi;
 use crate::TXN_MAX_RETRY_TIMES;
 
+/// The engine of a view, the same as `VIEW_ENGINE` of the view table storage.
+const VIEW_ENGINE: &str = "VIEW";
+
+/// The number of keys fetched at a time when enumerating the shares or the accounts of a tenant.
+const LIST_SHARE_KEYS_PAGE_SIZE: usize = 1024;
+
+/// The accesses to a share within this many seconds after the recorded one are not written,
+/// to avoid a write to the share meta for every read.
+const SHARE_ACCESS_RECORD_INTERVAL_SECS: i64 = 600;
+
+/// The number of accounts added in one transaction if `AddShareAccountsReq::chunk_size` is None.
+const DEFAULT_ADD_SHARE_ACCOUNTS_CHUNK_SIZE: usize = 100;
+
 /// ShareApi is implemented upon KVApi.
 /// Thus every type that impl KVApi impls ShareApi.
 #[async_trait::async_trait]
@@ -67,15 +103,28 @@ impl<KV: KVApi> ShareApi for KV {
     async fn show_shares(&self, req: ShowSharesReq) -> MetaResult<ShowSharesReply> {
         debug!(req = debug(&req), "ShareApi: {}", func_name!());
 
-        // Get all outbound share accounts.
-        let outbound_accounts = get_outbound_shared_accounts_by_tenant(self, &req.tenant).await?;
+        // Get outbound share accounts of this page.
+        let (outbound_accounts, next_outbound_offset, outbound_has_more) =
+            get_outbound_shared_accounts_by_tenant(
+                self,
+                &req.tenant,
+                req.outbound_offset,
+                req.limit,
+            )
+            .await?;
 
-        // Get all inbound share accounts.
-        let inbound_accounts = get_inbound_shared_accounts_by_tenant(self, &req.tenant).await?;
+        // Get inbound share accounts of this page.
+        let (inbound_accounts, next_inbound_offset, inbound_has_more) =
+            get_inbound_shared_accounts_by_tenant(self, &req.tenant, req.inbound_offset, req.limit)
+                .await?;
 
         Ok(ShowSharesReply {
             outbound_accounts,
             inbound_accounts,
+            next_outbound_offset,
+            next_inbound_offset,
+            outbound_has_more,
+            inbound_has_more,
         })
     }
 
@@ -93,6 +142,19 @@ impl<KV: KVApi> ShareApi for KV {
             debug!(share_id_seq, share_id, ?name_key, "get_share");
 
             if share_id_seq > 0 {
+                // A retried request returns the share created by its first attempt.
+                if let Some(request_id) = &req.request_id {
+                    let (_seq, share_meta) = get_share_meta_by_id_or_err(
+                        self,
+                        share_id,
+                        format!("create_share: {}", share_id),
+                    )
+                    .await?;
+                    if share_meta.create_request_id.as_ref() == Some(request_id) {
+                        return Ok(CreateShareReply { share_id });
+                    }
+                }
+
                 return if req.if_not_exists {
                     Ok(CreateShareReply { share_id })
                 } else {
@@ -113,6 +175,8 @@ impl<KV: KVApi> ShareApi for KV {
             let share_id = fetch_id(self, IdGenerator::share_id()).await?;
             let id_key = ShareId { share_id };
             let id_to_name_key = ShareIdToName { share_id };
+            let mut share_meta = ShareMeta::new(req.create_on, req.comment.clone());
+            share_meta.create_request_id = req.request_id.clone();
 
             debug!(share_id, name_key = debug(&name_key), "new share id");
 
@@ -125,10 +189,7 @@ impl<KV: KVApi> ShareApi for KV {
                     ],
                     if_then: vec![
                         txn_op_put(name_key, serialize_u64(share_id)?), /* (tenant, share_name) -> share_id */
-                        txn_op_put(
-                            &id_key,
-                            serialize_struct(&ShareMeta::new(req.create_on, req.comment.clone()))?,
-                        ), /* (share_id) -> share_meta */
+                        txn_op_put(&id_key, serialize_struct(&share_meta)?), /* (share_id) -> share_meta */
                         txn_op_put(&id_to_name_key, serialize_struct(name_key)?), /* __fd_share_id_to_name/<share_id> -> (tenant,share_name) */
                     ],
                     else_then: vec![],
@@ -154,6 +215,197 @@ impl<KV: KVApi> ShareApi for KV {
         )))
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn clone_share(&self, req: CloneShareReq) -> MetaResult<CloneShareReply> {
+        debug!(req = debug(&req), "ShareApi: {}", func_name!());
+
+        let source_key = &req.source;
+        let name_key = &ShareNameIdent {
+            tenant: source_key.tenant.clone(),
+            share_name: req.target_name.clone(),
+        };
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (source_id_seq, source_id, source_meta_seq, source_meta) =
+                get_share_or_err(self, source_key, format!("clone_share: {}", &source_key)).await?;
+
+            // The database may have become protected after it was granted to the source share.
+            if let Some(ShareGrantEntry {
+                object: ShareGrantObject::Database(db_id),
+                ..
+            }) = &source_meta.database
+            {
+                if !req.protected_databases.is_empty() {
+                    let (_, name_ident): (_, Option<DatabaseNameIdent>) =
+                        get_struct_value(self, &DatabaseIdToName { db_id: *db_id }).await?;
+                    if let Some(name_ident) = name_ident {
+                        check_share_protected_database(
+                            &ShareGrantObjectName::Database(name_ident.db_name),
+                            &req.protected_databases,
+                        )?;
+                    }
+                }
+            }
+
+            // Get share by name to ensure absence
+            let (share_id_seq, share_id) = get_u64_value(self, name_key).await?;
+            debug!(share_id_seq, share_id, ?name_key, "get_share");
+
+            if share_id_seq > 0 {
+                return if req.if_not_exists {
+                    Ok(CloneShareReply { share_id })
+                } else {
+                    Err(MetaError::AppError(AppError::ShareAlreadyExists(
+                        ShareAlreadyExists::new(
+                            &name_key.share_name,
+                            format!("clone share: tenant: {}", name_key.tenant),
+                        ),
+                    )))
+                };
+            }
+
+            // Clone share by inserting these record:
+            // (tenant, share_name) -> share_id
+            // (share_id) -> share_meta
+            // (share) -> (tenant,share_name)
+            // add share_id into the share_ids of every granted object that still exists
+            // add share_id into db_meta.shared_by of the granted database
+            // (account, share_id) -> share_account_meta if the accounts are cloned
+
+            let share_id = fetch_id(self, IdGenerator::share_id()).await?;
+            let id_key = ShareId { share_id };
+            let id_to_name_key = ShareIdToName { share_id };
+
+            debug!(share_id, name_key = debug(&name_key), "new share id");
+
+            let mut share_meta = ShareMeta::new(req.create_on, source_meta.comment.clone());
+            // The source share must not change while it is cloned.
+            let mut condition = vec![
+                txn_cond_seq(source_key, Eq, source_id_seq),
+                txn_cond_seq(
+                    &ShareId {
+                        share_id: source_id,
+                    },
+                    Eq,
+                    source_meta_seq,
+                ),
+                txn_cond_seq(name_key, Eq, 0),
+                txn_cond_seq(&id_to_name_key, Eq, 0),
+            ];
+            let mut if_then = vec![];
+
+            // The tables can not be granted without the database, skip them along with it.
+            if let Some(ShareGrantEntry {
+                object: ShareGrantObject::Database(db_id),
+                privileges,
+                ..
+            }) = &source_meta.database
+            {
+                let db_key = DatabaseId { db_id: *db_id };
+                let (db_meta_seq, db_meta): (_, Option<DatabaseMeta>) =
+                    get_struct_value(self, &db_key).await?;
+
+                if let Some(db_meta) = db_meta.filter(|meta| meta.drop_on.is_none()) {
+                    let object = ShareGrantObject::Database(*db_id);
+                    let seq_and_id =
+                        ShareGrantObjectSeqAndId::Database(db_meta_seq, *db_id, db_meta);
+                    add_txn_condition(&seq_and_id, &mut condition);
+                    add_shared_by_share_txn(self, share_id, &object, &mut condition, &mut if_then)
+                        .await?;
+                    add_grant_object_txn_if_then(&[share_id], seq_and_id, &mut if_then)?;
+                    share_meta.grant_object_privileges(object, *privileges, req.create_on);
+
+                    for entry in source_meta.entries.values() {
+                        // Only tables are granted as entries, anything else is a broken meta.
+                        let table_id = match entry.object {
+                            ShareGrantObject::Table(table_id) => table_id,
+                            ShareGrantObject::Database(_) => {
+                                return Err(MetaError::AppError(AppError::WrongShare(
+                                    WrongShare::new(&source_key.share_name),
+                                )));
+                            }
+                        };
+                        let (table_meta_seq, table_meta): (_, Option<TableMeta>) =
+                            get_struct_value(self, &TableId { table_id }).await?;
+                        match table_meta {
+                            Some(table_meta) if table_meta.drop_on.is_none() => {}
+                            _ => continue,
+                        }
+
+                        let seq_and_id =
+                            ShareGrantObjectSeqAndId::Table(*db_id, table_meta_seq, table_id);
+                        add_txn_condition(&seq_and_id, &mut condition);
+                        add_shared_by_share_txn(
+                            self,
+                            share_id,
+                            &entry.object,
+                            &mut condition,
+                            &mut if_then,
+                        )
+                        .await?;
+                        add_grant_object_txn_if_then(&[share_id], seq_and_id, &mut if_then)?;
+                        share_meta.grant_object_privileges(
+                            entry.object.clone(),
+                            entry.privileges,
+                            req.create_on,
+                        );
+                    }
+                }
+            }
+
+            if req.with_accounts {
+                for account in source_meta.accounts.iter() {
+                    let share_account_key = ShareAccountNameIdent {
+                        account: account.clone(),
+                        share_id,
+                    };
+                    condition.push(txn_cond_seq(&share_account_key, Eq, 0));
+
+                    let share_account_meta =
+                        ShareAccountMeta::new(account.clone(), share_id, req.create_on);
+                    if_then.push(txn_op_put(
+                        &share_account_key,
+                        serialize_struct(&share_account_meta)?,
+                    )); /* (account, share_id) -> share_account_meta */
+
+                    share_meta.add_account(account.clone());
+                }
+            }
+
+            if_then.push(txn_op_put(name_key, serialize_u64(share_id)?)); /* (tenant, share_name) -> share_id */
+            if_then.push(txn_op_put(&id_key, serialize_struct(&share_meta)?)); /* (share_id) -> share_meta */
+            if_then.push(txn_op_put(&id_to_name_key, serialize_struct(name_key)?)); /* __fd_share_id_to_name/<share_id> -> (tenant,share_name) */
+
+            // Clone share by transaction.
+            {
+                let txn_req = TxnRequest {
+                    condition,
+                    if_then,
+                    else_then: vec![],
+                };
+
+                let (succ, _responses) = send_txn(self, txn_req).await?;
+
+                debug!(
+                    name = debug(&name_key),
+                    id = debug(&id_key),
+                    succ = display(succ),
+                    "clone_share"
+                );
+
+                if succ {
+                    return Ok(CloneShareReply { share_id });
+                }
+            }
+        }
+
+        Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("clone_share", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     async fn drop_share(&self, req: DropShareReq) -> MetaResult<DropShareReply> {
         debug!(req = debug(&req), "ShareApi: {}", func_name!());
 
@@ -169,7 +421,7 @@ impl<KV: KVApi> ShareApi for KV {
                 Err(e) => {
                     if let MetaError::AppError(AppError::UnknownShare(_)) = e {
                         if req.if_exists {
-                            return Ok(DropShareReply {});
+                            return Ok(DropShareReply::default());
                         }
                     }
 
@@ -183,13 +435,18 @@ impl<KV: KVApi> ShareApi for KV {
             let (share_name_seq, _share_name) = match res {
                 Ok(x) => x,
                 Err(e) => {
-                    if let MetaError::AppError(AppError::UnknownShareId(_)) = e {
-                        if req.if_exists {
-                            return Ok(DropShareReply {});
+                    if let MetaError::AppError(AppError::UnknownShareId(_)) = &e {
+                        // A missing (share_id) -> (tenant, share_name) is deleted anyway.
+                        if req.force {
+                            (0, name_key.clone())
+                        } else if req.if_exists {
+                            return Ok(DropShareReply::default());
+                        } else {
+                            return Err(e);
                         }
+                    } else {
+                        return Err(e);
                     }
-
-                    return Err(e);
                 }
             };
 
@@ -200,6 +457,11 @@ impl<KV: KVApi> ShareApi for KV {
                     account: account.clone(),
                     share_id,
                 };
+                if req.force {
+                    accounts.push((share_account_key, 0));
+                    continue;
+                }
+
                 let ret = get_share_account_meta_or_err(
                     self,
                     &share_account_key,
@@ -213,6 +475,15 @@ impl<KV: KVApi> ShareApi for KV {
                 }
             }
 
+            let reply = DropShareReply {
+                accounts_to_remove: accounts.len() as u64,
+                objects_to_revoke: share_meta.database.is_some() as u64
+                    + share_meta.entries.len() as u64,
+            };
+            if req.dry_run {
+                return Ok(reply);
+            }
+
             // Delete share by these operations:
             // del (tenant, share_name)
             // del share_id
@@ -225,18 +496,21 @@ impl<KV: KVApi> ShareApi for KV {
             debug!(share_id, name_key = debug(&name_key), "drop_share");
 
             {
-                let mut condition = vec![
-                    txn_cond_seq(name_key, Eq, share_id_seq),
-                    txn_cond_seq(&share_id_key, Eq, share_meta_seq),
-                    txn_cond_seq(&id_name_key, Eq, share_name_seq),
-                ];
+                // A forced drop only checks the share name still refers to this share.
+                let mut condition = vec![txn_cond_seq(name_key, Eq, share_id_seq)];
+                if !req.force {
+                    condition.push(txn_cond_seq(&share_id_key, Eq, share_meta_seq));
+                    condition.push(txn_cond_seq(&id_name_key, Eq, share_name_seq));
+                }
                 let mut if_then = vec![
                     txn_op_del(name_key),      // del (tenant, share_name)
                     txn_op_del(&share_id_key), // del share_id
                     txn_op_del(&id_name_key),  // del (share_id) -> (tenant, share_name)
                 ];
                 for account in accounts {
-                    condition.push(txn_cond_seq(&account.0, Eq, account.1));
+                    if !req.force {
+                        condition.push(txn_cond_seq(&account.0, Eq, account.1));
+                    }
                     if_then.push(txn_op_del(&account.0));
                 }
 
@@ -256,7 +530,7 @@ impl<KV: KVApi> ShareApi for KV {
                 );
 
                 if succ {
-                    return Ok(DropShareReply {});
+                    return Ok(reply);
                 }
             }
         }
@@ -273,101 +547,84 @@ impl<KV: KVApi> ShareApi for KV {
         debug!(req = debug(&req), "ShareApi: {}", func_name!());
 
         let name_key = &req.share_name;
-        let mut retry = 0;
-        while retry < TXN_MAX_RETRY_TIMES {
-            retry += 1;
-
-            let res =
-                get_share_or_err(self, name_key, format!("add_share_tenants: {}", &name_key)).await;
-
-            let (share_id_seq, share_id, share_meta_seq, mut share_meta) = match res {
-                Ok(x) => x,
-                Err(e) => {
-                    if let MetaError::AppError(AppError::UnknownShare(_)) = e {
-                        if req.if_exists {
-                            return Ok(AddShareAccountsReply {});
-                        }
-                    }
-                    return Err(e);
-                }
-            };
-
-            let mut add_share_account_keys = vec![];
+        if req.check_cycle {
             for account in req.accounts.iter() {
-                if account == &name_key.tenant {
-                    continue;
-                }
-                if !share_meta.has_account(account) {
-                    add_share_account_keys.push(ShareAccountNameIdent {
-                        account: account.clone(),
-                        share_id,
-                    });
+                if account != &name_key.tenant
+                    && tenant_shares_to(self, account, &name_key.tenant).await?
+                {
+                    return Err(MetaError::AppError(AppError::ShareCycleDetected(
+                        ShareCycleDetected::new(
+                            &name_key.share_name,
+                            account,
+                            format!("add_share_tenants: {}", &name_key),
+                        ),
+                    )));
                 }
             }
-            if add_share_account_keys.is_empty() {
-                return Err(MetaError::AppError(AppError::ShareAccountsAlreadyExists(
-                    ShareAccountsAlreadyExists::new(
-                        req.share_name.share_name,
-                        &req.accounts,
-                        "share accounts already exists",
-                    ),
-                )));
-            }
-
-            // Add share account by these operations:
-            // mod share_meta add account
-            // add (account, share_id) -> share_account_meta
-            // return share_id
-            {
-                let id_key = ShareId { share_id };
-                let mut condition = vec![
-                    txn_cond_seq(name_key, Eq, share_id_seq),
-                    txn_cond_seq(&id_key, Eq, share_meta_seq),
-                ];
-                let mut if_then = vec![];
-
-                for share_account_key in add_share_account_keys.iter() {
-                    condition.push(txn_cond_seq(share_account_key, Eq, 0));
-
-                    let share_account_meta = ShareAccountMeta::new(
-                        share_account_key.account.clone(),
-                        share_id,
-                        req.share_on,
-                    );
-
-                    if_then.push(txn_op_put(
-                        share_account_key,
-                        serialize_struct(&share_account_meta)?,
-                    )); /* (account, share_id) -> share_account_meta */
+        }
 
-                    share_meta.add_account(share_account_key.account.clone());
+        let res =
+            get_share_or_err(self, name_key, format!("add_share_tenants: {}", &name_key)).await;
+        let (_share_id_seq, _share_id, _share_meta_seq, share_meta) = match res {
+            Ok(x) => x,
+            Err(e) => {
+                if let MetaError::AppError(AppError::UnknownShare(_)) = e {
+                    if req.if_exists {
+                        return Ok(AddShareAccountsReply {
+                            skipped_accounts: req.accounts.clone(),
+                            ..Default::default()
+                        });
+                    }
                 }
-                if_then.push(txn_op_put(&id_key, serialize_struct(&share_meta)?)); /* (share_id) -> share_meta */
-
-                let txn_req = TxnRequest {
-                    condition,
-                    if_then,
-                    else_then: vec![],
-                };
-
-                let (succ, _responses) = send_txn(self, txn_req).await?;
+                return Err(e);
+            }
+        };
+        share_has_to_be_unfrozen(&share_meta, name_key, "add_share_tenants")?;
 
-                debug!(
-                    name = debug(&name_key),
-                    id = debug(&id_key),
-                    succ = display(succ),
-                    "add_share_tenants"
-                );
+        let (accounts, mut skipped_accounts): (Vec<String>, Vec<String>) =
+            req.accounts.iter().cloned().partition(|account| {
+                account != &name_key.tenant
+                    && (req.update_existing || !share_meta.has_account(account))
+            });
+        if accounts.is_empty() {
+            return Err(MetaError::AppError(AppError::ShareAccountsAlreadyExists(
+                ShareAccountsAlreadyExists::new(
+                    req.share_name.share_name,
+                    &req.accounts,
+                    "share accounts already exists",
+                ),
+            )));
+        }
 
-                if succ {
-                    return Ok(AddShareAccountsReply {});
-                }
-            }
+        let chunk_size = req
+            .chunk_size
+            .unwrap_or(DEFAULT_ADD_SHARE_ACCOUNTS_CHUNK_SIZE)
+            .max(1);
+        let mut committed_chunks = 0;
+        let mut added_accounts = vec![];
+        let mut updated_accounts = vec![];
+        for chunk in accounts.chunks(chunk_size) {
+            let (added, updated) =
+                add_share_accounts_chunk(self, name_key, chunk, req.share_on, req.update_existing)
+                    .await?;
+            // The accounts added by another request in the meantime are skipped.
+            skipped_accounts.extend(
+                chunk
+                    .iter()
+                    .filter(|account| !added.contains(account) && !updated.contains(account))
+                    .cloned(),
+            );
+            added_accounts.extend(added);
+            updated_accounts.extend(updated);
+            committed_chunks += 1;
         }
 
-        Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
-            TxnRetryMaxTimes::new("add_share_tenants", TXN_MAX_RETRY_TIMES),
-        )))
+        Ok(AddShareAccountsReply {
+            committed_chunks,
+            added_accounts,
+            updated_accounts,
+            skipped_accounts,
+        })
     }
 
     async fn remove_share_tenants(
@@ -394,19 +651,23 @@ impl<KV: KVApi> ShareApi for KV {
                 Err(e) => {
                     if let MetaError::AppError(AppError::UnknownShare(_)) = e {
                         if req.if_exists {
-                            return Ok(RemoveShareAccountsReply {});
+                            return Ok(RemoveShareAccountsReply {
+                                removed_accounts: vec![],
+                                not_found_accounts: req.accounts.clone(),
+                            });
                         }
                     }
                     return Err(e);
                 }
             };
+            share_has_to_be_unfrozen(&share_meta, name_key, "remove_share_tenants")?;
 
             let mut remove_share_account_keys_and_seqs = vec![];
+            let mut not_found_accounts = vec![];
             for account in req.accounts.iter() {
-                if account == &name_key.tenant {
-                    continue;
-                }
-                if share_meta.has_account(account) {
+                if account == &name_key.tenant || !share_meta.has_account(account) {
+                    not_found_accounts.push(account.clone());
+                } else {
                     let share_account_key = ShareAccountNameIdent {
                         account: account.clone(),
                         share_id,
@@ -431,10 +692,12 @@ impl<KV: KVApi> ShareApi for KV {
                 }
             }
 
+            // None of the accounts is granted, there is nothing to remove.
             if remove_share_account_keys_and_seqs.is_empty() {
-                return Err(MetaError::AppError(AppError::UnknownShareAccounts(
-                    UnknownShareAccounts::new(&req.accounts, share_id, "unknown share account"),
-                )));
+                return Ok(RemoveShareAccountsReply {
+                    removed_accounts: vec![],
+                    not_found_accounts,
+                });
             }
 
             // Remove share account by these operations:
@@ -457,6 +720,7 @@ impl<KV: KVApi> ShareApi for KV {
 
                     share_meta.del_account(&share_account_key_and_seq.0.account);
                 }
+                share_meta.update_on = Some(Utc::now());
                 if_then.push(txn_op_put(&id_key, serialize_struct(&share_meta)?)); /* (share_id) -> share_meta */
 
                 let txn_req = TxnRequest {
@@ -474,7 +738,14 @@ impl<KV: KVApi> ShareApi for KV {
                 );
 
                 if succ {
-                    return Ok(RemoveShareAccountsReply {});
+                    let removed_accounts = remove_share_account_keys_and_seqs
+                        .into_iter()
+                        .map(|(share_account_key, _seq)| share_account_key.account)
+                        .collect();
+                    return Ok(RemoveShareAccountsReply {
+                        removed_accounts,
+                        not_found_accounts,
+                    });
                 }
             }
         }
@@ -484,6 +755,124 @@ impl<KV: KVApi> ShareApi for KV {
         )))
     }
 
+    async fn transfer_share_accounts(
+        &self,
+        from: ShareNameIdent,
+        to: ShareNameIdent,
+    ) -> MetaResult<TransferShareAccountsReply> {
+        debug!(
+            from = debug(&from),
+            to = debug(&to),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (from_id_seq, from_id, from_meta_seq, mut from_meta) =
+                get_share_or_err(self, &from, format!("transfer_share_accounts: {}", &from))
+                    .await?;
+
+            if from == to {
+                return Ok(TransferShareAccountsReply {
+                    transferred_accounts: vec![],
+                    skipped_accounts: from_meta.get_accounts(),
+                });
+            }
+
+            let (to_id_seq, to_id, to_meta_seq, mut to_meta) =
+                get_share_or_err(self, &to, format!("transfer_share_accounts: {}", &to)).await?;
+            share_has_to_be_unfrozen(&from_meta, &from, "transfer_share_accounts")?;
+            share_has_to_be_unfrozen(&to_meta, &to, "transfer_share_accounts")?;
+
+            let from_id_key = ShareId { share_id: from_id };
+            let to_id_key = ShareId { share_id: to_id };
+            let mut condition = vec![
+                txn_cond_seq(&from, Eq, from_id_seq),
+                txn_cond_seq(&from_id_key, Eq, from_meta_seq),
+                txn_cond_seq(&to, Eq, to_id_seq),
+                txn_cond_seq(&to_id_key, Eq, to_meta_seq),
+            ];
+            let mut if_then = vec![];
+
+            // Move the accounts by these operations:
+            // del (account, from_id)
+            // add (account, to_id) -> share_account_meta, if the account is not on the target share
+            // mod both share_meta
+            let mut transferred_accounts = vec![];
+            let mut skipped_accounts = vec![];
+            for account in from_meta.get_accounts() {
+                let from_account_key = ShareAccountNameIdent {
+                    account: account.clone(),
+                    share_id: from_id,
+                };
+                let (from_account_seq, mut share_account_meta) = get_share_account_meta_or_err(
+                    self,
+                    &from_account_key,
+                    format!("transfer_share_accounts: {}", from_id),
+                )
+                .await?;
+
+                condition.push(txn_cond_seq(&from_account_key, Eq, from_account_seq));
+                if_then.push(txn_op_del(&from_account_key)); // del (account, from_id)
+                from_meta.del_account(&account);
+
+                if account == to.tenant || to_meta.has_account(&account) {
+                    skipped_accounts.push(account);
+                    continue;
+                }
+
+                let to_account_key = ShareAccountNameIdent {
+                    account: account.clone(),
+                    share_id: to_id,
+                };
+                share_account_meta.share_id = to_id;
+                condition.push(txn_cond_seq(&to_account_key, Eq, 0));
+                if_then.push(txn_op_put(
+                    &to_account_key,
+                    serialize_struct(&share_account_meta)?,
+                )); /* (account, to_id) -> share_account_meta */
+                to_meta.add_account(account.clone());
+                transferred_accounts.push(account);
+            }
+            let now = Utc::now();
+            from_meta.update_on = Some(now);
+            if !transferred_accounts.is_empty() {
+                to_meta.update_on = Some(now);
+            }
+            if_then.push(txn_op_put(&from_id_key, serialize_struct(&from_meta)?)); /* (from_id) -> share_meta */
+            if_then.push(txn_op_put(&to_id_key, serialize_struct(&to_meta)?)); /* (to_id) -> share_meta */
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                from_id = debug(&from_id_key),
+                to_id = debug(&to_id_key),
+                succ = display(succ),
+                "transfer_share_accounts"
+            );
+
+            if succ {
+                return Ok(TransferShareAccountsReply {
+                    transferred_accounts,
+                    skipped_accounts,
+                });
+            }
+        }
+
+        Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("transfer_share_accounts", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
     async fn grant_share_object(
         &self,
         req: GrantShareObjectReq,
@@ -492,6 +881,7 @@ impl<KV: KVApi> ShareApi for KV {
 
         let share_name_key = &req.share_name;
         let mut retry = 0;
+        let mut last_conflict = None;
         while retry < TXN_MAX_RETRY_TIMES {
             retry += 1;
             let res = get_share_or_err(
@@ -507,15 +897,25 @@ impl<KV: KVApi> ShareApi for KV {
                     return Err(e);
                 }
             };
+            share_has_to_be_unfrozen(&share_meta, share_name_key, "grant_share_object")?;
+            check_share_protected_database(&req.object, &req.protected_databases)?;
 
             let seq_and_id =
                 get_share_object_seq_and_id(self, &req.object, &share_name_key.tenant).await?;
 
             check_share_object(&share_meta.database, &seq_and_id, &req.object)?;
+            check_share_privilege(
+                &ShareGrantObject::new(&seq_and_id),
+                req.privilege,
+                &req.object,
+            )?;
+
+            // `All` stands for all the privileges applicable to the kind of the object.
+            let privileges = ShareGrantObject::new(&seq_and_id).expand_privilege(req.privilege);
 
             // Check the object privilege has been granted
             let has_granted_privileges =
-                share_meta.has_granted_privileges(&req.object, &seq_and_id, req.privilege)?;
+                share_meta.has_granted_privileges(&req.object, &seq_and_id, privileges)?;
 
             if has_granted_privileges {
                 return Ok(GrantShareObjectReply {});
@@ -537,7 +937,7 @@ impl<KV: KVApi> ShareApi for KV {
                 let mut share_ids: ObjectSharedByShareIds = res.1;
                 share_ids.add(share_id);
 
-                share_meta.grant_object_privileges(object.clone(), req.privilege, req.grant_on);
+                share_meta.grant_object_privileges(object.clone(), privileges, req.grant_on);
 
                 // condition
                 let mut condition: Vec<TxnCondition> = vec![
@@ -551,7 +951,7 @@ impl<KV: KVApi> ShareApi for KV {
                     txn_op_put(&id_key, serialize_struct(&share_meta)?), /* (share_id) -> share_meta */
                     txn_op_put(&object, serialize_struct(&share_ids)?),  /* (object) -> share_ids */
                 ];
-                add_grant_object_txn_if_then(share_id, seq_and_id, &mut if_then)?;
+                add_grant_object_txn_if_then(&[share_id], seq_and_id, &mut if_then)?;
 
                 let txn_req = TxnRequest {
                     condition,
@@ -559,7 +959,7 @@ impl<KV: KVApi> ShareApi for KV {
                     else_then: vec![],
                 };
 
-                let (succ, _responses) = send_txn(self, txn_req).await?;
+                let (succ, conflict) = send_txn_with_conflict(self, txn_req).await?;
 
                 debug!(
                     name = debug(&share_name_key),
@@ -571,48 +971,145 @@ impl<KV: KVApi> ShareApi for KV {
                 if succ {
                     return Ok(GrantShareObjectReply {});
                 }
+                last_conflict = conflict;
             }
         }
 
         Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
-            TxnRetryMaxTimes::new("grant_share_object", TXN_MAX_RETRY_TIMES),
+            TxnRetryMaxTimes::new("grant_share_object", TXN_MAX_RETRY_TIMES)
+                .with_last_conflict(last_conflict),
         )))
     }
 
-    async fn revoke_share_object(
+    async fn grant_object_to_shares(
         &self,
-        req: RevokeShareObjectReq,
-    ) -> MetaResult<RevokeShareObjectReply> {
+        req: GrantObjectToSharesReq,
+    ) -> MetaResult<GrantObjectToSharesReply> {
         debug!(req = debug(&req), "ShareApi: {}", func_name!());
 
-        let share_name_key = &req.share_name;
+        let tenant = match req.share_names.first() {
+            Some(share_name) => share_name.tenant.clone(),
+            None => return Ok(GrantObjectToSharesReply {}),
+        };
+        check_share_protected_database(&req.object, &req.protected_databases)?;
+
         let mut retry = 0;
         while retry < TXN_MAX_RETRY_TIMES {
             retry += 1;
-            let res = get_share_or_err(
-                self,
-                share_name_key,
-                format!("revoke_share_object: {}", &share_name_key),
-            )
-            .await;
 
-            let (share_id_seq, share_id, share_meta_seq, mut share_meta) = match res {
-                Ok(x) => x,
-                Err(e) => {
-                    return Err(e);
-                }
-            };
+            let seq_and_id = get_share_object_seq_and_id(self, &req.object, &tenant).await?;
+            let object = ShareGrantObject::new(&seq_and_id);
+            check_share_privilege(&object, req.privilege, &req.object)?;
 
-            let seq_and_id =
-                get_share_object_seq_and_id(self, &req.object, &share_name_key.tenant).await?;
+            // `All` stands for all the privileges applicable to the kind of the object.
+            let privileges = object.expand_privilege(req.privilege);
 
-            check_share_object(&share_meta.database, &seq_and_id, &req.object)?;
+            // All the share ids are added to the single (object) -> share_ids record.
+            let (share_ids_seq, mut share_ids) =
+                get_object_shared_by_share_ids(self, &object).await?;
 
-            // Check the object privilege has not been granted.
-            let has_granted_privileges =
-                share_meta.has_granted_privileges(&req.object, &seq_and_id, req.privilege)?;
+            let mut condition: Vec<TxnCondition> = vec![txn_cond_seq(&object, Eq, share_ids_seq)];
+            add_txn_condition(&seq_and_id, &mut condition);
+            let mut if_then = vec![];
+            let mut granted_share_ids = vec![];
 
-            if !has_granted_privileges {
+            for share_name_key in &req.share_names {
+                let (share_id_seq, share_id, share_meta_seq, mut share_meta) = get_share_or_err(
+                    self,
+                    share_name_key,
+                    format!("grant_object_to_shares: {}", share_name_key),
+                )
+                .await?;
+                share_has_to_be_unfrozen(&share_meta, share_name_key, "grant_object_to_shares")?;
+
+                check_share_object(&share_meta.database, &seq_and_id, &req.object)?;
+
+                if granted_share_ids.contains(&share_id)
+                    || share_meta.has_granted_privileges(&req.object, &seq_and_id, privileges)?
+                {
+                    continue;
+                }
+
+                share_ids.add(share_id);
+                share_meta.grant_object_privileges(object.clone(), privileges, req.grant_on);
+
+                let id_key = ShareId { share_id };
+                condition.push(txn_cond_seq(share_name_key, Eq, share_id_seq));
+                condition.push(txn_cond_seq(&id_key, Eq, share_meta_seq));
+                if_then.push(txn_op_put(&id_key, serialize_struct(&share_meta)?)); /* (share_id) -> share_meta */
+                granted_share_ids.push(share_id);
+            }
+
+            if granted_share_ids.is_empty() {
+                return Ok(GrantObjectToSharesReply {});
+            }
+
+            if_then.push(txn_op_put(&object, serialize_struct(&share_ids)?)); /* (object) -> share_ids */
+            add_grant_object_txn_if_then(&granted_share_ids, seq_and_id, &mut if_then)?;
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                object = debug(&object),
+                share_ids = debug(&granted_share_ids),
+                succ = display(succ),
+                "grant_object_to_shares"
+            );
+
+            if succ {
+                return Ok(GrantObjectToSharesReply {});
+            }
+        }
+
+        Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("grant_object_to_shares", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
+    async fn revoke_share_object(
+        &self,
+        req: RevokeShareObjectReq,
+    ) -> MetaResult<RevokeShareObjectReply> {
+        debug!(req = debug(&req), "ShareApi: {}", func_name!());
+
+        let share_name_key = &req.share_name;
+        let mut retry = 0;
+        let mut last_conflict = None;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+            let res = get_share_or_err(
+                self,
+                share_name_key,
+                format!("revoke_share_object: {}", &share_name_key),
+            )
+            .await;
+
+            let (share_id_seq, share_id, share_meta_seq, mut share_meta) = match res {
+                Ok(x) => x,
+                Err(e) => {
+                    return Err(e);
+                }
+            };
+            share_has_to_be_unfrozen(&share_meta, share_name_key, "revoke_share_object")?;
+
+            let seq_and_id =
+                get_share_object_seq_and_id(self, &req.object, &share_name_key.tenant).await?;
+
+            check_share_object(&share_meta.database, &seq_and_id, &req.object)?;
+
+            // `All` revokes whatever privileges of the object have been granted.
+            let privileges = ShareGrantObject::new(&seq_and_id).expand_privilege(req.privilege);
+
+            // Check the object privilege has not been granted.
+            let granted_privileges = share_meta.get_granted_privileges(&req.object, &seq_and_id)?;
+
+            if !granted_privileges.intersects(privileges) {
                 return Ok(RevokeShareObjectReply {});
             }
 
@@ -625,11 +1122,8 @@ impl<KV: KVApi> ShareApi for KV {
                 let id_key = ShareId { share_id };
                 // modify the share_meta add privilege
                 let object = ShareGrantObject::new(&seq_and_id);
-                let _ = share_meta.revoke_object_privileges(
-                    object.clone(),
-                    req.privilege,
-                    req.update_on,
-                )?;
+                let _ =
+                    share_meta.revoke_object_privileges(object.clone(), privileges, Utc::now())?;
 
                 // modify share_ids
                 let res = get_object_shared_by_share_ids(self, &object).await?;
@@ -662,7 +1156,7 @@ impl<KV: KVApi> ShareApi for KV {
                     else_then: vec![],
                 };
 
-                let (succ, _responses) = send_txn(self, txn_req).await?;
+                let (succ, conflict) = send_txn_with_conflict(self, txn_req).await?;
 
                 debug!(
                     name = debug(&share_name_key),
@@ -674,14 +1168,153 @@ impl<KV: KVApi> ShareApi for KV {
                 if succ {
                     return Ok(RevokeShareObjectReply {});
                 }
+                last_conflict = conflict;
             }
         }
 
         Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
-            TxnRetryMaxTimes::new("revoke_share_object", TXN_MAX_RETRY_TIMES),
+            TxnRetryMaxTimes::new("revoke_share_object", TXN_MAX_RETRY_TIMES)
+                .with_last_conflict(last_conflict),
         )))
     }
 
+    async fn purge_share_grants(&self, share_name: ShareNameIdent) -> MetaResult<()> {
+        debug!(
+            share_name = debug(&share_name),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let name_key = &share_name;
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (share_id_seq, share_id, share_meta_seq, mut share_meta) =
+                get_share_or_err(self, name_key, format!("purge_share_grants: {}", &name_key))
+                    .await?;
+            share_has_to_be_unfrozen(&share_meta, name_key, "purge_share_grants")?;
+
+            // Purge the grants by these operations:
+            // clear the objects and accounts of share_meta and upsert (share_id) -> share_meta
+            // remove share_id from the share_ids of every granted object
+            // remove share_id from db_meta.shared_by of the granted database
+            // del all (account, share_id) -> share_account_meta
+            let id_key = ShareId { share_id };
+            let mut condition = vec![
+                txn_cond_seq(name_key, Eq, share_id_seq),
+                txn_cond_seq(&id_key, Eq, share_meta_seq),
+            ];
+            let mut if_then = vec![];
+
+            let mut objects = vec![];
+            if let Some(entry) = &share_meta.database {
+                objects.push(entry.object.clone());
+            }
+            objects.extend(
+                share_meta
+                    .entries
+                    .values()
+                    .map(|entry| entry.object.clone()),
+            );
+            for object in objects.iter() {
+                remove_shared_by_share_txn(self, share_id, object, &mut condition, &mut if_then)
+                    .await?;
+
+                if let ShareGrantObject::Database(db_id) = object {
+                    let key = DatabaseId { db_id: *db_id };
+                    let (db_meta_seq, db_meta): (_, Option<DatabaseMeta>) =
+                        get_struct_value(self, &key).await?;
+                    // The database may have been vacuumed.
+                    if let Some(mut db_meta) = db_meta {
+                        db_meta.shared_by.remove(&share_id);
+                        condition.push(txn_cond_seq(&key, Eq, db_meta_seq));
+                        if_then.push(txn_op_put(&key, serialize_struct(&db_meta)?));
+                        /* (db_id) -> db_meta */
+                    }
+                }
+            }
+
+            for account in share_meta.get_accounts() {
+                let share_account_key = ShareAccountNameIdent { account, share_id };
+                let (account_seq, _meta): (_, Option<ShareAccountMeta>) =
+                    get_struct_value(self, &share_account_key).await?;
+                condition.push(txn_cond_seq(&share_account_key, Eq, account_seq));
+                if account_seq > 0 {
+                    if_then.push(txn_op_del(&share_account_key)); /* del (account, share_id) */
+                }
+            }
+
+            share_meta.database = None;
+            share_meta.entries.clear();
+            share_meta.accounts.clear();
+            share_meta.update_on = Some(Utc::now());
+            if_then.push(txn_op_put(&id_key, serialize_struct(&share_meta)?)); /* (share_id) -> share_meta */
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&name_key),
+                id = debug(&id_key),
+                succ = display(succ),
+                "purge_share_grants"
+            );
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("purge_share_grants", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
+    async fn get_inbound_share_grant_objects(
+        &self,
+        consumer: String,
+        share_name: ShareNameIdent,
+    ) -> MetaResult<GetShareGrantObjectReply> {
+        debug!(
+            consumer = debug(&consumer),
+            share_name = debug(&share_name),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let msg = format!("get_inbound_share_grant_objects: {}", &share_name);
+        let (_share_id_seq, _share_id, _share_meta_seq, share_meta) =
+            get_share_or_err(self, &share_name, &msg).await?;
+        if !share_meta.has_account(&consumer) {
+            return Err(MetaError::AppError(AppError::UnknownShare(
+                UnknownShare::new(&share_name.share_name, msg),
+            )));
+        }
+
+        // The share meta is at hand, only the accesses due to be recorded cost a write.
+        let access_on = Utc::now();
+        if share_access_needs_record(&share_meta, access_on) {
+            if let Err(e) = self
+                .record_share_access(share_name.clone(), access_on)
+                .await
+            {
+                warn!("record the access to share {}: {}", share_name, e);
+            }
+        }
+
+        self.get_share_grant_objects(GetShareGrantObjectReq {
+            share_name,
+            include_dropped: false,
+        })
+        .await
+    }
+
     async fn get_share_grant_objects(
         &self,
         req: GetShareGrantObjectReq,
@@ -735,16 +1368,47 @@ impl<KV: KVApi> ShareApi for KV {
         }
         entries.push(share_meta.database.unwrap());
 
+        let grant_objects = entries
+            .iter()
+            .map(|entry| entry.object.clone())
+            .collect::<Vec<_>>();
+        let object_names = resolve_object_names(self, &database_name, &grant_objects).await?;
+
+        // The object has been dropped, only the provider asks for the dangling grants.
+        let dropped_objects = match req.include_dropped {
+            true => entries
+                .iter()
+                .zip(object_names.iter())
+                .filter(|(_, object)| object.is_none())
+                .map(|(entry, _)| entry.object.clone())
+                .collect::<Vec<_>>(),
+            false => vec![],
+        };
+        let mut dropped_names = get_dropped_object_names(self, &database_name, &dropped_objects)
+            .await?
+            .into_iter();
+
         let mut objects = vec![];
-        for entry in entries {
-            let object = get_object_name_from_id(self, &database_name, entry.object).await?;
+        for (entry, object) in entries.into_iter().zip(object_names) {
             match object {
                 Some(object) => objects.push(ShareGrantReplyObject {
                     object,
                     privileges: entry.privileges,
                     grant_on: entry.grant_on,
+                    privilege_grant_on: entry.get_privilege_grant_on(),
+                    status: ShareGrantObjectStatus::Granted,
                 }),
-                None => {}
+                None => {
+                    if let Some(object) = dropped_names.next() {
+                        objects.push(ShareGrantReplyObject {
+                            object,
+                            privileges: entry.privileges,
+                            grant_on: entry.grant_on,
+                            privilege_grant_on: entry.get_privilege_grant_on(),
+                            status: ShareGrantObjectStatus::Dropped,
+                        });
+                    }
+                }
             }
         }
 
@@ -754,130 +1418,681 @@ impl<KV: KVApi> ShareApi for KV {
         })
     }
 
+    async fn get_share_object_privilege(
+        &self,
+        share_name: ShareNameIdent,
+        object: ShareGrantObjectName,
+    ) -> MetaResult<Option<ShareGrantReplyObject>> {
+        debug!(
+            share_name = debug(&share_name),
+            object = debug(&object),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let (_share_id_seq, _share_id, _share_meta_seq, share_meta) = get_share_or_err(
+            self,
+            &share_name,
+            format!("get_share_object_privilege: {}", &share_name),
+        )
+        .await?;
+
+        let grant_object = get_share_grant_object(
+            self,
+            &share_name.tenant,
+            object.clone(),
+            "get_share_object_privilege",
+        )
+        .await?;
+
+        Ok(share_meta
+            .get_grant_entry(grant_object)
+            .map(|entry| ShareGrantReplyObject {
+                object,
+                privileges: entry.privileges,
+                grant_on: entry.grant_on,
+                privilege_grant_on: entry.get_privilege_grant_on(),
+                status: ShareGrantObjectStatus::Granted,
+            }))
+    }
+
     // Return all the grant tenants of the share
     async fn get_grant_tenants_of_share(
         &self,
         req: GetShareGrantTenantsReq,
     ) -> MetaResult<GetShareGrantTenantsReply> {
-        let reply = get_outbound_shared_accounts_by_name(self, &req.share_name).await?;
+        let (reply, _share_meta) =
+            get_outbound_shared_accounts_by_name(self, &req.share_name).await?;
+        let accounts = reply.accounts.unwrap_or_default();
+
+        let account_metas = if req.include_meta {
+            let (share_id_seq, share_id) = get_u64_value(self, &req.share_name).await?;
+            share_has_to_exist(
+                share_id_seq,
+                &req.share_name,
+                format!("get_grant_tenants_of_share: {}", &req.share_name),
+            )?;
+            get_share_account_metas(self, share_id, &accounts).await?
+        } else {
+            vec![]
+        };
 
         Ok(GetShareGrantTenantsReply {
-            accounts: reply.accounts.unwrap_or_default(),
+            accounts,
+            account_metas,
         })
     }
 
-    // Return all the grant privileges of the object
-    async fn get_grant_privileges_of_object(
+    async fn get_share_accounts(
         &self,
-        req: GetObjectGrantPrivilegesReq,
-    ) -> MetaResult<GetObjectGrantPrivilegesReply> {
-        let entries = match req.object {
-            ShareGrantObjectName::Database(db_name) => {
-                let db_name_key = DatabaseNameIdent {
-                    tenant: req.tenant,
-                    db_name: db_name.clone(),
-                };
-                let (db_seq, db_id) = get_u64_value(self, &db_name_key).await?;
-                db_has_to_exist(
-                    db_seq,
-                    &db_name_key,
-                    format!("get_grant_privileges_of_object: {}", db_name_key),
-                )?;
-                let object = ShareGrantObject::Database(db_id);
-                let (_seq, share_ids) = get_object_shared_by_share_ids(self, &object).await?;
-                let mut entries = vec![];
-                for share_id in share_ids.share_ids.iter() {
-                    let (_seq, share_name) = get_share_id_to_name_or_err(
-                        self,
-                        *share_id,
-                        format!("get_grant_privileges_of_object: {}", &share_id),
-                    )
-                    .await?;
+        share_name: ShareNameIdent,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> MetaResult<(Vec<String>, bool)> {
+        debug!(
+            share_name = debug(&share_name),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let (_share_id_seq, _share_id, _share_meta_seq, share_meta) = get_share_or_err(
+            self,
+            &share_name,
+            format!("get_share_accounts: {}", share_name),
+        )
+        .await?;
 
-                    let (_seq, share_meta) = get_share_meta_by_id_or_err(
-                        self,
-                        *share_id,
-                        format!("get_grant_privileges_of_object: {}", &share_id),
-                    )
-                    .await?;
+        // The accounts are kept in a set, `get_accounts()` returns them ordered by name.
+        Ok(page_of(share_meta.get_accounts(), offset, limit))
+    }
 
-                    entries.push((
-                        share_meta.get_grant_entry(object.clone()),
-                        share_name.share_name,
-                    ));
-                }
+    async fn record_share_access(
+        &self,
+        share_name: ShareNameIdent,
+        access_on: DateTime<Utc>,
+    ) -> MetaResult<bool> {
+        debug!(
+            share_name = debug(&share_name),
+            "ShareApi: {}",
+            func_name!()
+        );
 
-                entries
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (share_id_seq, share_id, share_meta_seq, mut share_meta) = get_share_or_err(
+                self,
+                &share_name,
+                format!("record_share_access: {}", share_name),
+            )
+            .await?;
+
+            if !share_access_needs_record(&share_meta, access_on) {
+                return Ok(false);
             }
-            ShareGrantObjectName::Table(db_name, table_name) => {
-                let db_name_key = DatabaseNameIdent {
-                    tenant: req.tenant.clone(),
-                    db_name: db_name.clone(),
-                };
-                let (db_seq, db_id) = get_u64_value(self, &db_name_key).await?;
-                db_has_to_exist(
-                    db_seq,
-                    &db_name_key,
-                    format!("get_grant_privileges_of_object: {}", db_name_key),
-                )?;
-
-                let table_name_key = DBIdTableName {
-                    db_id,
-                    table_name: table_name.clone(),
-                };
-                let (table_seq, table_id) = get_u64_value(self, &table_name_key).await?;
-                table_has_to_exist(
-                    table_seq,
-                    &TableNameIdent {
-                        tenant: req.tenant.clone(),
-                        db_name: db_name.clone(),
-                        table_name,
-                    },
-                    format!("get_grant_privileges_of_object: {}", table_name_key),
-                )?;
-
-                let object = ShareGrantObject::Table(table_id);
-                let (_seq, share_ids) = get_object_shared_by_share_ids(self, &object).await?;
-                let mut entries = vec![];
-                for share_id in share_ids.share_ids.iter() {
-                    let (_seq, share_name) = get_share_id_to_name_or_err(
-                        self,
-                        *share_id,
-                        format!("get_grant_privileges_of_object: {}", &share_id),
-                    )
-                    .await?;
+            share_meta.last_accessed_on = Some(access_on);
 
-                    let (_seq, share_meta) = get_share_meta_by_id_or_err(
-                        self,
-                        *share_id,
-                        format!("get_grant_privileges_of_object: {}", &share_id),
-                    )
-                    .await?;
+            let id_key = ShareId { share_id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    txn_cond_seq(&share_name, Eq, share_id_seq),
+                    txn_cond_seq(&id_key, Eq, share_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&id_key, serialize_struct(&share_meta)?), /* (share_id) -> share_meta */
+                ],
+                else_then: vec![],
+            };
 
-                    entries.push((
-                        share_meta.get_grant_entry(object.clone()),
-                        share_name.share_name,
-                    ));
-                }
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&share_name),
+                id = debug(&id_key),
+                succ = display(succ),
+                "record_share_access"
+            );
 
-                entries
+            if succ {
+                return Ok(true);
             }
-        };
+        }
+
+        Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("record_share_access", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
+    async fn alter_share_freeze(&self, share_name: ShareNameIdent, frozen: bool) -> MetaResult<()> {
+        debug!(
+            share_name = debug(&share_name),
+            frozen,
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let (share_id_seq, share_id, share_meta_seq, mut share_meta) = get_share_or_err(
+                self,
+                &share_name,
+                format!("alter_share_freeze: {}", share_name),
+            )
+            .await?;
+
+            if share_meta.frozen == frozen {
+                return Ok(());
+            }
+            share_meta.frozen = frozen;
+
+            let id_key = ShareId { share_id };
+            let txn_req = TxnRequest {
+                condition: vec![
+                    txn_cond_seq(&share_name, Eq, share_id_seq),
+                    txn_cond_seq(&id_key, Eq, share_meta_seq),
+                ],
+                if_then: vec![
+                    txn_op_put(&id_key, serialize_struct(&share_meta)?), /* (share_id) -> share_meta */
+                ],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                name = debug(&share_name),
+                id = debug(&id_key),
+                frozen,
+                succ = display(succ),
+                "alter_share_freeze"
+            );
+
+            if succ {
+                return Ok(());
+            }
+        }
+
+        Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("alter_share_freeze", TXN_MAX_RETRY_TIMES),
+        )))
+    }
+
+    // Return all the grant privileges of the object
+    async fn get_grant_privileges_of_object(
+        &self,
+        req: GetObjectGrantPrivilegesReq,
+    ) -> MetaResult<GetObjectGrantPrivilegesReply> {
+        let privilege = req.privilege;
+        let object = get_share_grant_object(
+            self,
+            &req.tenant,
+            req.object,
+            "get_grant_privileges_of_object",
+        )
+        .await?;
+        let entries = get_object_grant_entries(self, &object).await?;
         let mut privileges = vec![];
         for (entry, share_name) in entries {
             match entry {
-                Some(entry) => {
+                Some(entry)
+                    if privilege.map_or(true, |privilege| entry.privileges.contains(privilege)) =>
+                {
                     privileges.push(ObjectGrantPrivilege {
-                        share_name,
+                        share_name: share_name.share_name,
                         privileges: entry.privileges,
                         grant_on: entry.grant_on,
                     });
                 }
+                Some(_) => {}
                 None => {}
             }
         }
         Ok(GetObjectGrantPrivilegesReply { privileges })
     }
+
+    async fn get_shares_by_object(
+        &self,
+        object: ShareGrantObjectName,
+        tenant: String,
+    ) -> MetaResult<Vec<(ShareNameIdent, BitFlags<ShareGrantObjectPrivilege>)>> {
+        let object = get_share_grant_object(self, &tenant, object, "get_shares_by_object").await?;
+        let entries = get_object_grant_entries(self, &object).await?;
+
+        Ok(entries
+            .into_iter()
+            .filter_map(|(entry, share_name)| entry.map(|entry| (share_name, entry.privileges)))
+            .collect())
+    }
+
+    async fn validate_share(
+        &self,
+        share_name: ShareNameIdent,
+    ) -> MetaResult<ShareValidationReport> {
+        debug!(
+            share_name = debug(&share_name),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let (_share_id_seq, share_id, _share_meta_seq, share_meta) = get_share_or_err(
+            self,
+            &share_name,
+            format!("validate_share: {}", &share_name),
+        )
+        .await?;
+
+        let mut inconsistencies = vec![];
+
+        // (tenant, share_name) -> share_id -> (tenant, share_name)
+        let (_share_name_seq, found): (_, Option<ShareNameIdent>) =
+            get_struct_value(self, &ShareIdToName { share_id }).await?;
+        if found.as_ref() != Some(&share_name) {
+            inconsistencies.push(ShareInconsistency::IdToNameMismatch { found });
+        }
+
+        for account in share_meta.get_accounts() {
+            let share_account_key = ShareAccountNameIdent {
+                account: account.clone(),
+                share_id,
+            };
+            let (seq, _meta): (_, Option<ShareAccountMeta>) =
+                get_struct_value(self, &share_account_key).await?;
+            if seq == 0 {
+                inconsistencies.push(ShareInconsistency::MissingAccountMeta { account });
+            }
+        }
+
+        let mut objects = vec![];
+        objects.extend(share_meta.database.iter().map(|entry| entry.object.clone()));
+        objects.extend(
+            share_meta
+                .entries
+                .values()
+                .map(|entry| entry.object.clone()),
+        );
+
+        // Only whether the object exists is checked, the database name is not used.
+        let database_name = String::new();
+        let names = resolve_object_names(self, &Some(&database_name), &objects).await?;
+        for (object, name) in objects.into_iter().zip(names) {
+            if name.is_none() {
+                inconsistencies.push(ShareInconsistency::UnresolvedObject {
+                    object: object.clone(),
+                });
+            }
+
+            let (_seq, share_ids) = get_object_shared_by_share_ids(self, &object).await?;
+            if !share_ids.share_ids.contains(&share_id) {
+                inconsistencies.push(ShareInconsistency::ObjectNotSharedByShare { object });
+            }
+        }
+
+        inconsistencies.extend(tables_outside_shared_database(self, &share_meta).await?);
+
+        Ok(ShareValidationReport {
+            share_name,
+            share_id,
+            inconsistencies,
+        })
+    }
+
+    async fn get_share_meta_raw(&self, share_name: ShareNameIdent) -> MetaResult<(u64, ShareMeta)> {
+        debug!(
+            share_name = debug(&share_name),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let (_share_id_seq, _share_id, share_meta_seq, share_meta) = get_share_or_err(
+            self,
+            &share_name,
+            format!("get_share_meta_raw: {}", &share_name),
+        )
+        .await?;
+
+        Ok((share_meta_seq, share_meta))
+    }
+
+    async fn list_outbound_shares(
+        &self,
+        tenant: String,
+    ) -> MetaResult<Vec<(ShareAccountReply, ShareMeta)>> {
+        debug!(tenant = debug(&tenant), "ShareApi: {}", func_name!());
+
+        let mut shares = vec![];
+        for share_name in list_share_names(self, &tenant).await? {
+            match get_outbound_shared_accounts_by_name(self, &share_name).await {
+                Ok(share) => shares.push(share),
+                Err(e) => warn!("skip the outbound share {}: {}", share_name, e),
+            }
+        }
+
+        Ok(shares)
+    }
+
+    async fn diff_share_grants(
+        &self,
+        a: ShareNameIdent,
+        b: ShareNameIdent,
+    ) -> MetaResult<ShareGrantDiff> {
+        debug!(a = debug(&a), b = debug(&b), "ShareApi: {}", func_name!());
+
+        let mut unresolved = vec![];
+        let grants_a = get_share_grants_by_name(self, &a, &mut unresolved).await?;
+        let grants_b = get_share_grants_by_name(self, &b, &mut unresolved).await?;
+
+        Ok(ShareGrantDiff {
+            only_in_a: diff_share_grants_by_name(&grants_a, &grants_b),
+            only_in_b: diff_share_grants_by_name(&grants_b, &grants_a),
+            unresolved,
+        })
+    }
+
+    async fn get_share_create_statement(&self, share_name: ShareNameIdent) -> MetaResult<String> {
+        debug!(
+            share_name = debug(&share_name),
+            "ShareApi: {}",
+            func_name!()
+        );
+
+        let (_share_id_seq, _share_id, _share_meta_seq, share_meta) = get_share_or_err(
+            self,
+            &share_name,
+            format!("get_share_create_statement: {}", &share_name),
+        )
+        .await?;
+
+        let share = &share_name.share_name;
+        let mut statements = vec![];
+        match &share_meta.comment {
+            Some(comment) => statements.push(format!(
+                "CREATE SHARE {} COMMENT = '{}';",
+                share,
+                comment.replace('\'', "''")
+            )),
+            None => statements.push(format!("CREATE SHARE {};", share)),
+        }
+
+        if let Some(database) = &share_meta.database {
+            // The tables are dropped along with the database, none of them resolves without it.
+            let (database_name, database_dropped) =
+                match get_object_name_from_id(self, &None, database.object.clone()).await? {
+                    Some(ShareGrantObjectName::Database(db_name)) => (db_name, false),
+                    _ => match get_dropped_object_names(self, &None, &[database.object.clone()])
+                        .await?
+                        .pop()
+                    {
+                        Some(ShareGrantObjectName::Database(db_name)) => (db_name, true),
+                        _ => unreachable!("database MUST be Database object"),
+                    },
+                };
+
+            let entries = share_meta
+                .database
+                .iter()
+                .chain(share_meta.entries.values())
+                .collect::<Vec<_>>();
+            let objects = entries
+                .iter()
+                .map(|entry| entry.object.clone())
+                .collect::<Vec<_>>();
+            let names = match database_dropped {
+                true => vec![None; objects.len()],
+                false => resolve_object_names(self, &Some(&database_name), &objects).await?,
+            };
+            let dropped_objects = objects
+                .iter()
+                .zip(names.iter())
+                .filter(|(_, name)| name.is_none())
+                .map(|(object, _)| object.clone())
+                .collect::<Vec<_>>();
+            let mut dropped_names =
+                get_dropped_object_names(self, &Some(&database_name), &dropped_objects)
+                    .await?
+                    .into_iter();
+
+            for (entry, name) in entries.into_iter().zip(names) {
+                let (object, dropped) = match name {
+                    Some(object) => (object, false),
+                    // The dropped names are in the order of the objects.
+                    None => match dropped_names.next() {
+                        Some(object) => (object, true),
+                        None => unreachable!("each dropped object MUST have a name"),
+                    },
+                };
+
+                for privilege in entry.privileges.iter() {
+                    let statement =
+                        format!("GRANT {} ON {} TO SHARE {};", privilege, object, share);
+                    match dropped {
+                        true => statements.push(format!("-- dropped: {}", statement)),
+                        false => statements.push(statement),
+                    }
+                }
+            }
+        }
+
+        if !share_meta.accounts.is_empty() {
+            statements.push(format!(
+                "ALTER SHARE {} ADD TENANTS = {};",
+                share,
+                share_meta.get_accounts().join(",")
+            ));
+        }
+
+        Ok(statements.join("\n"))
+    }
+
+    async fn list_orphaned_share_accounts(
+        &self,
+        tenant: String,
+    ) -> MetaResult<Vec<ShareAccountNameIdent>> {
+        debug!(tenant = debug(&tenant), "ShareApi: {}", func_name!());
+
+        let tenant_share_name_key = ShareAccountNameIdent {
+            account: tenant,
+            share_id: 0,
+        };
+
+        let mut orphans = vec![];
+        let mut pager = list_keys_paged(self, &tenant_share_name_key, LIST_SHARE_KEYS_PAGE_SIZE);
+        while let Some(share_accounts) = pager.next_page().await? {
+            for share_account in share_accounts {
+                let share_id = share_account.share_id;
+                let res = get_share_meta_by_id_or_err(
+                    self,
+                    share_id,
+                    format!("list_orphaned_share_accounts: {}", share_id),
+                )
+                .await;
+                match res {
+                    Ok(_) => {}
+                    Err(MetaError::AppError(AppError::UnknownShareId(_))) => {
+                        orphans.push(share_account)
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        Ok(orphans)
+    }
+
+    async fn cleanup_orphaned_share_accounts(
+        &self,
+        tenant: String,
+    ) -> MetaResult<Vec<ShareAccountNameIdent>> {
+        debug!(tenant = debug(&tenant), "ShareApi: {}", func_name!());
+
+        let mut removed = vec![];
+        for share_account in self.list_orphaned_share_accounts(tenant).await? {
+            let (account_seq, _meta): (_, Option<ShareAccountMeta>) =
+                get_struct_value(self, &share_account).await?;
+            if account_seq == 0 {
+                // Already removed by another cleanup.
+                continue;
+            }
+
+            let txn_req = TxnRequest {
+                condition: vec![
+                    txn_cond_seq(&share_account, Eq, account_seq),
+                    // The share meta has to be still missing.
+                    txn_cond_seq(
+                        &ShareId {
+                            share_id: share_account.share_id,
+                        },
+                        Eq,
+                        0,
+                    ),
+                ],
+                if_then: vec![txn_op_del(&share_account)],
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+            debug!(
+                share_account = debug(&share_account),
+                succ = display(succ),
+                "cleanup_orphaned_share_accounts"
+            );
+
+            // A failed condition means the account changed since it was listed, leave it.
+            if succ {
+                removed.push(share_account);
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+/// Returns the granted objects of the share keyed by their names.
+///
+/// The objects that do not resolve to a name are pushed to `unresolved`.
+async fn get_share_grants_by_name(
+    kv_api: &(impl KVApi + ?Sized),
+    share_name: &ShareNameIdent,
+    unresolved: &mut Vec<ShareUnresolvedGrant>,
+) -> Result<BTreeMap<String, ShareGrantPrivileges>, MetaError> {
+    let (_share_id_seq, _share_id, _share_meta_seq, share_meta) = get_share_or_err(
+        kv_api,
+        share_name,
+        format!("diff_share_grants: {}", share_name),
+    )
+    .await?;
+
+    let mut grants = BTreeMap::new();
+    let database_obj = match &share_meta.database {
+        Some(entry) => entry.object.clone(),
+        None => return Ok(grants),
+    };
+    let entries = share_meta
+        .database
+        .iter()
+        .chain(share_meta.entries.values());
+
+    // The tables are named after the database, none of them resolves without it.
+    let database_name = match get_object_name_from_id(kv_api, &None, database_obj).await? {
+        Some(ShareGrantObjectName::Database(db_name)) => db_name,
+        _ => {
+            for entry in entries {
+                unresolved.push(ShareUnresolvedGrant {
+                    share_name: share_name.clone(),
+                    object: entry.object.clone(),
+                });
+            }
+            return Ok(grants);
+        }
+    };
+
+    let entries = entries.collect::<Vec<_>>();
+    let objects = entries
+        .iter()
+        .map(|entry| entry.object.clone())
+        .collect::<Vec<_>>();
+    let names = resolve_object_names(kv_api, &Some(&database_name), &objects).await?;
+    for (entry, object) in entries.into_iter().zip(names) {
+        match object {
+            Some(object) => {
+                grants.insert(object.to_string(), ShareGrantPrivileges {
+                    object,
+                    privileges: entry.privileges,
+                });
+            }
+            None => unresolved.push(ShareUnresolvedGrant {
+                share_name: share_name.clone(),
+                object: entry.object.clone(),
+            }),
+        }
+    }
+
+    Ok(grants)
+}
+
+/// Returns the privileges granted in `a` but not in `b`.
+fn diff_share_grants_by_name(
+    a: &BTreeMap<String, ShareGrantPrivileges>,
+    b: &BTreeMap<String, ShareGrantPrivileges>,
+) -> Vec<ShareGrantPrivileges> {
+    a.iter()
+        .filter_map(|(name, grant)| {
+            let privileges = match b.get(name) {
+                Some(other) => grant.privileges & !other.privileges,
+                None => grant.privileges,
+            };
+            (!privileges.is_empty()).then(|| ShareGrantPrivileges {
+                object: grant.object.clone(),
+                privileges,
+            })
+        })
+        .collect()
+}
+
+/// Resolves the name of a database or table to the object granted to a share.
+async fn get_share_grant_object(
+    kv_api: &(impl KVApi + ?Sized),
+    tenant: &str,
+    object: ShareGrantObjectName,
+    msg: impl Display,
+) -> Result<ShareGrantObject, MetaError> {
+    match object {
+        ShareGrantObjectName::Database(db_name) => {
+            let db_name_key = DatabaseNameIdent {
+                tenant: tenant.to_string(),
+                db_name,
+            };
+            let (db_seq, db_id) = get_u64_value(kv_api, &db_name_key).await?;
+            db_has_to_exist(db_seq, &db_name_key, format!("{}: {}", msg, db_name_key))?;
+            Ok(ShareGrantObject::Database(db_id))
+        }
+        ShareGrantObjectName::Table(db_name, table_name) => {
+            let db_name_key = DatabaseNameIdent {
+                tenant: tenant.to_string(),
+                db_name: db_name.clone(),
+            };
+            let (db_seq, db_id) = get_u64_value(kv_api, &db_name_key).await?;
+            db_has_to_exist(db_seq, &db_name_key, format!("{}: {}", msg, db_name_key))?;
+
+            let table_name_key = DBIdTableName {
+                db_id,
+                table_name: table_name.clone(),
+            };
+            let (table_seq, table_id) = get_u64_value(kv_api, &table_name_key).await?;
+            table_has_to_exist(
+                table_seq,
+                &TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name,
+                    table_name,
+                },
+                format!("{}: {}", msg, table_name_key),
+            )?;
+            Ok(ShareGrantObject::Table(table_id))
+        }
+    }
 }
 
 async fn get_object_shared_by_share_ids(
@@ -893,6 +2108,48 @@ async fn get_object_shared_by_share_ids(
     }
 }
 
+/// Returns the grant entry of the object in each share it is granted to, with the share name.
+/// An object that has never been shared costs no more reads than its share ids.
+async fn get_object_grant_entries(
+    kv_api: &(impl KVApi + ?Sized),
+    object: &ShareGrantObject,
+) -> Result<Vec<(Option<ShareGrantEntry>, ShareNameIdent)>, MetaError> {
+    let (_seq, share_ids) = get_object_shared_by_share_ids(kv_api, object).await?;
+    if share_ids.share_ids.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut entries = Vec::with_capacity(share_ids.share_ids.len());
+    for share_id in share_ids.share_ids.iter() {
+        let (_seq, share_name) = get_share_id_to_name_or_err(
+            kv_api,
+            *share_id,
+            format!("get_grant_privileges_of_object: {}", &share_id),
+        )
+        .await?;
+
+        let (_seq, share_meta) = get_share_meta_by_id_or_err(
+            kv_api,
+            *share_id,
+            format!("get_grant_privileges_of_object: {}", &share_id),
+        )
+        .await?;
+
+        entries.push((share_meta.get_grant_entry(object.clone()), share_name));
+    }
+    Ok(entries)
+}
+
+/// Whether an access to the share at `access_on` is outside the interval of the recorded one.
+fn share_access_needs_record(share_meta: &ShareMeta, access_on: DateTime<Utc>) -> bool {
+    match share_meta.last_accessed_on {
+        Some(last_accessed_on) => {
+            access_on >= last_accessed_on + Duration::seconds(SHARE_ACCESS_RECORD_INTERVAL_SECS)
+        }
+        None => true,
+    }
+}
+
 async fn get_share_database_name(
     kv_api: &(impl KVApi + ?Sized),
     share_meta: &ShareMeta,
@@ -920,10 +2177,136 @@ async fn get_share_database_name(
     }
 }
 
+/// Adds the accounts to the share in one transaction.
+///
+/// The accounts already added are skipped, or have their `share_on` refreshed if `update_existing`.
+/// Returns the accounts added and the accounts refreshed.
+async fn add_share_accounts_chunk(
+    kv_api: &impl KVApi,
+    name_key: &ShareNameIdent,
+    accounts: &[String],
+    share_on: DateTime<Utc>,
+    update_existing: bool,
+) -> Result<(Vec<String>, Vec<String>), MetaError> {
+    let mut retry = 0;
+    let mut last_conflict = None;
+    while retry < TXN_MAX_RETRY_TIMES {
+        retry += 1;
+
+        let (share_id_seq, share_id, share_meta_seq, mut share_meta) = get_share_or_err(
+            kv_api,
+            name_key,
+            format!("add_share_tenants: {}", &name_key),
+        )
+        .await?;
+        share_has_to_be_unfrozen(&share_meta, name_key, "add_share_tenants")?;
+
+        let mut add_share_account_keys = vec![];
+        let mut update_share_account_keys = vec![];
+        for account in accounts {
+            let share_account_key = ShareAccountNameIdent {
+                account: account.clone(),
+                share_id,
+            };
+            if !share_meta.has_account(account) {
+                add_share_account_keys.push(share_account_key);
+            } else if update_existing {
+                update_share_account_keys.push(share_account_key);
+            }
+        }
+        if add_share_account_keys.is_empty() && update_share_account_keys.is_empty() {
+            // Added by another request in the meantime.
+            return Ok((vec![], vec![]));
+        }
+
+        // Add share account by these operations:
+        // mod share_meta add account
+        // add (account, share_id) -> share_account_meta
+        let id_key = ShareId { share_id };
+        let mut condition = vec![
+            txn_cond_seq(name_key, Eq, share_id_seq),
+            txn_cond_seq(&id_key, Eq, share_meta_seq),
+        ];
+        let mut if_then = vec![];
+
+        for share_account_key in add_share_account_keys.iter() {
+            condition.push(txn_cond_seq(share_account_key, Eq, 0));
+
+            let share_account_meta =
+                ShareAccountMeta::new(share_account_key.account.clone(), share_id, share_on);
+
+            if_then.push(txn_op_put(
+                share_account_key,
+                serialize_struct(&share_account_meta)?,
+            )); /* (account, share_id) -> share_account_meta */
+
+            share_meta.add_account(share_account_key.account.clone());
+        }
+        share_meta.update_on = Some(share_on);
+        for share_account_key in update_share_account_keys.iter() {
+            let (account_seq, share_account_meta): (_, Option<ShareAccountMeta>) =
+                get_struct_value(kv_api, share_account_key).await?;
+            condition.push(txn_cond_seq(share_account_key, Eq, account_seq));
+
+            // A missing account meta is recreated.
+            let share_account_meta = match share_account_meta {
+                Some(mut share_account_meta) => {
+                    share_account_meta.share_on = share_on;
+                    share_account_meta
+                }
+                None => {
+                    ShareAccountMeta::new(share_account_key.account.clone(), share_id, share_on)
+                }
+            };
+
+            if_then.push(txn_op_put(
+                share_account_key,
+                serialize_struct(&share_account_meta)?,
+            )); /* (account, share_id) -> share_account_meta */
+        }
+        if_then.push(txn_op_put(&id_key, serialize_struct(&share_meta)?)); /* (share_id) -> share_meta */
+
+        let txn_req = TxnRequest {
+            condition,
+            if_then,
+            else_then: vec![],
+        };
+
+        let (succ, conflict) = send_txn_with_conflict(kv_api, txn_req).await?;
+
+        debug!(
+            name = debug(&name_key),
+            id = debug(&id_key),
+            succ = display(succ),
+            "add_share_tenants"
+        );
+
+        if succ {
+            return Ok((
+                add_share_account_keys
+                    .into_iter()
+                    .map(|key| key.account)
+                    .collect(),
+                update_share_account_keys
+                    .into_iter()
+                    .map(|key| key.account)
+                    .collect(),
+            ));
+        }
+        last_conflict = conflict;
+    }
+
+    Err(MetaError::AppError(AppError::TxnRetryMaxTimes(
+        TxnRetryMaxTimes::new("add_share_tenants", TXN_MAX_RETRY_TIMES)
+            .with_last_conflict(last_conflict),
+    )))
+}
+
+/// Returns the outbound share account reply of the share, with the share meta it is built from.
 async fn get_outbound_shared_accounts_by_name(
     kv_api: &(impl KVApi + ?Sized),
     share_name: &ShareNameIdent,
-) -> Result<ShareAccountReply, MetaError> {
+) -> Result<(ShareAccountReply, ShareMeta), MetaError> {
     let res = get_share_or_err(
         kv_api,
         share_name,
@@ -939,118 +2322,463 @@ async fn get_outbound_shared_accounts_by_name(
 
     let database_name = get_share_database_name(kv_api, &share_meta, share_name).await?;
 
-    Ok(ShareAccountReply {
+    let reply = ShareAccountReply {
         share_name: share_name.clone(),
         database_name,
         create_on: share_meta.share_on,
         accounts: Some(accounts),
         comment: share_meta.comment.clone(),
-    })
+        last_accessed_on: share_meta.last_accessed_on,
+    };
+
+    Ok((reply, share_meta))
+}
+
+/// Returns the share account metas of the accounts, with all the keys fetched in a single `mget_kv`.
+///
+/// An account whose meta does not exist is left out.
+async fn get_share_account_metas(
+    kv_api: &(impl KVApi + ?Sized),
+    share_id: u64,
+    accounts: &[String],
+) -> Result<Vec<ShareAccountMeta>, MetaError> {
+    let keys = accounts
+        .iter()
+        .map(|account| {
+            ShareAccountNameIdent {
+                account: account.clone(),
+                share_id,
+            }
+            .to_key()
+        })
+        .collect::<Vec<_>>();
+
+    let mut account_metas = Vec::with_capacity(keys.len());
+    for seq_v in kv_api.mget_kv(&keys).await?.into_iter().flatten() {
+        account_metas.push(deserialize_struct(&seq_v.data)?);
+    }
+
+    Ok(account_metas)
+}
+
+/// Returns the page of `items` selected by `offset` and `limit`,
+/// and whether there are more items after this page.
+fn page_of<T>(items: Vec<T>, offset: usize, limit: Option<usize>) -> (Vec<T>, bool) {
+    let remain = items.len().saturating_sub(offset);
+    let take = limit.map_or(remain, |limit| limit.min(remain));
+    let has_more = take < remain;
+    let page = items.into_iter().skip(offset).take(take).collect();
+
+    (page, has_more)
 }
 
+/// Returns (outbound share accounts of the page, next offset, has_more)
+///
+/// The shares skipped because they can not be resolved count toward the next offset.
 async fn get_outbound_shared_accounts_by_tenant(
     kv_api: &(impl KVApi + ?Sized),
     tenant: &str,
-) -> Result<Vec<ShareAccountReply>, MetaError> {
+    offset: usize,
+    limit: Option<usize>,
+) -> Result<(Vec<ShareAccountReply>, usize, bool), MetaError> {
     let mut outbound_share_accounts: Vec<ShareAccountReply> = vec![];
 
+    let share_name_keys = list_share_names(kv_api, tenant).await?;
+    let (share_name_keys, has_more) = page_of(share_name_keys, offset, limit);
+    let next_offset = offset + share_name_keys.len();
+
+    for share_name in share_name_keys {
+        // A share that can not be resolved is skipped instead of failing the others.
+        match get_outbound_shared_accounts_by_name(kv_api, &share_name).await {
+            Ok((reply, _share_meta)) => outbound_share_accounts.push(reply),
+            Err(e) => warn!("skip the outbound share {}: {}", share_name, e),
+        }
+    }
+
+    Ok((outbound_share_accounts, next_offset, has_more))
+}
+
+/// Returns the names of all the shares of the tenant, sorted by share name.
+async fn list_share_names(
+    kv_api: &(impl KVApi + ?Sized),
+    tenant: &str,
+) -> Result<Vec<ShareNameIdent>, MetaError> {
     let tenant_share_name_key = ShareNameIdent {
         tenant: tenant.to_string(),
         share_name: "".to_string(),
     };
-    let share_name_keys = list_keys(kv_api, &tenant_share_name_key).await?;
+    let mut share_name_keys = vec![];
+    let mut pager = list_keys_paged(kv_api, &tenant_share_name_key, LIST_SHARE_KEYS_PAGE_SIZE);
+    while let Some(page) = pager.next_page().await? {
+        share_name_keys.extend(page);
+    }
+    // Keep the page order stable by sorting with share name.
+    share_name_keys.sort_by(|a, b| a.share_name.cmp(&b.share_name));
 
-    for share_name in share_name_keys {
-        let reply = get_outbound_shared_accounts_by_name(kv_api, &share_name).await;
-        if let Ok(reply) = reply {
-            outbound_share_accounts.push(reply)
+    Ok(share_name_keys)
+}
+
+/// Returns true if `provider` shares to `consumer`, directly or through the shares of other tenants.
+async fn tenant_shares_to(
+    kv_api: &(impl KVApi + ?Sized),
+    provider: &str,
+    consumer: &str,
+) -> Result<bool, MetaError> {
+    let mut visited = HashSet::new();
+    let mut providers = vec![provider.to_string()];
+
+    while let Some(provider) = providers.pop() {
+        if !visited.insert(provider.clone()) {
+            continue;
+        }
+
+        let tenant_share_name_key = ShareNameIdent {
+            tenant: provider,
+            share_name: "".to_string(),
+        };
+        for share_name in list_keys(kv_api, &tenant_share_name_key).await? {
+            let (share_id_seq, share_id) = get_u64_value(kv_api, &share_name).await?;
+            if share_id_seq == 0 {
+                continue;
+            }
+            let (_share_meta_seq, share_meta): (_, Option<ShareMeta>) =
+                get_struct_value(kv_api, &ShareId { share_id }).await?;
+            let share_meta = match share_meta {
+                Some(share_meta) => share_meta,
+                None => continue,
+            };
+
+            for account in share_meta.accounts {
+                if account == consumer {
+                    return Ok(true);
+                }
+                providers.push(account);
+            }
         }
     }
 
-    Ok(outbound_share_accounts)
+    Ok(false)
 }
 
+/// Returns (inbound share accounts of the page, next offset, has_more)
+///
+/// A share whose name can not be resolved is left out of the ordering. The other shares skipped
+/// because they can not be resolved count toward the next offset.
 async fn get_inbound_shared_accounts_by_tenant(
     kv_api: &(impl KVApi + ?Sized),
     tenant: &String,
-) -> Result<Vec<ShareAccountReply>, MetaError> {
+    offset: usize,
+    limit: Option<usize>,
+) -> Result<(Vec<ShareAccountReply>, usize, bool), MetaError> {
     let mut inbound_share_accounts: Vec<ShareAccountReply> = vec![];
 
     let tenant_share_name_key = ShareAccountNameIdent {
         account: tenant.clone(),
         share_id: 0,
     };
-    let share_accounts = list_keys(kv_api, &tenant_share_name_key).await?;
-    for share_account in share_accounts {
-        let share_id = share_account.share_id;
-        let (_share_meta_seq, share_meta) = get_share_meta_by_id_or_err(
-            kv_api,
-            share_id,
-            format!("get_inbound_shared_accounts_by_tenant: {}", share_id),
-        )
-        .await?;
 
-        let (_seq, share_name) = get_share_id_to_name_or_err(
-            kv_api,
-            share_id,
-            format!("get_inbound_shared_accounts_by_tenant: {}", share_id),
-        )
-        .await?;
-        let database_name = get_share_database_name(kv_api, &share_meta, &share_name).await?;
+    // The keys are ordered by share id, resolve the share names first to keep the page order stable.
+    // Same as the outbound shares, a share that can not be resolved is skipped instead of failing
+    // the others.
+    let mut share_ids_and_names = vec![];
+    let mut pager = list_keys_paged(kv_api, &tenant_share_name_key, LIST_SHARE_KEYS_PAGE_SIZE);
+    while let Some(share_accounts) = pager.next_page().await? {
+        for share_account in share_accounts {
+            let share_id = share_account.share_id;
+            let res = get_share_id_to_name_or_err(
+                kv_api,
+                share_id,
+                format!("get_inbound_shared_accounts_by_tenant: {}", share_id),
+            )
+            .await;
+            match res {
+                Ok((_seq, share_name)) => share_ids_and_names.push((share_id, share_name)),
+                Err(e) => warn!("skip the inbound share {}: {}", share_id, e),
+            }
+        }
+    }
+    share_ids_and_names.sort_by(|a, b| {
+        (&a.1.share_name, &a.1.tenant, a.0).cmp(&(&b.1.share_name, &b.1.tenant, b.0))
+    });
+    let (share_ids_and_names, has_more) = page_of(share_ids_and_names, offset, limit);
+    let next_offset = offset + share_ids_and_names.len();
+
+    for (share_id, share_name) in share_ids_and_names {
+        match get_inbound_share_account(kv_api, tenant, share_id, share_name).await {
+            Ok(reply) => inbound_share_accounts.push(reply),
+            Err(e) => warn!("skip the inbound share {}: {}", share_id, e),
+        }
+    }
+    Ok((inbound_share_accounts, next_offset, has_more))
+}
 
-        let share_account_key = ShareAccountNameIdent {
-            account: tenant.clone(),
-            share_id,
-        };
-        let (_seq, meta) = get_share_account_meta_or_err(
-            kv_api,
-            &share_account_key,
-            format!(
-                "get_inbound_shared_accounts_by_tenant's account: {}/{}",
-                share_id, tenant
-            ),
-        )
-        .await?;
+/// Same as `get_inbound_shared_accounts_by_tenant` without paging, but each inbound share account
+/// is yielded as soon as it is resolved, in the order of the share ids.
+///
+/// A share that can not be resolved, e.g. dropped while the stream is running, is skipped.
+pub fn get_inbound_shared_accounts_stream<'a, KV: KVApi + ?Sized>(
+    kv_api: &'a KV,
+    tenant: &'a String,
+) -> impl Stream<Item = Result<ShareAccountReply, MetaError>> + 'a {
+    let tenant_share_name_key = ShareAccountNameIdent {
+        account: tenant.clone(),
+        share_id: 0,
+    };
+    let pager = list_keys_paged(kv_api, &tenant_share_name_key, LIST_SHARE_KEYS_PAGE_SIZE);
+
+    stream::try_unfold(
+        (pager, VecDeque::new()),
+        move |(mut pager, mut share_accounts)| async move {
+            loop {
+                if share_accounts.is_empty() {
+                    match pager.next_page().await? {
+                        Some(page) => share_accounts.extend(page),
+                        None => return Ok(None),
+                    }
+                }
+                let share_id = match share_accounts.pop_front() {
+                    Some(share_account) => share_account.share_id,
+                    None => continue,
+                };
 
-        inbound_share_accounts.push(ShareAccountReply {
-            share_name,
-            database_name,
-            create_on: meta.share_on,
-            accounts: None,
-            comment: share_meta.comment.clone(),
-        });
-    }
-    Ok(inbound_share_accounts)
+                let res = get_share_id_to_name_or_err(
+                    kv_api,
+                    share_id,
+                    format!("get_inbound_shared_accounts_stream: {}", share_id),
+                )
+                .await;
+                let share_name = match res {
+                    Ok((_seq, share_name)) => share_name,
+                    Err(e) => {
+                        warn!("skip the inbound share {}: {}", share_id, e);
+                        continue;
+                    }
+                };
+
+                match get_inbound_share_account(kv_api, tenant, share_id, share_name).await {
+                    Ok(reply) => return Ok(Some((reply, (pager, share_accounts)))),
+                    Err(e) => warn!("skip the inbound share {}: {}", share_id, e),
+                }
+            }
+        },
+    )
+}
+
+/// Resolves the inbound share account of `tenant` to the share `share_id`.
+async fn get_inbound_share_account(
+    kv_api: &(impl KVApi + ?Sized),
+    tenant: &String,
+    share_id: u64,
+    share_name: ShareNameIdent,
+) -> Result<ShareAccountReply, MetaError> {
+    let (_share_meta_seq, share_meta) = get_share_meta_by_id_or_err(
+        kv_api,
+        share_id,
+        format!("get_inbound_shared_accounts_by_tenant: {}", share_id),
+    )
+    .await?;
+
+    let database_name = get_share_database_name(kv_api, &share_meta, &share_name).await?;
+
+    let share_account_key = ShareAccountNameIdent {
+        account: tenant.clone(),
+        share_id,
+    };
+    let (_seq, meta) = get_share_account_meta_or_err(
+        kv_api,
+        &share_account_key,
+        format!(
+            "get_inbound_shared_accounts_by_tenant's account: {}/{}",
+            share_id, tenant
+        ),
+    )
+    .await?;
+
+    Ok(ShareAccountReply {
+        share_name,
+        database_name,
+        create_on: meta.share_on,
+        accounts: None,
+        comment: share_meta.comment,
+        last_accessed_on: share_meta.last_accessed_on,
+    })
 }
 
-async fn get_object_name_from_id(
+pub(crate) async fn get_object_name_from_id(
     kv_api: &(impl KVApi + ?Sized),
     database_name: &Option<&String>,
     object: ShareGrantObject,
 ) -> Result<Option<ShareGrantObjectName>, MetaError> {
-    match object {
-        ShareGrantObject::Database(db_id) => {
-            let db_id_key = DatabaseIdToName { db_id };
-            let (_db_name_seq, db_name): (_, Option<DatabaseNameIdent>) =
-                get_struct_value(kv_api, &db_id_key).await?;
-            match db_name {
-                Some(db_name) => Ok(Some(ShareGrantObjectName::Database(db_name.db_name))),
-                None => Ok(None),
+    let mut names = resolve_object_names(kv_api, database_name, &[object]).await?;
+    Ok(names.pop().flatten())
+}
+
+/// Resolves the names of the objects, None for the objects which are dropped,
+/// with all the keys fetched in a single `mget_kv`.
+pub(crate) async fn resolve_object_names(
+    kv_api: &(impl KVApi + ?Sized),
+    database_name: &Option<&String>,
+    objects: &[ShareGrantObject],
+) -> Result<Vec<Option<ShareGrantObjectName>>, MetaError> {
+    // A table is resolved by its meta, to tell whether it is dropped, and then its name.
+    let mut keys = Vec::with_capacity(objects.len() * 2);
+    for object in objects {
+        match object {
+            ShareGrantObject::Database(db_id) => {
+                keys.push(DatabaseIdToName { db_id: *db_id }.to_key());
+            }
+            ShareGrantObject::Table(table_id) => {
+                keys.push(
+                    TableId {
+                        table_id: *table_id,
+                    }
+                    .to_key(),
+                );
+                keys.push(
+                    TableIdToName {
+                        table_id: *table_id,
+                    }
+                    .to_key(),
+                );
             }
         }
-        ShareGrantObject::Table(table_id) => {
-            let table_id_key = TableIdToName { table_id };
-            let (_db_id_table_name_seq, table_name): (_, Option<DBIdTableName>) =
-                get_struct_value(kv_api, &table_id_key).await?;
-            match table_name {
-                Some(table_name) => Ok(Some(ShareGrantObjectName::Table(
-                    database_name.as_ref().unwrap().to_string(),
-                    table_name.table_name,
-                ))),
-                None => Ok(None),
+    }
+
+    let mut values = kv_api.mget_kv(&keys).await?.into_iter();
+    let mut next_value = || values.next().flatten();
+
+    let mut names = Vec::with_capacity(objects.len());
+    for object in objects {
+        let name = match object {
+            ShareGrantObject::Database(_db_id) => match next_value() {
+                Some(seq_v) => {
+                    let db_name: DatabaseNameIdent = deserialize_struct(&seq_v.data)?;
+                    Some(ShareGrantObjectName::Database(db_name.db_name))
+                }
+                None => None,
+            },
+            ShareGrantObject::Table(_table_id) => {
+                let tb_meta: Option<TableMeta> = match next_value() {
+                    Some(seq_v) => Some(deserialize_struct(&seq_v.data)?),
+                    None => None,
+                };
+                let table_name: Option<DBIdTableName> = match next_value() {
+                    Some(seq_v) => Some(deserialize_struct(&seq_v.data)?),
+                    None => None,
+                };
+                match (tb_meta, table_name) {
+                    // A dropped table keeps its id to name mapping until it is vacuumed.
+                    (Some(tb_meta), Some(table_name)) if tb_meta.drop_on.is_none() => {
+                        Some(ShareGrantObjectName::Table(
+                            table_database_name(database_name, object)?,
+                            table_name.table_name,
+                        ))
+                    }
+                    _ => None,
+                }
+            }
+        };
+        names.push(name);
+    }
+
+    Ok(names)
+}
+
+/// Returns the last known names of the dropped objects, or their ids if the names have been
+/// vacuumed too, with all the keys fetched in a single `mget_kv`.
+async fn get_dropped_object_names(
+    kv_api: &(impl KVApi + ?Sized),
+    database_name: &Option<&String>,
+    objects: &[ShareGrantObject],
+) -> Result<Vec<ShareGrantObjectName>, MetaError> {
+    let keys = objects
+        .iter()
+        .map(|object| match object {
+            ShareGrantObject::Database(db_id) => DatabaseIdToName { db_id: *db_id }.to_key(),
+            ShareGrantObject::Table(table_id) => TableIdToName {
+                table_id: *table_id,
+            }
+            .to_key(),
+        })
+        .collect::<Vec<_>>();
+    let values = kv_api.mget_kv(&keys).await?;
+
+    let mut names = Vec::with_capacity(objects.len());
+    for (object, value) in objects.iter().zip(values) {
+        let name = match object {
+            ShareGrantObject::Database(_db_id) => {
+                let db_name: Option<DatabaseNameIdent> = match value {
+                    Some(seq_v) => Some(deserialize_struct(&seq_v.data)?),
+                    None => None,
+                };
+                ShareGrantObjectName::Database(
+                    db_name.map_or_else(|| object.to_string(), |db_name| db_name.db_name),
+                )
+            }
+            ShareGrantObject::Table(_table_id) => {
+                let table_name: Option<DBIdTableName> = match value {
+                    Some(seq_v) => Some(deserialize_struct(&seq_v.data)?),
+                    None => None,
+                };
+                ShareGrantObjectName::Table(
+                    table_database_name(database_name, object)?,
+                    table_name
+                        .map_or_else(|| object.to_string(), |table_name| table_name.table_name),
+                )
+            }
+        };
+        names.push(name);
+    }
+
+    Ok(names)
+}
+
+/// Returns the name of the shared database, which the granted table belongs to.
+fn table_database_name(
+    database_name: &Option<&String>,
+    object: &ShareGrantObject,
+) -> Result<String, MetaError> {
+    match database_name {
+        Some(database_name) => Ok(database_name.to_string()),
+        None => Err(MetaError::AppError(AppError::ShareDatabaseNotGranted(
+            ShareDatabaseNotGranted::new(object.to_string()),
+        ))),
+    }
+}
+
+/// Returns the granted tables that do not belong to the granted database of the share.
+///
+/// A table whose id to name mapping is gone is left to the check of unresolved objects.
+async fn tables_outside_shared_database(
+    kv_api: &(impl KVApi + ?Sized),
+    share_meta: &ShareMeta,
+) -> Result<Vec<ShareInconsistency>, MetaError> {
+    let shared_db_id = match &share_meta.database {
+        Some(entry) => match entry.object {
+            ShareGrantObject::Database(db_id) => Some(db_id),
+            ShareGrantObject::Table(_) => None,
+        },
+        None => None,
+    };
+
+    let mut inconsistencies = vec![];
+    for entry in share_meta.entries.values() {
+        let table_id = match entry.object {
+            ShareGrantObject::Table(table_id) => table_id,
+            ShareGrantObject::Database(_) => continue,
+        };
+        let (_seq, table_name): (_, Option<DBIdTableName>) =
+            get_struct_value(kv_api, &TableIdToName { table_id }).await?;
+        if let Some(table_name) = table_name {
+            if Some(table_name.db_id) != shared_db_id {
+                inconsistencies.push(ShareInconsistency::TableOutsideSharedDatabase {
+                    object: entry.object.clone(),
+                    db_id: table_name.db_id,
+                });
             }
         }
     }
+    Ok(inconsistencies)
 }
 
 fn check_share_object(
@@ -1065,8 +2793,8 @@ fn check_share_object(
                 ShareGrantObjectSeqAndId::Table(db_id, _seq, _id) => *db_id,
             };
             if db_id != object_db_id {
-                return Err(MetaError::AppError(AppError::WrongShareObject(
-                    WrongShareObject::new(obj_name.to_string()),
+                return Err(MetaError::AppError(AppError::ShareObjectDatabaseMismatch(
+                    ShareObjectDatabaseMismatch::new(obj_name.to_string()),
                 )));
             }
         } else {
@@ -1075,8 +2803,8 @@ fn check_share_object(
     } else {
         // Table cannot be granted without database has been granted.
         if let ShareGrantObjectSeqAndId::Table(_, _, _) = seq_and_id {
-            return Err(MetaError::AppError(AppError::WrongShareObject(
-                WrongShareObject::new(obj_name.to_string()),
+            return Err(MetaError::AppError(AppError::ShareDatabaseNotGranted(
+                ShareDatabaseNotGranted::new(obj_name.to_string()),
             )));
         }
     }
@@ -1102,6 +2830,7 @@ async fn get_share_object_seq_and_id(
                 format!("get_share_object_seq_and_id: {}", name_key),
             )
             .await?;
+            dropped_object_can_not_be_shared(db_meta.drop_on.is_some(), obj_name)?;
 
             Ok(ShareGrantObjectSeqAndId::Database(
                 db_meta_seq,
@@ -1115,40 +2844,174 @@ async fn get_share_object_seq_and_id(
                 tenant: tenant.to_string(),
                 db_name: db_name.clone(),
             };
-            let (db_seq, db_id) = get_u64_value(kv_api, &db_name_key).await?;
-            db_has_to_exist(
-                db_seq,
+            let (_db_id_seq, db_id, _db_meta_seq, db_meta) = get_db_or_err(
+                kv_api,
                 &db_name_key,
                 format!("get_share_object_seq_and_id: {}", db_name_key),
-            )?;
+            )
+            .await?;
+            dropped_object_can_not_be_shared(db_meta.drop_on.is_some(), obj_name)?;
 
-            let name_key = DBIdTableName {
-                db_id,
-                table_name: table_name.clone(),
-            };
+            get_share_table_seq_and_id(kv_api, &db_name_key, db_id, table_name, obj_name).await
+        }
+    }
+}
 
-            let (table_seq, table_id) = get_u64_value(kv_api, &name_key).await?;
-            table_has_to_exist(
-                table_seq,
-                &TableNameIdent {
-                    tenant: tenant.to_string(),
-                    db_name: db_name.clone(),
-                    table_name: table_name.clone(),
-                },
-                format!("get_share_object_seq_and_id: {}", name_key),
-            )?;
+/// Resolves a table of the database `db_id` that `db_name_key` has been resolved to.
+///
+/// The database may be dropped after it is resolved, a missing table is then reported as
+/// a missing database rather than a missing table.
+pub(crate) async fn get_share_table_seq_and_id(
+    kv_api: &(impl KVApi + ?Sized),
+    db_name_key: &DatabaseNameIdent,
+    db_id: u64,
+    table_name: &str,
+    obj_name: &ShareGrantObjectName,
+) -> Result<ShareGrantObjectSeqAndId, MetaError> {
+    let name_key = DBIdTableName {
+        db_id,
+        table_name: table_name.to_string(),
+    };
 
-            let tbid = TableId { table_id };
-            let (table_meta_seq, _tb_meta): (_, Option<TableMeta>) =
-                get_struct_value(kv_api, &tbid).await?;
+    let (table_seq, table_id) = get_u64_value(kv_api, &name_key).await?;
+    if table_seq == 0 {
+        db_has_to_be_resolved_to(
+            kv_api,
+            db_name_key,
+            db_id,
+            format!("get_share_object_seq_and_id: {}", name_key),
+        )
+        .await?;
+    }
+    table_has_to_exist(
+        table_seq,
+        &TableNameIdent {
+            tenant: db_name_key.tenant.clone(),
+            db_name: db_name_key.db_name.clone(),
+            table_name: table_name.to_string(),
+        },
+        format!("get_share_object_seq_and_id: {}", name_key),
+    )?;
+
+    let tbid = TableId { table_id };
+    let (table_meta_seq, tb_meta): (_, Option<TableMeta>) = get_struct_value(kv_api, &tbid).await?;
+    if let Some(tb_meta) = &tb_meta {
+        dropped_object_can_not_be_shared(tb_meta.drop_on.is_some(), obj_name)?;
+
+        // A view has no data of its own, it is not shared as a table.
+        if tb_meta.engine == VIEW_ENGINE {
+            return Err(MetaError::AppError(AppError::CannotShareView(
+                CannotShareView::new(obj_name.to_string()),
+            )));
+        }
+    }
 
-            Ok(ShareGrantObjectSeqAndId::Table(
-                db_id,
-                table_meta_seq,
-                table_id,
-            ))
+    Ok(ShareGrantObjectSeqAndId::Table(
+        db_id,
+        table_meta_seq,
+        table_id,
+    ))
+}
+
+/// Returns UnknownDatabase if the database name no longer refers to the undropped database `db_id`.
+async fn db_has_to_be_resolved_to(
+    kv_api: &(impl KVApi + ?Sized),
+    db_name_key: &DatabaseNameIdent,
+    db_id: u64,
+    msg: impl Display,
+) -> Result<(), MetaError> {
+    let (db_id_seq, current_db_id) = get_u64_value(kv_api, db_name_key).await?;
+    let (_db_meta_seq, db_meta): (_, Option<DatabaseMeta>) =
+        get_struct_value(kv_api, &DatabaseId { db_id }).await?;
+
+    let dropped = db_meta.map_or(true, |db_meta| db_meta.drop_on.is_some());
+    if db_id_seq == 0 || current_db_id != db_id || dropped {
+        return Err(MetaError::AppError(AppError::UnknownDatabase(
+            UnknownDatabase::new(
+                &db_name_key.db_name,
+                format!("database dropped while resolving the table, {}", msg),
+            ),
+        )));
+    }
+    Ok(())
+}
+
+/// A dropped object is only retained until it is vacuumed, it can not be granted to a share.
+fn dropped_object_can_not_be_shared(
+    dropped: bool,
+    obj_name: &ShareGrantObjectName,
+) -> Result<(), MetaError> {
+    if dropped {
+        return Err(MetaError::AppError(AppError::CannotShareDroppedObject(
+            CannotShareDroppedObject::new(obj_name.to_string()),
+        )));
+    }
+    Ok(())
+}
+
+/// The objects of the protected databases can not be shared.
+fn check_share_protected_database(
+    obj_name: &ShareGrantObjectName,
+    protected_databases: &[String],
+) -> Result<(), MetaError> {
+    let db_name = match obj_name {
+        ShareGrantObjectName::Database(db_name) => db_name,
+        ShareGrantObjectName::Table(db_name, _table_name) => db_name,
+    };
+    if protected_databases.contains(db_name) {
+        return Err(MetaError::AppError(AppError::CannotShareProtectedObject(
+            CannotShareProtectedObject::new(obj_name.to_string(), db_name),
+        )));
+    }
+    Ok(())
+}
+
+/// `privilege` has to be `All` or one of the privileges valid for the kind of the object.
+fn check_share_privilege(
+    object: &ShareGrantObject,
+    privilege: GrantSharePrivilege,
+    obj_name: &ShareGrantObjectName,
+) -> Result<(), MetaError> {
+    if let GrantSharePrivilege::Privilege(privilege) = privilege {
+        if !valid_privileges_for(object.kind()).contains(privilege) {
+            return Err(MetaError::AppError(AppError::InvalidSharePrivilege(
+                InvalidSharePrivilege::new(privilege.to_string(), obj_name.to_string()),
+            )));
         }
     }
+    Ok(())
+}
+
+/// Adds `share_id` into the share_ids of the object.
+async fn add_shared_by_share_txn(
+    kv_api: &(impl KVApi + ?Sized),
+    share_id: u64,
+    object: &ShareGrantObject,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+) -> MetaResult<()> {
+    let (share_ids_seq, mut share_ids) = get_object_shared_by_share_ids(kv_api, object).await?;
+    share_ids.add(share_id);
+
+    condition.push(txn_cond_seq(object, Eq, share_ids_seq));
+    if_then.push(txn_op_put(object, serialize_struct(&share_ids)?)); /* (object) -> share_ids */
+    Ok(())
+}
+
+/// Removes `share_id` from the share_ids of the object.
+async fn remove_shared_by_share_txn(
+    kv_api: &(impl KVApi + ?Sized),
+    share_id: u64,
+    object: &ShareGrantObject,
+    condition: &mut Vec<TxnCondition>,
+    if_then: &mut Vec<TxnOp>,
+) -> MetaResult<()> {
+    let (share_ids_seq, mut share_ids) = get_object_shared_by_share_ids(kv_api, object).await?;
+    share_ids.remove(share_id);
+
+    condition.push(txn_cond_seq(object, Eq, share_ids_seq));
+    if_then.push(txn_op_put(object, serialize_struct(&share_ids)?)); /* (object) -> share_ids */
+    Ok(())
 }
 
 fn add_txn_condition(seq_and_id: &ShareGrantObjectSeqAndId, condition: &mut Vec<TxnCondition>) {
@@ -1167,15 +3030,16 @@ fn add_txn_condition(seq_and_id: &ShareGrantObjectSeqAndId, condition: &mut Vec<
 }
 
 fn add_grant_object_txn_if_then(
-    share_id: u64,
+    share_ids: &[u64],
     seq_and_id: ShareGrantObjectSeqAndId,
     if_then: &mut Vec<TxnOp>,
 ) -> MetaResult<()> {
     match seq_and_id {
         ShareGrantObjectSeqAndId::Database(_db_meta_seq, db_id, mut db_meta) => {
-            // modify db_meta add share_id into shared_by
-            if !db_meta.shared_by.contains(&share_id) {
-                db_meta.shared_by.insert(share_id);
+            // modify db_meta add share_ids into shared_by
+            let len = db_meta.shared_by.len();
+            db_meta.shared_by.extend(share_ids);
+            if db_meta.shared_by.len() != len {
                 let key = DatabaseId { db_id };
                 if_then.push(txn_op_put(&key, serialize_struct(&db_meta)?));
             }
@@ -1268,6 +3132,28 @@ fn share_has_to_exist(
     }
 }
 
+/// Return OK if the share is not frozen.
+///
+/// Otherwise returns ShareFrozen error
+fn share_has_to_be_unfrozen(
+    share_meta: &ShareMeta,
+    share_name_ident: &ShareNameIdent,
+    msg: impl Display,
+) -> Result<(), MetaError> {
+    if share_meta.frozen {
+        debug!(?share_name_ident, "share is frozen");
+
+        Err(MetaError::AppError(AppError::ShareFrozen(
+            ShareFrozen::new(
+                &share_name_ident.share_name,
+                format!("{}: {}", msg, share_name_ident),
+            ),
+        )))
+    } else {
+        Ok(())
+    }
+}
+
 /// Returns (share_account_meta_seq, share_account_meta)
 pub(crate) async fn get_share_account_meta_or_err(
     kv_api: &(impl KVApi + ?Sized),
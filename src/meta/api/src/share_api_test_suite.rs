@@ -12,26 +12,135 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::TimeZone;
 use common_datavalues::chrono::Utc;
 use common_exception::ErrorCode;
 use common_meta_app::schema::CreateDatabaseReq;
 use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::DatabaseId;
 use common_meta_app::schema::DatabaseMeta;
 use common_meta_app::schema::DatabaseNameIdent;
+use common_meta_app::schema::DropDatabaseReq;
+use common_meta_app::schema::DropTableReq;
+use common_meta_app::schema::GetDatabaseReq;
+use common_meta_app::schema::RenameDatabaseReq;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableNameIdent;
 use common_meta_app::share::*;
+use common_meta_types::GetKVReply;
+use common_meta_types::ListKVReply;
+use common_meta_types::MGetKVReply;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::TxnReply;
+use common_meta_types::TxnRequest;
+use common_meta_types::UpsertKVReply;
+use common_meta_types::UpsertKVReq;
 use enumflags2::BitFlags;
+use futures::TryStreamExt;
 use tracing::info;
 
+use crate::get_inbound_shared_accounts_stream;
+use crate::get_object_name_from_id;
 use crate::get_share_account_meta_or_err;
 use crate::get_share_id_to_name_or_err;
 use crate::get_share_meta_by_id_or_err;
+use crate::get_share_table_seq_and_id;
+use crate::get_struct_value;
+use crate::resolve_object_names;
+use crate::serialize_struct;
 use crate::ApiBuilder;
 use crate::AsKVApi;
+use crate::KVApi;
+use crate::KVApiKey;
 use crate::SchemaApi;
 use crate::ShareApi;
 
+/// A KVApi that rewrites `key` before each transaction,
+/// so that every transaction conditioned on the seq of `key` fails.
+struct ContendedKVApi<'a> {
+    inner: &'a dyn KVApi,
+    key: String,
+}
+
+#[async_trait::async_trait]
+impl KVApi for ContendedKVApi<'_> {
+    async fn upsert_kv(&self, req: UpsertKVReq) -> Result<UpsertKVReply, MetaError> {
+        self.inner.upsert_kv(req).await
+    }
+
+    async fn get_kv(&self, key: &str) -> Result<GetKVReply, MetaError> {
+        self.inner.get_kv(key).await
+    }
+
+    async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, MetaError> {
+        self.inner.mget_kv(keys).await
+    }
+
+    async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, MetaError> {
+        self.inner.prefix_list_kv(prefix).await
+    }
+
+    async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
+        // Rewrite the same value, only the seq is bumped.
+        if let Some(seq_v) = self.inner.get_kv(&self.key).await? {
+            self.inner
+                .upsert_kv(UpsertKVReq::new(
+                    &self.key,
+                    MatchSeq::Any,
+                    Operation::Update(seq_v.data),
+                    None,
+                ))
+                .await?;
+        }
+        self.inner.transaction(txn).await
+    }
+}
+
+/// A KVApi that counts the reads sent to `inner`.
+struct CountingKVApi<'a> {
+    inner: &'a dyn KVApi,
+    reads: AtomicU64,
+}
+
+impl CountingKVApi<'_> {
+    fn reads(&self) -> u64 {
+        self.reads.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait::async_trait]
+impl KVApi for CountingKVApi<'_> {
+    async fn upsert_kv(&self, req: UpsertKVReq) -> Result<UpsertKVReply, MetaError> {
+        self.inner.upsert_kv(req).await
+    }
+
+    async fn get_kv(&self, key: &str) -> Result<GetKVReply, MetaError> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        self.inner.get_kv(key).await
+    }
+
+    async fn mget_kv(&self, keys: &[String]) -> Result<MGetKVReply, MetaError> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        self.inner.mget_kv(keys).await
+    }
+
+    async fn prefix_list_kv(&self, prefix: &str) -> Result<ListKVReply, MetaError> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        self.inner.prefix_list_kv(prefix).await
+    }
+
+    async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
+        self.inner.transaction(txn).await
+    }
+}
+
 /// Test suite of `ShareApi`.
 ///
 /// It is not used by this crate, but is used by other crate that impl `ShareApi`,
@@ -50,12 +159,52 @@ impl ShareApiTestSuite {
         let suite = ShareApiTestSuite {};
 
         suite.share_create_show_drop(&b.build().await).await?;
+        suite.share_force_drop(&b.build().await).await?;
+        suite.share_drop_dry_run(&b.build().await).await?;
+        suite.list_orphaned_share_accounts(&b.build().await).await?;
+        suite.show_shares_pagination(&b.build().await).await?;
+        suite
+            .inbound_shared_accounts_stream(&b.build().await)
+            .await?;
         suite.share_add_remove_account(&b.build().await).await?;
+        suite.share_add_account_cycle(&b.build().await).await?;
+        suite.share_add_accounts_in_chunks(&b.build().await).await?;
+        suite
+            .share_add_accounts_update_existing(&b.build().await)
+            .await?;
+        suite.share_accounts_pagination(&b.build().await).await?;
+        suite.share_record_access(&b.build().await).await?;
+        suite.share_freeze(&b.build().await).await?;
+        suite.share_transfer_accounts(&b.build().await).await?;
         suite.share_grant_revoke_object(&b.build().await).await?;
+        suite.share_grant_object_to_shares(&b.build().await).await?;
+        suite.share_revoke_all_object(&b.build().await).await?;
+        suite.share_purge_grants(&b.build().await).await?;
+        suite.share_txn_retry_conflict(&b.build().await).await?;
+        suite.share_grant_dropped_object(&b.build().await).await?;
+        suite
+            .share_grant_table_of_dropped_database(&b.build().await)
+            .await?;
+        suite.share_grant_view(&b.build().await).await?;
+        suite
+            .share_grant_protected_database(&b.build().await)
+            .await?;
+        suite.share_database_rename(&b.build().await).await?;
+        suite
+            .share_grant_invalid_privilege(&b.build().await)
+            .await?;
+        suite.share_clone(&b.build().await).await?;
         suite.get_share_grant_objects(&b.build().await).await?;
+        suite.get_share_object_privilege(&b.build().await).await?;
+        suite.validate_share(&b.build().await).await?;
+        suite.get_share_meta_raw(&b.build().await).await?;
+        suite.list_outbound_shares(&b.build().await).await?;
+        suite.diff_share_grants(&b.build().await).await?;
+        suite.get_share_create_statement(&b.build().await).await?;
         suite
             .get_grant_privileges_of_object(&b.build().await)
             .await?;
+        suite.get_shares_by_object(&b.build().await).await?;
 
         Ok(())
     }
@@ -72,9 +221,7 @@ impl ShareApiTestSuite {
 
         info!("--- show share when there are no share");
         {
-            let req = ShowSharesReq {
-                tenant: tenant.to_string(),
-            };
+            let req = ShowSharesReq::new(tenant);
 
             let res = mt.show_shares(req).await;
             info!("show share res: {:?}", res);
@@ -92,6 +239,7 @@ impl ShareApiTestSuite {
                 share_name: share_name.clone(),
                 comment: None,
                 create_on,
+                request_id: None,
             };
 
             let res = mt.create_share(req).await;
@@ -108,9 +256,7 @@ impl ShareApiTestSuite {
 
         info!("--- show share again");
         {
-            let req = ShowSharesReq {
-                tenant: tenant.to_string(),
-            };
+            let req = ShowSharesReq::new(tenant);
 
             let res = mt.show_shares(req).await;
             info!("show share res: {:?}", res);
@@ -120,440 +266,804 @@ impl ShareApiTestSuite {
             assert_eq!(resp.outbound_accounts.len(), 1);
         }
 
+        info!("--- create share2 twice with the same request id");
+        {
+            let share_name2 = ShareNameIdent {
+                tenant: tenant.to_string(),
+                share_name: "share2".to_string(),
+            };
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name2.clone(),
+                comment: None,
+                create_on,
+                request_id: Some("request1".to_string()),
+            };
+
+            let res = mt.create_share(req.clone()).await?;
+            info!("create share res: {:?}", res);
+            let share_id2 = res.share_id;
+
+            // The retry returns the share of the first attempt.
+            let res = mt.create_share(req.clone()).await?;
+            assert_eq!(share_id2, res.share_id);
+
+            // Another request still finds the share existing.
+            let res = mt
+                .create_share(CreateShareReq {
+                    request_id: Some("request2".to_string()),
+                    ..req
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareAlreadyExists("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let resp = mt.show_shares(ShowSharesReq::new(tenant)).await?;
+            assert_eq!(resp.outbound_accounts.len(), 2);
+        }
+
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
-    async fn share_add_remove_account<MT: ShareApi + AsKVApi>(
-        &self,
-        mt: &MT,
-    ) -> anyhow::Result<()> {
+    async fn share_force_drop<MT: ShareApi + AsKVApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
-        let tenant2 = "tenant2";
         let share1 = "share1";
-        let share2 = "share2";
-        let account = "account1";
-        let account2 = "account2";
         let share_name = ShareNameIdent {
             tenant: tenant.to_string(),
             share_name: share1.to_string(),
         };
-        let share_name2 = ShareNameIdent {
-            tenant: tenant.to_string(),
-            share_name: share2.to_string(),
-        };
-        let share_name3 = ShareNameIdent {
-            tenant: tenant2.to_string(),
-            share_name: share2.to_string(),
-        };
-        let comment1 = "comment1";
-        let comment2 = "comment2";
-        let comment3 = "comment3";
         let share_id: u64;
-        let share_on = Utc::now();
-        let create_on = Utc::now();
-        let if_exists = true;
 
-        info!("--- add and remove account with not exist share");
+        info!("--- create share1");
         {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on: Utc::now(),
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await?;
+            info!("create share res: {:?}", res);
+            share_id = res.share_id;
+
             let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
-                share_on,
+                share_on: Utc::now(),
                 if_exists: false,
-                accounts: vec![account.to_string()],
+                accounts: vec!["account1".to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
             };
+            mt.add_share_tenants(req).await?;
+        }
 
-            // get share meta and check account has been added
-            let res = mt.add_share_tenants(req).await;
-            assert!(res.is_err());
+        // The account meta of share1 is rewritten before each transaction.
+        let account_meta_key = ShareAccountNameIdent {
+            account: "account1".to_string(),
+            share_id,
+        }
+        .to_key();
+        let contended = ContendedKVApi {
+            inner: mt.as_kv_api(),
+            key: account_meta_key.clone(),
+        };
+
+        info!("--- drop share1 retries on the contended account meta until exhaustion");
+        {
+            let req = DropShareReq {
+                if_exists: false,
+                share_name: share_name.clone(),
+                force: false,
+                dry_run: false,
+            };
+
+            let res = contended.drop_share(req).await;
+            info!("drop share res: {:?}", res);
             let err = res.unwrap_err();
             assert_eq!(
-                ErrorCode::UnknownShare("").code(),
+                ErrorCode::TxnRetryMaxTimes("").code(),
                 ErrorCode::from(err).code()
             );
 
-            let req = RemoveShareAccountsReq {
-                share_name: share_name.clone(),
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert_eq!(share_meta.get_accounts(), vec!["account1".to_string()]);
+        }
+
+        info!("--- corrupt share1 by removing (share_id) -> (tenant, share_name)");
+        {
+            let id_name_key = ShareIdToName { share_id };
+            mt.as_kv_api()
+                .upsert_kv(UpsertKVReq::new(
+                    &id_name_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+        }
+
+        info!("--- force drop share1 with the account meta still contended");
+        {
+            let req = DropShareReq {
                 if_exists: false,
-                accounts: vec![account2.to_string()],
+                share_name: share_name.clone(),
+                force: true,
+                dry_run: false,
             };
 
-            let res = mt.remove_share_tenants(req).await;
-            assert!(res.is_err());
+            let res = contended.drop_share(req).await;
+            info!("drop share res: {:?}", res);
+            assert!(res.is_ok());
+            assert!(mt.as_kv_api().get_kv(&account_meta_key).await?.is_none());
+
+            let res = get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await;
             let err = res.unwrap_err();
             assert_eq!(
-                ErrorCode::UnknownShare("").code(),
+                ErrorCode::UnknownShareId("").code(),
                 ErrorCode::from(err).code()
             );
+
+            let req = ShowSharesReq::new(tenant);
+            let resp = mt.show_shares(req).await?;
+            assert!(resp.outbound_accounts.is_empty());
         }
 
-        info!("--- prepare share1 share2 share3");
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_drop_dry_run<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+        let accounts = vec!["account1".to_string(), "account2".to_string()];
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        let share_id: u64;
+
+        info!("--- create share1 with two accounts, db1 and table1 granted");
+        let create_on = Utc::now();
         {
             let req = CreateShareReq {
                 if_not_exists: false,
                 share_name: share_name.clone(),
-                comment: Some(comment1.to_string()),
+                comment: None,
                 create_on,
+                request_id: None,
             };
+            share_id = mt.create_share(req).await?.share_id;
 
-            let res = mt.create_share(req).await;
-            info!("add share account res: {:?}", res);
-            let res = res.unwrap();
-            assert_eq!(1, res.share_id, "first database id is 1");
-            share_id = res.share_id;
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: create_on,
+                if_exists: false,
+                accounts: accounts.clone(),
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
 
-            let req = CreateShareReq {
+            let plan = CreateDatabaseReq {
                 if_not_exists: false,
-                share_name: share_name2.clone(),
-                comment: Some(comment2.to_string()),
-                create_on,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
             };
+            mt.create_database(plan).await?;
 
-            let res = mt.create_share(req).await;
-            info!("add share account res: {:?}", res);
-
-            let req = CreateShareReq {
+            let req = CreateTableReq {
                 if_not_exists: false,
-                share_name: share_name3.clone(),
-                comment: Some(comment3.to_string()),
-                create_on,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
             };
+            mt.create_table(req).await?;
 
-            let res = mt.create_share(req).await;
-            info!("add share account res: {:?}", res);
+            for (object, privilege) in [
+                (
+                    ShareGrantObjectName::Database(db_name.to_string()),
+                    ShareGrantObjectPrivilege::Usage,
+                ),
+                (
+                    ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                    ShareGrantObjectPrivilege::Select,
+                ),
+            ] {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object,
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+            }
         }
 
-        info!("--- add account account1");
-        {
-            let req = AddShareAccountsReq {
-                share_name: share_name.clone(),
-                share_on,
-                if_exists,
-                accounts: vec![account.to_string()],
-            };
-
-            // get share meta and check account has been added
-            let res = mt.add_share_tenants(req).await;
-            info!("add share account res: {:?}", res);
-            assert!(res.is_ok());
+        let expected = DropShareReply {
+            accounts_to_remove: 2,
+            objects_to_revoke: 2,
+        };
 
-            let (_share_meta_seq, share_meta) =
+        info!("--- dry run reports what would be removed and changes nothing");
+        {
+            let (share_meta_seq, share_meta) =
                 get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
-            assert!(share_meta.has_account(&account.to_string()));
-
-            // get and check share account meta
-            let share_account_name = ShareAccountNameIdent {
-                account: account.to_string(),
-                share_id,
-            };
-            let (_share_account_meta_seq, share_account_meta) =
-                get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await?;
-            assert_eq!(share_account_meta.share_id, share_id);
-            assert_eq!(share_account_meta.account, account.to_string());
-            assert_eq!(share_account_meta.share_on, share_on);
 
-            // get_grant_tenants_of_share
-            let req = GetShareGrantTenantsReq {
+            let req = DropShareReq {
+                if_exists: false,
                 share_name: share_name.clone(),
+                force: false,
+                dry_run: true,
             };
-            let resp = mt.get_grant_tenants_of_share(req).await;
-            assert!(resp.is_ok());
-            let resp = resp.unwrap();
-            assert_eq!(resp.accounts.len(), 1);
-            assert_eq!(resp.accounts[0], account.to_string());
+            let res = mt.drop_share(req).await?;
+            assert_eq!(res, expected);
+
+            let (seq, meta) = get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert_eq!((seq, meta), (share_meta_seq, share_meta));
+            for account in accounts.iter() {
+                let share_account_name = ShareAccountNameIdent {
+                    account: account.clone(),
+                    share_id,
+                };
+                let (seq, _meta) =
+                    get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await?;
+                assert!(seq > 0);
+            }
         }
 
-        info!("--- share tenant2.share2 to tenant1");
+        info!("--- drop reports what it removed");
         {
-            let req = AddShareAccountsReq {
-                share_name: share_name3.clone(),
-                share_on,
-                if_exists,
-                accounts: vec![tenant.to_string()],
+            let req = DropShareReq {
+                if_exists: false,
+                share_name: share_name.clone(),
+                force: false,
+                dry_run: false,
             };
+            let res = mt.drop_share(req).await?;
+            assert_eq!(res, expected);
 
-            // get share meta and check account has been added
-            let res = mt.add_share_tenants(req).await;
-            info!("add share account res: {:?}", res);
-            assert!(res.is_ok());
+            let res = get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShareId("").code(),
+                ErrorCode::from(err).code()
+            );
         }
 
-        // test show share api
-        info!("--- show share check account information");
+        info!("--- dry run of a missing share with if_exists reports nothing");
         {
-            let req = ShowSharesReq {
-                tenant: tenant.to_string(),
+            let req = DropShareReq {
+                if_exists: true,
+                share_name: share_name.clone(),
+                force: false,
+                dry_run: true,
             };
+            let res = mt.drop_share(req).await?;
+            assert_eq!(res, DropShareReply::default());
+        }
 
-            let res = mt.show_shares(req).await;
-            info!("show share res: {:?}", res);
-            assert!(res.is_ok());
-            let resp = res.unwrap();
-            assert_eq!(resp.inbound_accounts.len(), 1);
-            assert_eq!(resp.inbound_accounts[0].share_name, share_name3.clone());
-            assert_eq!(resp.inbound_accounts[0].create_on, share_on.clone());
-            assert_eq!(resp.inbound_accounts[0].comment, Some(comment3.to_string()));
+        Ok(())
+    }
 
-            assert_eq!(resp.outbound_accounts.len(), 2);
-            assert_eq!(resp.outbound_accounts[0].share_name, share_name.clone());
-            assert_eq!(resp.outbound_accounts[0].create_on, create_on.clone());
-            assert_eq!(
-                resp.outbound_accounts[0].comment,
-                Some(comment1.to_string())
-            );
-            assert_eq!(resp.outbound_accounts[1].share_name, share_name2.clone());
-            assert_eq!(resp.outbound_accounts[1].create_on, create_on.clone());
-            assert_eq!(
-                resp.outbound_accounts[1].comment,
-                Some(comment2.to_string())
-            );
-            assert!(resp.outbound_accounts[0].accounts.is_some());
-            assert!(resp.outbound_accounts[1].accounts.is_some());
-            let accounts = resp.outbound_accounts[0].accounts.as_ref().unwrap();
-            assert_eq!(accounts.len(), 1);
-            assert_eq!(accounts[0], account.to_string());
-            assert_eq!(
-                resp.outbound_accounts[1].accounts.as_ref().unwrap().len(),
-                0
-            );
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn list_orphaned_share_accounts<MT: ShareApi + AsKVApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let provider = "provider";
+        let consumer = "consumer";
+
+        info!("--- share share1 and share2 to the consumer");
+        let mut share_ids = vec![];
+        {
+            for share in ["share1", "share2"] {
+                let share_name = ShareNameIdent {
+                    tenant: provider.to_string(),
+                    share_name: share.to_string(),
+                };
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on: Utc::now(),
+                    request_id: None,
+                };
+                share_ids.push(mt.create_share(req).await?.share_id);
+
+                let req = AddShareAccountsReq {
+                    share_name,
+                    share_on: Utc::now(),
+                    if_exists: false,
+                    accounts: vec![consumer.to_string()],
+                    check_cycle: false,
+                    chunk_size: None,
+                    update_existing: false,
+                };
+                mt.add_share_tenants(req).await?;
+            }
+
+            let orphans = mt
+                .list_orphaned_share_accounts(consumer.to_string())
+                .await?;
+            assert!(orphans.is_empty());
         }
 
-        info!("--- add account account1 again");
+        info!("--- remove the meta of share1 but not its account, as a racing drop would");
         {
-            let req = AddShareAccountsReq {
-                share_name: share_name.clone(),
-                share_on,
-                if_exists,
-                accounts: vec![account.to_string()],
+            let id_key = ShareId {
+                share_id: share_ids[0],
             };
+            mt.as_kv_api()
+                .upsert_kv(UpsertKVReq::new(
+                    &id_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+        }
 
-            let res = mt.add_share_tenants(req).await;
-            info!("add share account res: {:?}", res);
-            let err = res.unwrap_err();
-            assert_eq!(
-                ErrorCode::ShareAccountsAlreadyExists("").code(),
-                ErrorCode::from(err).code()
-            );
+        info!("--- the account of share1 is orphaned");
+        {
+            let orphans = mt
+                .list_orphaned_share_accounts(consumer.to_string())
+                .await?;
+            assert_eq!(orphans, vec![ShareAccountNameIdent {
+                account: consumer.to_string(),
+                share_id: share_ids[0],
+            }]);
+
+            let orphans = mt
+                .list_orphaned_share_accounts(provider.to_string())
+                .await?;
+            assert!(orphans.is_empty());
         }
 
-        info!("--- add account account2");
+        info!("--- cleanup removes the orphaned account only");
         {
-            let req = AddShareAccountsReq {
-                share_name: share_name.clone(),
-                share_on,
-                if_exists,
-                accounts: vec![account2.to_string()],
-            };
+            let removed = mt
+                .cleanup_orphaned_share_accounts(consumer.to_string())
+                .await?;
+            assert_eq!(removed, vec![ShareAccountNameIdent {
+                account: consumer.to_string(),
+                share_id: share_ids[0],
+            }]);
+
+            let orphans = mt
+                .list_orphaned_share_accounts(consumer.to_string())
+                .await?;
+            assert!(orphans.is_empty());
+
+            let (seq, _meta) = get_share_account_meta_or_err(
+                mt.as_kv_api(),
+                &ShareAccountNameIdent {
+                    account: consumer.to_string(),
+                    share_id: share_ids[1],
+                },
+                "",
+            )
+            .await?;
+            assert!(seq > 0);
+        }
 
-            let res = mt.add_share_tenants(req).await;
-            info!("add share account res: {:?}", res);
-            assert!(res.is_ok());
+        info!("--- cleanup again is a no-op");
+        {
+            let removed = mt
+                .cleanup_orphaned_share_accounts(consumer.to_string())
+                .await?;
+            assert!(removed.is_empty());
+        }
 
-            let (_share_meta_seq, share_meta) =
-                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
-            assert!(share_meta.has_account(&account2.to_string()));
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn show_shares_pagination<MT: ShareApi + AsKVApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let provider = "provider1";
+        let share_count = 7;
+        let page_size = 3;
+
+        info!("--- create shares more than the page size");
+        let mut share_names = vec![];
+        let mut share_ids = BTreeMap::new();
+        {
+            // create in reverse order to make sure the pages are ordered by share name.
+            for i in (0..share_count).rev() {
+                let share_name = format!("share{}", i);
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: ShareNameIdent {
+                        tenant: tenant.to_string(),
+                        share_name: share_name.clone(),
+                    },
+                    comment: None,
+                    create_on: Utc::now(),
+                    request_id: None,
+                };
+
+                let res = mt.create_share(req).await;
+                info!("create share res: {:?}", res);
+                share_ids.insert(share_name.clone(), res?.share_id);
+                share_names.push(share_name);
+            }
+            share_names.sort();
         }
 
-        info!("--- remove account account2");
+        info!("--- walk through all the pages");
         {
-            let req = RemoveShareAccountsReq {
-                share_name: share_name.clone(),
-                if_exists,
-                accounts: vec![account2.to_string()],
-            };
+            let mut got = vec![];
+            let mut offset = 0;
+            loop {
+                let req = ShowSharesReq {
+                    tenant: tenant.to_string(),
+                    outbound_offset: offset,
+                    inbound_offset: 0,
+                    limit: Some(page_size),
+                };
+
+                let resp = mt.show_shares(req).await?;
+                info!("show share res: {:?}", resp);
+                assert!(resp.inbound_accounts.is_empty());
+                assert!(!resp.inbound_has_more);
+                assert!(resp.outbound_accounts.len() <= page_size);
+                for account in resp.outbound_accounts.iter() {
+                    got.push(account.share_name.share_name.clone());
+                }
 
-            let res = mt.remove_share_tenants(req).await;
-            info!("remove share account res: {:?}", res);
-            assert!(res.is_ok());
+                assert_eq!(
+                    resp.next_outbound_offset,
+                    offset + resp.outbound_accounts.len()
+                );
+                offset = resp.next_outbound_offset;
+                if !resp.outbound_has_more {
+                    break;
+                }
+            }
 
-            // check account2 has been removed from share_meta
-            let (_share_meta_seq, share_meta) =
-                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
-            assert!(!share_meta.has_account(&account2.to_string()));
+            assert_eq!(share_names, got);
+        }
 
-            // check share account meta has been removed
-            let share_account_name = ShareAccountNameIdent {
-                account: account2.to_string(),
-                share_id,
+        info!("--- offset beyond the end returns an empty page");
+        {
+            let req = ShowSharesReq {
+                tenant: tenant.to_string(),
+                outbound_offset: share_count,
+                inbound_offset: 0,
+                limit: Some(page_size),
             };
-            let res = get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await;
-            let err = res.unwrap_err();
-            assert_eq!(
-                ErrorCode::UnknownShareAccounts("").code(),
-                ErrorCode::from(err).code()
-            );
+
+            let resp = mt.show_shares(req).await?;
+            assert!(resp.outbound_accounts.is_empty());
+            assert!(!resp.outbound_has_more);
         }
 
-        info!("--- drop share1 with if_exists=true");
+        info!("--- a skipped share still counts toward the next offset");
         {
-            let req = DropShareReq {
-                if_exists: true,
-                share_name: share_name.clone(),
+            // Corrupt share3 by removing its meta, the second page returns one share less.
+            let id_key = ShareId {
+                share_id: share_ids["share3"],
             };
+            mt.as_kv_api()
+                .upsert_kv(UpsertKVReq::new(
+                    &id_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
 
-            let res = mt.drop_share(req).await;
-            assert!(res.is_ok());
+            let req = ShowSharesReq {
+                tenant: tenant.to_string(),
+                outbound_offset: page_size,
+                inbound_offset: 0,
+                limit: Some(page_size),
+            };
+            let resp = mt.show_shares(req).await?;
+            let got: Vec<_> = resp
+                .outbound_accounts
+                .iter()
+                .map(|account| account.share_name.share_name.clone())
+                .collect();
+            assert_eq!(got, vec!["share4".to_string(), "share5".to_string()]);
+            assert_eq!(resp.next_outbound_offset, page_size * 2);
+            assert!(resp.outbound_has_more);
 
-            // check share account meta has been removed
-            let share_account_name = ShareAccountNameIdent {
-                account: account.to_string(),
-                share_id,
+            let req = ShowSharesReq {
+                tenant: tenant.to_string(),
+                outbound_offset: resp.next_outbound_offset,
+                inbound_offset: 0,
+                limit: Some(page_size),
             };
-            let res = get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await;
-            let err = res.unwrap_err();
-            assert_eq!(
-                ErrorCode::UnknownShareAccounts("").code(),
-                ErrorCode::from(err).code()
-            );
+            let resp = mt.show_shares(req).await?;
+            assert_eq!(resp.outbound_accounts.len(), 1);
+            assert_eq!(resp.outbound_accounts[0].share_name.share_name, "share6");
+            assert!(!resp.outbound_has_more);
+        }
+
+        info!("--- share more shares than the page size to tenant1");
+        let mut inbound_share_names = vec![];
+        {
+            for i in (0..share_count).rev() {
+                let share_name = ShareNameIdent {
+                    tenant: provider.to_string(),
+                    share_name: format!("inbound{}", i),
+                };
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on: Utc::now(),
+                    request_id: None,
+                };
+                mt.create_share(req).await?;
+
+                let req = AddShareAccountsReq {
+                    share_name: share_name.clone(),
+                    share_on: Utc::now(),
+                    if_exists: false,
+                    accounts: vec![tenant.to_string()],
+                    check_cycle: false,
+                    chunk_size: None,
+                    update_existing: false,
+                };
+                mt.add_share_tenants(req).await?;
+                inbound_share_names.push(share_name.share_name);
+            }
+            inbound_share_names.sort();
+        }
+
+        info!("--- walk through the inbound pages with their own cursor");
+        {
+            let mut got = vec![];
+            let mut inbound_offset = 0;
+            loop {
+                // The outbound cursor stays at the end, the inbound one is not affected by it.
+                let req = ShowSharesReq {
+                    tenant: tenant.to_string(),
+                    outbound_offset: share_count,
+                    inbound_offset,
+                    limit: Some(page_size),
+                };
+
+                let resp = mt.show_shares(req).await?;
+                info!("show share res: {:?}", resp);
+                assert!(resp.outbound_accounts.is_empty());
+                assert!(!resp.outbound_has_more);
+                assert!(resp.inbound_accounts.len() <= page_size);
+                for account in resp.inbound_accounts.iter() {
+                    assert_eq!(account.share_name.tenant, provider);
+                    got.push(account.share_name.share_name.clone());
+                }
+
+                inbound_offset = resp.next_inbound_offset;
+                if !resp.inbound_has_more {
+                    break;
+                }
+            }
+
+            assert_eq!(inbound_share_names, got);
         }
 
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
-    async fn share_grant_revoke_object<MT: ShareApi + AsKVApi + SchemaApi>(
+    async fn share_add_accounts_in_chunks<MT: ShareApi + AsKVApi>(
         &self,
         mt: &MT,
     ) -> anyhow::Result<()> {
         let tenant = "tenant1";
-        let share1 = "share1";
-        let db_name = "db1";
-        let tbl_name = "table1";
-        let db2_name = "db2";
-        let tbl2_name = "table2";
-
         let share_name = ShareNameIdent {
             tenant: tenant.to_string(),
-            share_name: share1.to_string(),
+            share_name: "share1".to_string(),
         };
         let share_id: u64;
-        let db_id: u64;
-        let table_id: u64;
 
-        info!("--- create share1,db1,table1");
-        let create_on = Utc::now();
+        info!("--- create share1");
         {
             let req = CreateShareReq {
                 if_not_exists: false,
                 share_name: share_name.clone(),
                 comment: None,
-                create_on,
+                create_on: Utc::now(),
+                request_id: None,
             };
+            share_id = mt.create_share(req).await?.share_id;
+        }
 
-            let res = mt.create_share(req).await;
-            info!("create share res: {:?}", res);
-            let res = res.unwrap();
-            assert_eq!(1, res.share_id, "first database id is 1");
-            share_id = res.share_id;
-
-            let (share_name_seq, share_name_ret) =
-                get_share_id_to_name_or_err(mt.as_kv_api(), share_id, "").await?;
-            assert!(share_name_seq > 0);
-            assert_eq!(share_name, share_name_ret);
-
-            let plan = CreateDatabaseReq {
-                if_not_exists: false,
-                name_ident: DatabaseNameIdent {
-                    tenant: tenant.to_string(),
-                    db_name: db_name.to_string(),
-                },
-                meta: DatabaseMeta::default(),
+        info!("--- add five accounts in chunks of two");
+        let accounts: Vec<String> = (0..5).map(|i| format!("account{}", i)).collect();
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: Utc::now(),
+                if_exists: false,
+                accounts: accounts.clone(),
+                check_cycle: false,
+                chunk_size: Some(2),
+                update_existing: false,
             };
 
-            let res = mt.create_database(plan).await?;
-            info!("create database res: {:?}", res);
-            db_id = res.db_id;
-
-            let req = CreateTableReq {
-                if_not_exists: false,
-                name_ident: TableNameIdent {
-                    tenant: tenant.to_string(),
-                    db_name: db_name.to_string(),
-                    table_name: tbl_name.to_string(),
-                },
-                table_meta: TableMeta::default(),
-            };
+            let res = mt.add_share_tenants(req).await?;
+            info!("add share account res: {:?}", res);
+            assert_eq!(res.committed_chunks, 3);
+            assert_eq!(res.added_accounts, accounts);
+            assert!(res.skipped_accounts.is_empty());
+        }
 
-            let res = mt.create_table(req.clone()).await?;
-            info!("create table res: {:?}", res);
-            table_id = res.table_id;
+        info!("--- all the accounts are added");
+        {
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert_eq!(share_meta.get_accounts(), accounts);
+
+            for account in accounts.iter() {
+                let (seq, _meta) = get_share_account_meta_or_err(
+                    mt.as_kv_api(),
+                    &ShareAccountNameIdent {
+                        account: account.clone(),
+                        share_id,
+                    },
+                    "",
+                )
+                .await?;
+                assert!(seq > 0);
+            }
+        }
 
-            let plan = CreateDatabaseReq {
-                if_not_exists: false,
-                name_ident: DatabaseNameIdent {
-                    tenant: tenant.to_string(),
-                    db_name: db2_name.to_string(),
-                },
-                meta: DatabaseMeta::default(),
-            };
+        Ok(())
+    }
 
-            let res = mt.create_database(plan).await?;
-            info!("create database res: {:?}", res);
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_add_accounts_update_existing<MT: ShareApi + AsKVApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let account = "account1";
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        let share_on = Utc.ymd(2022, 8, 1).and_hms(12, 0, 0);
+        let renew_on = Utc.ymd(2022, 9, 1).and_hms(12, 0, 0);
+        let share_id: u64;
 
-            let req = CreateTableReq {
+        info!("--- create share1 and add account1");
+        {
+            let req = CreateShareReq {
                 if_not_exists: false,
-                name_ident: TableNameIdent {
-                    tenant: tenant.to_string(),
-                    db_name: db2_name.to_string(),
-                    table_name: tbl2_name.to_string(),
-                },
-                table_meta: TableMeta::default(),
+                share_name: share_name.clone(),
+                comment: None,
+                create_on: Utc::now(),
+                request_id: None,
             };
+            share_id = mt.create_share(req).await?.share_id;
 
-            let res = mt.create_table(req.clone()).await?;
-            info!("create table res: {:?}", res);
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on,
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
         }
 
-        info!("--- grant unknown db2,table2");
+        let share_account_key = ShareAccountNameIdent {
+            account: account.to_string(),
+            share_id,
+        };
+
+        info!("--- re-adding account1 without update_existing fails");
         {
-            let req = GrantShareObjectReq {
+            let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
-                object: ShareGrantObjectName::Database("unknown_db".to_string()),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                share_on: renew_on,
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
             };
-
-            let res = mt.grant_share_object(req).await;
-            info!("grant object res: {:?}", res);
+            let res = mt.add_share_tenants(req).await;
             let err = res.unwrap_err();
             assert_eq!(
-                ErrorCode::UnknownDatabase("").code(),
+                ErrorCode::ShareAccountsAlreadyExists("").code(),
                 ErrorCode::from(err).code()
             );
 
-            let req = GrantShareObjectReq {
+            let (_seq, meta) =
+                get_share_account_meta_or_err(mt.as_kv_api(), &share_account_key, "").await?;
+            assert_eq!(meta.share_on, share_on);
+        }
+
+        info!("--- re-adding account1 with update_existing refreshes share_on");
+        {
+            let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
-                object: ShareGrantObjectName::Table(
-                    db_name.to_string(),
-                    "unknown_table".to_string(),
-                ),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                share_on: renew_on,
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: true,
             };
+            let res = mt.add_share_tenants(req).await?;
+            assert_eq!(res.committed_chunks, 1);
+            assert!(res.added_accounts.is_empty());
+            assert_eq!(res.updated_accounts, vec![account.to_string()]);
 
-            let res = mt.grant_share_object(req).await;
-            info!("grant object res: {:?}", res);
-            let err = res.unwrap_err();
-            assert_eq!(
-                ErrorCode::UnknownTable("").code(),
-                ErrorCode::from(err).code()
-            );
+            let (_seq, meta) =
+                get_share_account_meta_or_err(mt.as_kv_api(), &share_account_key, "").await?;
+            assert_eq!(meta.share_on, renew_on);
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert_eq!(share_meta.get_accounts(), vec![account.to_string()]);
         }
 
-        info!("--- grant unknown share2");
+        info!("--- adding account1 and account2 skips account1");
         {
-            let req = GrantShareObjectReq {
-                share_name: ShareNameIdent {
-                    tenant: tenant.to_string(),
-                    share_name: "share2".to_string(),
-                },
-                object: ShareGrantObjectName::Database("db2".to_string()),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: renew_on,
+                if_exists: false,
+                accounts: vec![account.to_string(), "account2".to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
             };
+            let res = mt.add_share_tenants(req).await?;
+            assert_eq!(res.added_accounts, vec!["account2".to_string()]);
+            assert!(res.updated_accounts.is_empty());
+            assert_eq!(res.skipped_accounts, vec![account.to_string()]);
+        }
 
-            let res = mt.grant_share_object(req).await;
-            info!("grant object res: {:?}", res);
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_accounts_pagination<MT: ShareApi + AsKVApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let account_count = 50;
+        let page_size = 7;
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+
+        info!("--- unknown share");
+        {
+            let res = mt
+                .get_share_accounts(share_name.clone(), Some(page_size), 0)
+                .await;
             let err = res.unwrap_err();
             assert_eq!(
                 ErrorCode::UnknownShare("").code(),
@@ -561,256 +1071,3996 @@ impl ShareApiTestSuite {
             );
         }
 
-        info!("--- grant table2 on a unbound database share");
+        info!("--- create share1 with accounts more than the page size");
+        let mut accounts = vec![];
         {
-            let req = GrantShareObjectReq {
+            let req = CreateShareReq {
+                if_not_exists: false,
                 share_name: share_name.clone(),
-                object: ShareGrantObjectName::Table(db2_name.to_string(), tbl2_name.to_string()),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                comment: None,
+                create_on: Utc::now(),
+                request_id: None,
             };
+            mt.create_share(req).await?;
 
-            let res = mt.grant_share_object(req).await;
-            info!("grant object res: {:?}", res);
+            // add in reverse order to make sure the pages are ordered by account name.
+            for i in (0..account_count).rev() {
+                accounts.push(format!("account{:02}", i));
+            }
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: Utc::now(),
+                if_exists: false,
+                accounts: accounts.clone(),
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+            accounts.sort();
+        }
+
+        info!("--- walk through all the pages");
+        {
+            let mut got = vec![];
+            let mut offset = 0;
+            loop {
+                let (page, has_more) = mt
+                    .get_share_accounts(share_name.clone(), Some(page_size), offset)
+                    .await?;
+                info!("get share accounts res: {:?}", page);
+                assert!(page.len() <= page_size);
+
+                offset += page.len();
+                got.extend(page);
+                if !has_more {
+                    break;
+                }
+            }
+
+            // no duplicates and no omissions.
+            assert_eq!(accounts, got);
+        }
+
+        info!("--- no limit returns all the accounts after offset");
+        {
+            let (page, has_more) = mt.get_share_accounts(share_name.clone(), None, 45).await?;
+            assert_eq!(accounts[45..].to_vec(), page);
+            assert!(!has_more);
+        }
+
+        info!("--- offset beyond the end returns an empty page");
+        {
+            let (page, has_more) = mt
+                .get_share_accounts(share_name.clone(), Some(page_size), account_count)
+                .await?;
+            assert!(page.is_empty());
+            assert!(!has_more);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_record_access<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let consumer = "tenant2";
+        let db_name = "db1";
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        info!("--- unknown share");
+        {
+            let res = mt.record_share_access(share_name.clone(), Utc::now()).await;
             let err = res.unwrap_err();
             assert_eq!(
-                ErrorCode::WrongShareObject("").code(),
+                ErrorCode::UnknownShare("").code(),
                 ErrorCode::from(err).code()
             );
         }
 
-        info!("--- grant db object and table object");
+        info!("--- create share1, grant db1 and add the consumer, never accessed");
         {
-            let req = GrantShareObjectReq {
+            let req = CreateShareReq {
+                if_not_exists: false,
                 share_name: share_name.clone(),
-                object: ShareGrantObjectName::Database(db_name.to_string()),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                comment: None,
+                create_on: Utc::now(),
+                request_id: None,
             };
+            mt.create_share(req).await?;
 
-            let res = mt.grant_share_object(req).await?;
-            info!("grant object res: {:?}", res);
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
 
-            let tbl_ob_name =
-                ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
             let req = GrantShareObjectReq {
                 share_name: share_name.clone(),
-                object: tbl_ob_name.clone(),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: Utc::now(),
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
             };
+            mt.grant_share_object(req).await?;
 
-            let res = mt.grant_share_object(req).await?;
-            info!("grant object res: {:?}", res);
-
-            let (_share_meta_seq, share_meta) =
-                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
-
-            match share_meta.database {
-                Some(entry) => match entry.object {
-                    ShareGrantObject::Database(obj_db_id) => {
-                        assert_eq!(obj_db_id, db_id);
-
-                        assert_eq!(entry.grant_on, create_on);
-                        assert_eq!(
-                            entry.privileges,
-                            BitFlags::from(ShareGrantObjectPrivilege::Usage)
-                        );
-                    }
-                    _ => {
-                        panic!("MUST has database entry!")
-                    }
-                },
-                None => {
-                    panic!("MUST has database entry!")
-                }
-            }
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: Utc::now(),
+                if_exists: false,
+                accounts: vec![consumer.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
 
-            let object = ShareGrantObject::Table(table_id);
-            if let Some(entry) = share_meta.entries.get(&object.to_string()) {
-                assert_eq!(entry.object, object);
-                assert_eq!(entry.grant_on, create_on);
-                assert_eq!(
-                    entry.privileges,
-                    BitFlags::from(ShareGrantObjectPrivilege::Usage)
-                );
-            } else {
-                panic!("MUST has table entry!")
-            }
+            let resp = mt.show_shares(ShowSharesReq::new(tenant)).await?;
+            assert_eq!(resp.outbound_accounts.len(), 1);
+            assert_eq!(resp.outbound_accounts[0].last_accessed_on, None);
         }
 
-        info!("--- grant db2, table2 on another bounded database share");
+        info!("--- the provider reading its own grants is not an access");
         {
-            let req = GrantShareObjectReq {
-                share_name: share_name.clone(),
-                object: ShareGrantObjectName::Database(db2_name.to_string()),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+            let req = GetObjectGrantPrivilegesReq {
+                tenant: tenant.to_string(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                privilege: None,
             };
+            let res = mt.get_grant_privileges_of_object(req).await?;
+            assert_eq!(res.privileges.len(), 1);
 
-            let res = mt.grant_share_object(req).await;
-            info!("grant object res: {:?}", res);
+            let (_seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert_eq!(share_meta.last_accessed_on, None);
+        }
+
+        info!("--- a tenant that is not an account of share1 can not resolve it");
+        {
+            let res = mt
+                .get_inbound_share_grant_objects("tenant3".to_string(), share_name.clone())
+                .await;
             let err = res.unwrap_err();
             assert_eq!(
-                ErrorCode::WrongShareObject("").code(),
+                ErrorCode::UnknownShare("").code(),
                 ErrorCode::from(err).code()
             );
 
-            let req = GrantShareObjectReq {
-                share_name: share_name.clone(),
-                object: ShareGrantObjectName::Table(db2_name.to_string(), tbl2_name.to_string()),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
-            };
+            let (_seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert_eq!(share_meta.last_accessed_on, None);
+        }
 
-            let res = mt.grant_share_object(req).await;
-            info!("grant object res: {:?}", res);
-            let err = res.unwrap_err();
+        info!("--- the consumer resolving share1 records the first access");
+        let first_access_on;
+        {
+            let before = Utc::now();
+            let res = mt
+                .get_inbound_share_grant_objects(consumer.to_string(), share_name.clone())
+                .await?;
+            assert_eq!(res.objects.len(), 1);
+
+            let resp = mt.show_shares(ShowSharesReq::new(tenant)).await?;
+            first_access_on = resp.outbound_accounts[0].last_accessed_on.unwrap();
+            assert!(first_access_on >= before);
+
+            let resp = mt.show_shares(ShowSharesReq::new(consumer)).await?;
+            assert_eq!(resp.inbound_accounts.len(), 1);
             assert_eq!(
-                ErrorCode::WrongShareObject("").code(),
-                ErrorCode::from(err).code()
+                resp.inbound_accounts[0].last_accessed_on,
+                Some(first_access_on)
             );
         }
 
-        info!("--- revoke share of table");
+        info!("--- resolving share1 again soon after does not write the share meta");
         {
-            let req = RevokeShareObjectReq {
-                share_name: share_name.clone(),
-                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
-                update_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
-            };
+            let (seq, _share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
 
-            let res = mt.revoke_share_object(req).await?;
-            info!("revoke object res: {:?}", res);
+            let res = mt
+                .get_inbound_share_grant_objects(consumer.to_string(), share_name.clone())
+                .await?;
+            assert_eq!(res.objects.len(), 1);
 
-            let (_share_meta_seq, share_meta) =
-                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            let (seq_after, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert_eq!(seq_after, seq);
+            assert_eq!(share_meta.last_accessed_on, Some(first_access_on));
+        }
 
-            match share_meta.database {
-                Some(entry) => match entry.object {
-                    ShareGrantObject::Database(obj_db_id) => {
-                        assert_eq!(obj_db_id, db_id);
+        info!("--- an access soon after the recorded one is not written");
+        {
+            let written = mt
+                .record_share_access(share_name.clone(), first_access_on + Duration::seconds(60))
+                .await?;
+            assert!(!written);
+        }
 
-                        assert_eq!(entry.grant_on, create_on);
-                        assert_eq!(
-                            entry.privileges,
-                            BitFlags::from(ShareGrantObjectPrivilege::Usage)
-                        );
-                    }
-                    _ => {
-                        panic!("MUST has database entry!")
-                    }
-                },
-                None => {
-                    panic!("MUST has database entry!")
-                }
+        info!("--- a later access updates the timestamp");
+        {
+            let later = first_access_on + Duration::hours(1);
+            let written = mt.record_share_access(share_name.clone(), later).await?;
+            assert!(written);
+
+            let resp = mt.show_shares(ShowSharesReq::new(tenant)).await?;
+            assert_eq!(resp.outbound_accounts[0].last_accessed_on, Some(later));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn inbound_shared_accounts_stream<MT: ShareApi + AsKVApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let consumer = "consumer".to_string();
+
+        info!("--- share to the consumer from several providers");
+        {
+            for i in (0..5).rev() {
+                let share_name = ShareNameIdent {
+                    tenant: format!("provider{}", i),
+                    share_name: format!("share{}", i),
+                };
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: Some(format!("comment{}", i)),
+                    create_on: Utc::now(),
+                    request_id: None,
+                };
+                mt.create_share(req).await?;
+
+                let req = AddShareAccountsReq {
+                    share_name,
+                    share_on: Utc::now(),
+                    if_exists: false,
+                    accounts: vec![consumer.clone()],
+                    check_cycle: false,
+                    chunk_size: None,
+                    update_existing: false,
+                };
+                mt.add_share_tenants(req).await?;
             }
+        }
 
-            let object = ShareGrantObject::Table(table_id);
-            assert!(share_meta.entries.get(&object.to_string()).is_none());
+        info!("--- the stream yields the same accounts as show_shares");
+        {
+            let resp = mt.show_shares(ShowSharesReq::new(consumer.clone())).await?;
+            assert_eq!(resp.inbound_accounts.len(), 5);
+
+            let mut streamed: Vec<ShareAccountReply> =
+                get_inbound_shared_accounts_stream(mt.as_kv_api(), &consumer)
+                    .try_collect()
+                    .await?;
+            streamed.sort_by(|a, b| a.share_name.share_name.cmp(&b.share_name.share_name));
+            assert_eq!(streamed, resp.inbound_accounts);
         }
 
-        info!("--- grant share of table again, and revoke the database");
+        info!("--- a share dropped while the stream is running is skipped");
         {
-            // first grant share table again
-            let req = GrantShareObjectReq {
-                share_name: share_name.clone(),
-                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+            let mut stream = Box::pin(get_inbound_shared_accounts_stream(
+                mt.as_kv_api(),
+                &consumer,
+            ));
+            let first = stream.try_next().await?.unwrap();
+
+            // Drop a share that has not been yielded yet.
+            let (provider, dropped) = if first.share_name.share_name == "share0" {
+                ("provider1", "share1")
+            } else {
+                ("provider0", "share0")
+            };
+            let share_name = ShareNameIdent {
+                tenant: provider.to_string(),
+                share_name: dropped.to_string(),
+            };
+            let req = DropShareReq {
+                if_exists: false,
+                share_name,
+                force: false,
+                dry_run: false,
+            };
+            mt.drop_share(req).await?;
+
+            let rest: Vec<ShareAccountReply> = stream.try_collect().await?;
+            assert_eq!(rest.len(), 3);
+            assert!(rest.iter().all(|a| a.share_name.share_name != dropped));
+        }
+
+        info!("--- a tenant without inbound shares yields nothing");
+        {
+            let tenant = "provider0".to_string();
+            let streamed: Vec<ShareAccountReply> =
+                get_inbound_shared_accounts_stream(mt.as_kv_api(), &tenant)
+                    .try_collect()
+                    .await?;
+            assert!(streamed.is_empty());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_add_remove_account<MT: ShareApi + AsKVApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let tenant2 = "tenant2";
+        let share1 = "share1";
+        let share2 = "share2";
+        let account = "account1";
+        let account2 = "account2";
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let share_name2 = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share2.to_string(),
+        };
+        let share_name3 = ShareNameIdent {
+            tenant: tenant2.to_string(),
+            share_name: share2.to_string(),
+        };
+        let comment1 = "comment1";
+        let comment2 = "comment2";
+        let comment3 = "comment3";
+        let share_id: u64;
+        let share_on = Utc::now();
+        let create_on = Utc::now();
+        let if_exists = true;
+
+        info!("--- add and remove account with not exist share");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on,
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+
+            // get share meta and check account has been added
+            let res = mt.add_share_tenants(req).await;
+            assert!(res.is_err());
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let req = RemoveShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists: false,
+                accounts: vec![account2.to_string()],
+            };
+
+            let res = mt.remove_share_tenants(req).await;
+            assert!(res.is_err());
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- prepare share1 share2 share3");
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: Some(comment1.to_string()),
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await;
+            info!("add share account res: {:?}", res);
+            let res = res.unwrap();
+            assert_eq!(1, res.share_id, "first database id is 1");
+            share_id = res.share_id;
+
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name2.clone(),
+                comment: Some(comment2.to_string()),
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await;
+            info!("add share account res: {:?}", res);
+
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name3.clone(),
+                comment: Some(comment3.to_string()),
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await;
+            info!("add share account res: {:?}", res);
+        }
+
+        info!("--- add account account1");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on,
+                if_exists,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+
+            // get share meta and check account has been added
+            let res = mt.add_share_tenants(req).await;
+            info!("add share account res: {:?}", res);
+            assert!(res.is_ok());
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_meta.has_account(&account.to_string()));
+
+            // get and check share account meta
+            let share_account_name = ShareAccountNameIdent {
+                account: account.to_string(),
+                share_id,
+            };
+            let (_share_account_meta_seq, share_account_meta) =
+                get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await?;
+            assert_eq!(share_account_meta.share_id, share_id);
+            assert_eq!(share_account_meta.account, account.to_string());
+            assert_eq!(share_account_meta.share_on, share_on);
+
+            // get_grant_tenants_of_share
+            let req = GetShareGrantTenantsReq {
+                share_name: share_name.clone(),
+                include_meta: false,
+            };
+            let resp = mt.get_grant_tenants_of_share(req).await;
+            assert!(resp.is_ok());
+            let resp = resp.unwrap();
+            assert_eq!(resp.accounts.len(), 1);
+            assert_eq!(resp.accounts[0], account.to_string());
+            assert!(resp.account_metas.is_empty());
+
+            // get_grant_tenants_of_share with the account metas
+            let req = GetShareGrantTenantsReq {
+                share_name: share_name.clone(),
+                include_meta: true,
+            };
+            let resp = mt.get_grant_tenants_of_share(req).await?;
+            assert_eq!(resp.accounts, vec![account.to_string()]);
+            assert_eq!(resp.account_metas, vec![share_account_meta]);
+            assert_eq!(resp.account_metas[0].share_on, share_on);
+            assert_eq!(resp.account_metas[0].accept_on, None);
+        }
+
+        info!("--- share tenant2.share2 to tenant1");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name3.clone(),
+                share_on,
+                if_exists,
+                accounts: vec![tenant.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+
+            // get share meta and check account has been added
+            let res = mt.add_share_tenants(req).await;
+            info!("add share account res: {:?}", res);
+            assert!(res.is_ok());
+        }
+
+        // test show share api
+        info!("--- show share check account information");
+        {
+            let req = ShowSharesReq::new(tenant);
+
+            let res = mt.show_shares(req).await;
+            info!("show share res: {:?}", res);
+            assert!(res.is_ok());
+            let resp = res.unwrap();
+            assert_eq!(resp.inbound_accounts.len(), 1);
+            assert_eq!(resp.inbound_accounts[0].share_name, share_name3.clone());
+            assert_eq!(resp.inbound_accounts[0].create_on, share_on.clone());
+            assert_eq!(resp.inbound_accounts[0].comment, Some(comment3.to_string()));
+
+            assert_eq!(resp.outbound_accounts.len(), 2);
+            assert_eq!(resp.outbound_accounts[0].share_name, share_name.clone());
+            assert_eq!(resp.outbound_accounts[0].create_on, create_on.clone());
+            assert_eq!(
+                resp.outbound_accounts[0].comment,
+                Some(comment1.to_string())
+            );
+            assert_eq!(resp.outbound_accounts[1].share_name, share_name2.clone());
+            assert_eq!(resp.outbound_accounts[1].create_on, create_on.clone());
+            assert_eq!(
+                resp.outbound_accounts[1].comment,
+                Some(comment2.to_string())
+            );
+            assert!(resp.outbound_accounts[0].accounts.is_some());
+            assert!(resp.outbound_accounts[1].accounts.is_some());
+            let accounts = resp.outbound_accounts[0].accounts.as_ref().unwrap();
+            assert_eq!(accounts.len(), 1);
+            assert_eq!(accounts[0], account.to_string());
+            assert_eq!(
+                resp.outbound_accounts[1].accounts.as_ref().unwrap().len(),
+                0
+            );
+        }
+
+        info!("--- add account account1 again");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on,
+                if_exists,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+
+            let res = mt.add_share_tenants(req).await;
+            info!("add share account res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareAccountsAlreadyExists("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- add account account2");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on,
+                if_exists,
+                accounts: vec![account2.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+
+            let res = mt.add_share_tenants(req).await;
+            info!("add share account res: {:?}", res);
+            assert!(res.is_ok());
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_meta.has_account(&account2.to_string()));
+        }
+
+        info!("--- remove account account2");
+        {
+            let req = RemoveShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists,
+                accounts: vec![account2.to_string()],
+            };
+
+            let res = mt.remove_share_tenants(req).await;
+            info!("remove share account res: {:?}", res);
+            let res = res.unwrap();
+            assert_eq!(res.removed_accounts, vec![account2.to_string()]);
+            assert!(res.not_found_accounts.is_empty());
+
+            // check account2 has been removed from share_meta
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(!share_meta.has_account(&account2.to_string()));
+
+            // check share account meta has been removed
+            let share_account_name = ShareAccountNameIdent {
+                account: account2.to_string(),
+                share_id,
+            };
+            let res = get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShareAccounts("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- remove account1 and an unknown account");
+        {
+            let req = RemoveShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists,
+                accounts: vec![account.to_string(), "account3".to_string()],
+            };
+
+            let res = mt.remove_share_tenants(req).await;
+            info!("remove share account res: {:?}", res);
+            let res = res.unwrap();
+            assert_eq!(res.removed_accounts, vec![account.to_string()]);
+            assert_eq!(res.not_found_accounts, vec!["account3".to_string()]);
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(!share_meta.has_account(&account.to_string()));
+        }
+
+        info!("--- remove only the accounts not granted");
+        {
+            let req = RemoveShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists,
+                accounts: vec![account.to_string(), "account3".to_string()],
+            };
+
+            let (share_meta_seq, _share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+
+            let res = mt.remove_share_tenants(req).await;
+            info!("remove share account res: {:?}", res);
+            let res = res.unwrap();
+            assert!(res.removed_accounts.is_empty());
+            assert_eq!(res.not_found_accounts, vec![
+                account.to_string(),
+                "account3".to_string()
+            ]);
+
+            // The share meta is not written.
+            let (seq, _share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert_eq!(seq, share_meta_seq);
+        }
+
+        info!("--- drop share1 with if_exists=true");
+        {
+            let req = DropShareReq {
+                if_exists: true,
+                share_name: share_name.clone(),
+                force: false,
+                dry_run: false,
+            };
+
+            let res = mt.drop_share(req).await;
+            assert!(res.is_ok());
+
+            // check share account meta has been removed
+            let share_account_name = ShareAccountNameIdent {
+                account: account.to_string(),
+                share_id,
+            };
+            let res = get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShareAccounts("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_add_account_cycle<MT: ShareApi + AsKVApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let share_on = Utc::now();
+        let share_name_of = |tenant: &str| ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: format!("share_of_{}", tenant),
+        };
+
+        info!("--- tenant1 shares to tenant2, tenant2 shares to tenant3");
+        let share_id3 = {
+            for (tenant, account) in [("tenant1", "tenant2"), ("tenant2", "tenant3")] {
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name_of(tenant),
+                    comment: None,
+                    create_on: share_on,
+                    request_id: None,
+                };
+                mt.create_share(req).await?;
+
+                let req = AddShareAccountsReq {
+                    share_name: share_name_of(tenant),
+                    share_on,
+                    if_exists: false,
+                    accounts: vec![account.to_string()],
+                    check_cycle: true,
+                    chunk_size: None,
+                    update_existing: false,
+                };
+                mt.add_share_tenants(req).await?;
+            }
+
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name_of("tenant3"),
+                comment: None,
+                create_on: share_on,
+                request_id: None,
+            };
+            mt.create_share(req).await?.share_id
+        };
+
+        info!("--- tenant3 sharing to tenant1 closes the loop");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name_of("tenant3"),
+                share_on,
+                if_exists: false,
+                accounts: vec!["tenant1".to_string()],
+                check_cycle: true,
+                chunk_size: None,
+                update_existing: false,
+            };
+            let res = mt.add_share_tenants(req).await;
+            info!("add share account res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareCycleDetected("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id3, "").await?;
+            assert!(share_meta.accounts.is_empty());
+        }
+
+        info!("--- tenant3 sharing to a tenant out of the loop is allowed");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name_of("tenant3"),
+                share_on,
+                if_exists: false,
+                accounts: vec!["tenant4".to_string()],
+                check_cycle: true,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_freeze<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let account = "account1";
+        let account2 = "account2";
+        let db_name = "db1";
+        let tbl_name = "table1";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let db_object = ShareGrantObjectName::Database(db_name.to_string());
+        let tbl_object = ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
+
+        info!("--- create share1, db1 and table1, grant db1 and add account1 to share1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+            mt.create_share(req).await?;
+
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(req).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+            mt.create_table(req).await?;
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: db_object.clone(),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+                share_on: create_on,
+            };
+            mt.add_share_tenants(req).await?;
+        }
+
+        info!("--- freeze share1");
+        {
+            mt.alter_share_freeze(share_name.clone(), true).await?;
+
+            let (_seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert!(share_meta.frozen);
+
+            // Freezing a frozen share is a no-op.
+            mt.alter_share_freeze(share_name.clone(), true).await?;
+        }
+
+        info!("--- grant_share_object is rejected while frozen");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: tbl_object.clone(),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+            let err = mt.grant_share_object(req).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareFrozen("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- revoke_share_object is rejected while frozen");
+        {
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: db_object.clone(),
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+            };
+            let err = mt.revoke_share_object(req).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareFrozen("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- add_share_tenants is rejected while frozen");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists: false,
+                accounts: vec![account2.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+                share_on: create_on,
+            };
+            let err = mt.add_share_tenants(req).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareFrozen("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- remove_share_tenants is rejected while frozen");
+        {
+            let req = RemoveShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists: false,
+                accounts: vec![account.to_string()],
+            };
+            let err = mt.remove_share_tenants(req).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareFrozen("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- the grants and accounts are not changed");
+        {
+            let (_seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert!(share_meta.database.is_some());
+            assert!(share_meta.entries.is_empty());
+            assert_eq!(share_meta.get_accounts(), vec![account.to_string()]);
+        }
+
+        info!("--- unfreeze share1, grant table1 to share1");
+        {
+            mt.alter_share_freeze(share_name.clone(), false).await?;
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: tbl_object.clone(),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+
+            let res = mt
+                .get_share_object_privilege(share_name.clone(), tbl_object.clone())
+                .await?;
+            assert!(res.is_some());
+        }
+
+        info!("--- a frozen share can still be dropped");
+        {
+            mt.alter_share_freeze(share_name.clone(), true).await?;
+
+            let req = DropShareReq {
+                if_exists: false,
+                share_name: share_name.clone(),
+                force: false,
+                dry_run: false,
+            };
+            mt.drop_share(req).await?;
+
+            let err = mt
+                .alter_share_freeze(share_name.clone(), false)
+                .await
+                .unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_transfer_accounts<MT: ShareApi + AsKVApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let accounts = ["account1", "account2", "account3"];
+        let share_on = Utc::now();
+
+        let share_name_a = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share_a".to_string(),
+        };
+        let share_name_b = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share_b".to_string(),
+        };
+        let mut share_ids = vec![];
+
+        info!("--- create share_a with 3 accounts, share_b with one of them");
+        {
+            for share_name in [&share_name_a, &share_name_b] {
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on: share_on,
+                    request_id: None,
+                };
+                share_ids.push(mt.create_share(req).await?.share_id);
+            }
+
+            let req = AddShareAccountsReq {
+                share_name: share_name_a.clone(),
+                share_on,
+                if_exists: false,
+                accounts: accounts.iter().map(|a| a.to_string()).collect(),
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+
+            let req = AddShareAccountsReq {
+                share_name: share_name_b.clone(),
+                share_on,
+                if_exists: false,
+                accounts: vec![accounts[2].to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+        }
+
+        info!("--- transfer the accounts of share_a to share_b");
+        {
+            let reply = mt
+                .transfer_share_accounts(share_name_a.clone(), share_name_b.clone())
+                .await?;
+            assert_eq!(reply.transferred_accounts, vec![
+                accounts[0].to_string(),
+                accounts[1].to_string()
+            ]);
+            assert_eq!(reply.skipped_accounts, vec![accounts[2].to_string()]);
+
+            let (_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_ids[0], "").await?;
+            assert!(share_meta.accounts.is_empty());
+
+            let (_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_ids[1], "").await?;
+            assert_eq!(share_meta.get_accounts(), accounts.to_vec());
+
+            for account in accounts {
+                let share_account_name = ShareAccountNameIdent {
+                    account: account.to_string(),
+                    share_id: share_ids[0],
+                };
+                let res =
+                    get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await;
+                let err = res.unwrap_err();
+                assert_eq!(
+                    ErrorCode::UnknownShareAccounts("").code(),
+                    ErrorCode::from(err).code()
+                );
+
+                let share_account_name = ShareAccountNameIdent {
+                    account: account.to_string(),
+                    share_id: share_ids[1],
+                };
+                let (_seq, share_account_meta) =
+                    get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await?;
+                assert_eq!(share_account_meta.share_id, share_ids[1]);
+                assert_eq!(share_account_meta.share_on, share_on);
+            }
+        }
+
+        info!("--- transfer from an unknown share");
+        {
+            let share_name = ShareNameIdent {
+                tenant: tenant.to_string(),
+                share_name: "share_c".to_string(),
+            };
+            let res = mt
+                .transfer_share_accounts(share_name, share_name_b.clone())
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_grant_revoke_object<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+        let db2_name = "db2";
+        let tbl2_name = "table2";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let share_id: u64;
+        let db_id: u64;
+        let table_id: u64;
+
+        info!("--- create share1,db1,table1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await;
+            info!("create share res: {:?}", res);
+            let res = res.unwrap();
+            assert_eq!(1, res.share_id, "first database id is 1");
+            share_id = res.share_id;
+
+            let (share_name_seq, share_name_ret) =
+                get_share_id_to_name_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_name_seq > 0);
+            assert_eq!(share_name, share_name_ret);
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+            db_id = res.db_id;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+
+            let res = mt.create_table(req.clone()).await?;
+            info!("create table res: {:?}", res);
+            table_id = res.table_id;
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db2_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db2_name.to_string(),
+                    table_name: tbl2_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+
+            let res = mt.create_table(req.clone()).await?;
+            info!("create table res: {:?}", res);
+        }
+
+        info!("--- grant unknown db2,table2");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database("unknown_db".to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownDatabase("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(
+                    db_name.to_string(),
+                    "unknown_table".to_string(),
+                ),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- grant unknown share2");
+        {
+            let req = GrantShareObjectReq {
+                share_name: ShareNameIdent {
+                    tenant: tenant.to_string(),
+                    share_name: "share2".to_string(),
+                },
+                object: ShareGrantObjectName::Database("db2".to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- grant table2 on a unbound database share");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db2_name.to_string(), tbl2_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareDatabaseNotGranted("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- grant db object and table object");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let tbl_ob_name =
+                ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: tbl_ob_name.clone(),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+
+            match share_meta.database {
+                Some(entry) => match entry.object {
+                    ShareGrantObject::Database(obj_db_id) => {
+                        assert_eq!(obj_db_id, db_id);
+
+                        assert_eq!(entry.grant_on, create_on);
+                        assert_eq!(
+                            entry.privileges,
+                            BitFlags::from(ShareGrantObjectPrivilege::Usage)
+                        );
+                    }
+                    _ => {
+                        panic!("MUST has database entry!")
+                    }
+                },
+                None => {
+                    panic!("MUST has database entry!")
+                }
+            }
+
+            let object = ShareGrantObject::Table(table_id);
+            if let Some(entry) = share_meta.entries.get(&object.to_string()) {
+                assert_eq!(entry.object, object);
+                assert_eq!(entry.grant_on, create_on);
+                assert_eq!(
+                    entry.privileges,
+                    BitFlags::from(ShareGrantObjectPrivilege::Select)
+                );
+            } else {
+                panic!("MUST has table entry!")
+            }
+        }
+
+        info!("--- grant db2, table2 on another bounded database share");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db2_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareObjectDatabaseMismatch("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db2_name.to_string(), tbl2_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareObjectDatabaseMismatch("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db2_name.to_string(), tbl2_name.to_string()),
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+            };
+
+            let res = mt.revoke_share_object(req).await;
+            info!("revoke object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareObjectDatabaseMismatch("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            // Every granted table still belongs to the granted database.
+            let report = mt.validate_share(share_name.clone()).await?;
+            info!("validate_share report: {:?}", report);
+            assert!(report.is_consistent());
+        }
+
+        info!("--- revoke share of table");
+        {
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+            };
+
+            let res = mt.revoke_share_object(req).await?;
+            info!("revoke object res: {:?}", res);
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+
+            match share_meta.database {
+                Some(entry) => match entry.object {
+                    ShareGrantObject::Database(obj_db_id) => {
+                        assert_eq!(obj_db_id, db_id);
+
+                        assert_eq!(entry.grant_on, create_on);
+                        assert_eq!(
+                            entry.privileges,
+                            BitFlags::from(ShareGrantObjectPrivilege::Usage)
+                        );
+                    }
+                    _ => {
+                        panic!("MUST has database entry!")
+                    }
+                },
+                None => {
+                    panic!("MUST has database entry!")
+                }
+            }
+
+            let object = ShareGrantObject::Table(table_id);
+            assert!(share_meta.entries.get(&object.to_string()).is_none());
+        }
+
+        info!("--- grant share of table again, and revoke the database");
+        {
+            // first grant share table again
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            // assert table share exists
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            let object = ShareGrantObject::Table(table_id);
+            assert!(share_meta.entries.get(&object.to_string()).is_some());
+
+            // then revoke the database
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+            };
+
+            let res = mt.revoke_share_object(req).await?;
+            info!("revoke object res: {:?}", res);
+
+            // assert share_meta.database is none, and share_meta.entries is empty
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_meta.database.is_none());
+            assert!(share_meta.entries.is_empty());
+        }
+
+        info!("--- grant all privileges of table");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                grant_on: create_on,
+                privilege: GrantSharePrivilege::All,
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            // `All` is expanded to the privileges of a table.
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            let object = ShareGrantObject::Table(table_id);
+            match share_meta.entries.get(&object.to_string()) {
+                Some(entry) => {
+                    assert_eq!(
+                        entry.privileges,
+                        BitFlags::from(ShareGrantObjectPrivilege::Select)
+                    );
+                }
+                None => {
+                    panic!("MUST has table entry!")
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_grant_object_to_shares<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+        let shares = ["share1", "share2", "share3"];
+
+        let share_names: Vec<ShareNameIdent> = shares
+            .iter()
+            .map(|share| ShareNameIdent {
+                tenant: tenant.to_string(),
+                share_name: share.to_string(),
+            })
+            .collect();
+        let mut share_ids = vec![];
+        let table_id: u64;
+
+        info!("--- create share1,share2,share3 with db1 granted, and table1");
+        let create_on = Utc::now();
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+
+            let res = mt.create_table(req).await?;
+            info!("create table res: {:?}", res);
+            table_id = res.table_id;
+
+            for share_name in &share_names {
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on,
+                    request_id: None,
+                };
+
+                let res = mt.create_share(req).await?;
+                info!("create share res: {:?}", res);
+                share_ids.push(res.share_id);
+
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object: ShareGrantObjectName::Database(db_name.to_string()),
+                    grant_on: create_on,
+                    privilege: ShareGrantObjectPrivilege::Usage.into(),
+                    protected_databases: vec![],
+                };
+
+                let res = mt.grant_share_object(req).await?;
+                info!("grant object res: {:?}", res);
+            }
+        }
+
+        info!("--- grant table1 to an unknown share grants none of the shares");
+        {
+            let mut names = share_names.clone();
+            names.push(ShareNameIdent {
+                tenant: tenant.to_string(),
+                share_name: "unknown_share".to_string(),
+            });
+            let req = GrantObjectToSharesReq {
+                share_names: names,
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_object_to_shares(req).await;
+            info!("grant object to shares res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            for share_id in &share_ids {
+                let (_share_meta_seq, share_meta) =
+                    get_share_meta_by_id_or_err(mt.as_kv_api(), *share_id, "").await?;
+                assert!(share_meta.entries.is_empty());
+            }
+        }
+
+        info!("--- grant table1 to share1,share2,share3");
+        {
+            let req = GrantObjectToSharesReq {
+                share_names: share_names.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_object_to_shares(req).await?;
+            info!("grant object to shares res: {:?}", res);
+
+            let object = ShareGrantObject::Table(table_id);
+            for share_id in &share_ids {
+                let (_share_meta_seq, share_meta) =
+                    get_share_meta_by_id_or_err(mt.as_kv_api(), *share_id, "").await?;
+                match share_meta.entries.get(&object.to_string()) {
+                    Some(entry) => {
+                        assert_eq!(entry.grant_on, create_on);
+                        assert_eq!(
+                            entry.privileges,
+                            BitFlags::from(ShareGrantObjectPrivilege::Select)
+                        );
+                    }
+                    None => {
+                        panic!("MUST has table entry!")
+                    }
+                }
+            }
+
+            let (_seq, obj_share_ids): (_, Option<ObjectSharedByShareIds>) =
+                get_struct_value(mt.as_kv_api(), &object).await?;
+            let obj_share_ids = obj_share_ids.unwrap().share_ids;
+            assert_eq!(obj_share_ids.len(), 3);
+            for share_id in &share_ids {
+                assert!(obj_share_ids.contains(share_id));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn share_revoke_all_object<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let share_id: u64;
+        let db_id: u64;
+        let table_id: u64;
+
+        info!("--- create share1,db1,table1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await?;
+            share_id = res.share_id;
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            db_id = res.db_id;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+
+            let res = mt.create_table(req).await?;
+            table_id = res.table_id;
+        }
+
+        info!("--- grant all privileges of db1 and table1");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: GrantSharePrivilege::All,
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                grant_on: create_on,
+                privilege: GrantSharePrivilege::All,
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert_eq!(
+                share_meta.database.unwrap().privileges,
+                ShareGrantObjectPrivilege::Usage | ShareGrantObjectPrivilege::ReferenceUsage
+            );
+        }
+
+        info!("--- revoke reference_usage of db1, update_on is stamped by the meta service");
+        {
+            let before = Utc::now();
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                privilege: ShareGrantObjectPrivilege::ReferenceUsage.into(),
+            };
+
+            let res = mt.revoke_share_object(req).await?;
+            info!("revoke object res: {:?}", res);
+            let after = Utc::now();
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            let entry = share_meta.database.unwrap();
+            assert_eq!(
+                entry.privileges,
+                BitFlags::from(ShareGrantObjectPrivilege::Usage)
+            );
+            let update_on = entry.update_on.unwrap();
+            assert!(update_on >= before && update_on <= after);
+        }
+
+        info!("--- revoke all privileges of table1");
+        {
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                privilege: GrantSharePrivilege::All,
+            };
+
+            let res = mt.revoke_share_object(req).await?;
+            info!("revoke object res: {:?}", res);
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_meta.database.is_some());
+            assert!(share_meta.entries.is_empty());
+
+            let (_seq, share_ids): (_, Option<ObjectSharedByShareIds>) =
+                get_struct_value(mt.as_kv_api(), &ShareGrantObject::Table(table_id)).await?;
+            assert!(!share_ids.unwrap().share_ids.contains(&share_id));
+        }
+
+        info!("--- revoke all privileges of db1");
+        {
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                privilege: GrantSharePrivilege::All,
+            };
+
+            let res = mt.revoke_share_object(req).await?;
+            info!("revoke object res: {:?}", res);
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_meta.database.is_none());
+            assert!(share_meta.entries.is_empty());
+
+            let (_seq, share_ids): (_, Option<ObjectSharedByShareIds>) =
+                get_struct_value(mt.as_kv_api(), &ShareGrantObject::Database(db_id)).await?;
+            assert!(!share_ids.unwrap().share_ids.contains(&share_id));
+
+            let (_seq, db_meta): (_, Option<DatabaseMeta>) =
+                get_struct_value(mt.as_kv_api(), &DatabaseId { db_id }).await?;
+            assert!(!db_meta.unwrap().shared_by.contains(&share_id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_purge_grants<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+        let account = "account1";
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        let share_id: u64;
+        let db_id: u64;
+        let table_id: u64;
+
+        info!("--- unknown share");
+        {
+            let res = mt.purge_share_grants(share_name.clone()).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- grant db1, table1 and add account1 to share1");
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on: Utc::now(),
+                request_id: None,
+            };
+            share_id = mt.create_share(req).await?.share_id;
+
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            db_id = mt.create_database(req).await?.db_id;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+            table_id = mt.create_table(req).await?.table_id;
+
+            for (object, privilege) in [
+                (
+                    ShareGrantObjectName::Database(db_name.to_string()),
+                    ShareGrantObjectPrivilege::Usage,
+                ),
+                (
+                    ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                    ShareGrantObjectPrivilege::Select,
+                ),
+            ] {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object,
+                    grant_on: Utc::now(),
+                    privilege: privilege.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+            }
+
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: Utc::now(),
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+        }
+
+        info!("--- purge the grants of share1");
+        {
+            mt.purge_share_grants(share_name.clone()).await?;
+
+            // The share is kept, without objects and accounts.
+            let (_share_meta_seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert!(share_meta.database.is_none());
+            assert!(share_meta.entries.is_empty());
+            assert!(share_meta.accounts.is_empty());
+
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: true,
+            };
+            let res = mt.get_share_grant_objects(req).await?;
+            assert!(res.objects.is_empty());
+
+            let (seq, _meta): (_, Option<ShareAccountMeta>) =
+                get_struct_value(mt.as_kv_api(), &ShareAccountNameIdent {
+                    account: account.to_string(),
+                    share_id,
+                })
+                .await?;
+            assert_eq!(seq, 0);
+
+            for object in [
+                ShareGrantObject::Database(db_id),
+                ShareGrantObject::Table(table_id),
+            ] {
+                let (_seq, share_ids): (_, Option<ObjectSharedByShareIds>) =
+                    get_struct_value(mt.as_kv_api(), &object).await?;
+                assert!(!share_ids.unwrap().share_ids.contains(&share_id));
+            }
+
+            let (_seq, db_meta): (_, Option<DatabaseMeta>) =
+                get_struct_value(mt.as_kv_api(), &DatabaseId { db_id }).await?;
+            assert!(!db_meta.unwrap().shared_by.contains(&share_id));
+        }
+
+        info!("--- share1 can be granted again");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: Utc::now(),
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_grant_dropped_object<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let db1 = "db1";
+        let db2 = "db2";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let share_id: u64;
+        let mut db_ids = vec![];
+
+        info!("--- create share1,db1,db2");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await?;
+            info!("create share res: {:?}", res);
+            share_id = res.share_id;
+
+            for db_name in [db1, db2] {
+                let plan = CreateDatabaseReq {
+                    if_not_exists: false,
+                    name_ident: DatabaseNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                    },
+                    meta: DatabaseMeta::default(),
+                };
+
+                let res = mt.create_database(plan).await?;
+                info!("create database res: {:?}", res);
+                db_ids.push(res.db_id);
+            }
+        }
+
+        info!("--- grant a dropped db1 fails");
+        {
+            let req = DropDatabaseReq {
+                if_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db1.to_string(),
+                },
+            };
+            mt.drop_database(req).await?;
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db1.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownDatabase("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- grant db2 whose meta is marked dropped fails");
+        {
+            // The name of a dropped database is removed along with the drop,
+            // mark the meta dropped alone to reach the check behind the name.
+            let id_key = DatabaseId { db_id: db_ids[1] };
+            let (_seq, db_meta): (_, Option<DatabaseMeta>) =
+                get_struct_value(mt.as_kv_api(), &id_key).await?;
+            let mut db_meta = db_meta.unwrap();
+            db_meta.drop_on = Some(Utc::now());
+            mt.as_kv_api()
+                .upsert_kv(UpsertKVReq::new(
+                    &id_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Update(serialize_struct(&db_meta)?),
+                    None,
+                ))
+                .await?;
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db2.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::CannotShareDroppedObject("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let (_share_meta_seq, share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            assert!(share_meta.database.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_txn_retry_conflict<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        let share_id: u64;
+
+        info!("--- create share1 with db1 granted, and table1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await?;
+            info!("create share res: {:?}", res);
+            share_id = res.share_id;
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+
+            let res = mt.create_table(req).await?;
+            info!("create table res: {:?}", res);
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+        }
+
+        let share_meta_key = ShareId { share_id }.to_key();
+        let contended = ContendedKVApi {
+            inner: mt.as_kv_api(),
+            key: share_meta_key.clone(),
+        };
+
+        info!("--- grant table1 with the share meta contended names the share meta key");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = contended.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TxnRetryMaxTimes("").code(), err.code());
+            assert!(err.message().contains(&share_meta_key));
+        }
+
+        info!("--- revoke db1 with the share meta contended names the share meta key");
+        {
+            let req = RevokeShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+            };
+
+            let res = contended.revoke_share_object(req).await;
+            info!("revoke object res: {:?}", res);
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TxnRetryMaxTimes("").code(), err.code());
+            assert!(err.message().contains(&share_meta_key));
+        }
+
+        info!("--- add account with the share meta contended names the share meta key");
+        {
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                if_exists: false,
+                accounts: vec!["account1".to_string()],
+                share_on: create_on,
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+
+            let res = contended.add_share_tenants(req).await;
+            info!("add share account res: {:?}", res);
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TxnRetryMaxTimes("").code(), err.code());
+            assert!(err.message().contains(&share_meta_key));
+        }
+
+        Ok(())
+    }
+
+    async fn share_grant_table_of_dropped_database<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db1 = "db1";
+        let tbl1 = "table1";
+
+        let db_name_key = DatabaseNameIdent {
+            tenant: tenant.to_string(),
+            db_name: db1.to_string(),
+        };
+        let db_id: u64;
+
+        info!("--- create db1");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: db_name_key.clone(),
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+            db_id = res.db_id;
+        }
+
+        info!("--- resolve table1 after db1 is dropped reports the database");
+        {
+            // Drop db1 between resolving the database and the table,
+            // the step after the database lookup is run directly.
+            let req = DropDatabaseReq {
+                if_exists: false,
+                name_ident: db_name_key.clone(),
+            };
+            mt.drop_database(req).await?;
+
+            let obj_name = ShareGrantObjectName::Table(db1.to_string(), tbl1.to_string());
+            let res =
+                get_share_table_seq_and_id(mt.as_kv_api(), &db_name_key, db_id, tbl1, &obj_name)
+                    .await;
+            info!("resolve table res: {:?}", res);
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::UnknownDatabase("").code(), err.code());
+            assert!(err.message().contains(db1));
+        }
+
+        Ok(())
+    }
+
+    async fn share_grant_view<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let db_name = "db1";
+        let view_name = "view1";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+
+        info!("--- create share1,db1,view1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await?;
+            info!("create share res: {:?}", res);
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: view_name.to_string(),
+                },
+                table_meta: TableMeta {
+                    engine: "VIEW".to_string(),
+                    ..TableMeta::default()
+                },
+            };
+
+            let res = mt.create_table(req).await?;
+            info!("create table res: {:?}", res);
+        }
+
+        info!("--- grant view1 fails");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), view_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await;
+            info!("grant object res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::CannotShareView("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_grant_protected_database<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+        let protected_databases = vec![db_name.to_string()];
+
+        let share_name1 = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        let share_name2 = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share2".to_string(),
+        };
+
+        info!("--- create share1,share2,db1,table1");
+        let create_on = Utc::now();
+        {
+            for share_name in [&share_name1, &share_name2] {
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on,
+                    request_id: None,
+                };
+                let res = mt.create_share(req).await?;
+                info!("create share res: {:?}", res);
+            }
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+            let res = mt.create_table(req).await?;
+            info!("create table res: {:?}", res);
+        }
+
+        info!("--- grant the objects of the protected db1 fails");
+        {
+            for (object, privilege) in [
+                (
+                    ShareGrantObjectName::Database(db_name.to_string()),
+                    ShareGrantObjectPrivilege::Usage,
+                ),
+                (
+                    ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                    ShareGrantObjectPrivilege::Select,
+                ),
+            ] {
+                let req = GrantShareObjectReq {
+                    share_name: share_name1.clone(),
+                    object: object.clone(),
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: protected_databases.clone(),
+                };
+                let err = mt.grant_share_object(req).await.unwrap_err();
+                assert_eq!(
+                    ErrorCode::CannotShareProtectedObject("").code(),
+                    ErrorCode::from(err).code()
+                );
+
+                let req = GrantObjectToSharesReq {
+                    share_names: vec![share_name1.clone(), share_name2.clone()],
+                    object,
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: protected_databases.clone(),
+                };
+                let err = mt.grant_object_to_shares(req).await.unwrap_err();
+                assert_eq!(
+                    ErrorCode::CannotShareProtectedObject("").code(),
+                    ErrorCode::from(err).code()
+                );
+            }
+
+            let res = mt
+                .get_share_grant_objects(GetShareGrantObjectReq {
+                    share_name: share_name1.clone(),
+                    include_dropped: false,
+                })
+                .await?;
+            assert!(res.objects.is_empty());
+        }
+
+        info!("--- clone share1 fails once db1 becomes protected");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name1.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+
+            let req = CloneShareReq {
+                if_not_exists: false,
+                source: share_name1.clone(),
+                target_name: "share3".to_string(),
+                with_accounts: false,
+                create_on,
+                protected_databases: protected_databases.clone(),
+            };
+            let err = mt.clone_share(req.clone()).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::CannotShareProtectedObject("").code(),
+                ErrorCode::from(err).code()
+            );
+
+            let res = mt
+                .clone_share(CloneShareReq {
+                    protected_databases: vec![],
+                    ..req
+                })
+                .await?;
+            info!("clone share res: {:?}", res);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_database_rename<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let new_db_name = "db2";
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+
+        info!("--- create share1,db1 and grant db1 to share1");
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on: Utc::now(),
+                request_id: None,
+            };
+            mt.create_share(req).await?;
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: Utc::now(),
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+
+            let (_seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert!(!share_meta.needs_refresh);
+        }
+
+        info!("--- rename db1 marks share1 to be refreshed");
+        {
+            let req = RenameDatabaseReq {
+                if_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                new_db_name: new_db_name.to_string(),
+            };
+            mt.rename_database(req).await?;
+
+            let (_seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert!(share_meta.needs_refresh);
+
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: false,
+            };
+            let res = mt.get_share_grant_objects(req).await?;
+            assert_eq!(
+                res.objects[0].object,
+                ShareGrantObjectName::Database(new_db_name.to_string())
+            );
+        }
+
+        info!("--- rename db2 skips the shares whose meta is gone");
+        {
+            let db_info = mt
+                .get_database(GetDatabaseReq::new(tenant, new_db_name))
+                .await?;
+            let share_id = *db_info.meta.shared_by.iter().next().unwrap();
+            let id_key = ShareId { share_id };
+            mt.as_kv_api()
+                .upsert_kv(UpsertKVReq::new(
+                    &id_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+
+            let req = RenameDatabaseReq {
+                if_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: new_db_name.to_string(),
+                },
+                new_db_name: "db3".to_string(),
+            };
+            mt.rename_database(req).await?;
+
+            let res = mt.get_database(GetDatabaseReq::new(tenant, "db3")).await;
+            assert!(res.is_ok());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_grant_invalid_privilege<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        let db_object = ShareGrantObjectName::Database(db_name.to_string());
+        let tbl_object = ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
+
+        info!("--- valid privileges of each kind of object");
+        {
+            assert_eq!(
+                valid_privileges_for(ShareGrantObjectKind::Database),
+                ShareGrantObjectPrivilege::Usage | ShareGrantObjectPrivilege::ReferenceUsage
+            );
+            assert_eq!(
+                valid_privileges_for(ShareGrantObjectKind::Table),
+                BitFlags::from(ShareGrantObjectPrivilege::Select)
+            );
+        }
+
+        info!("--- create share1,db1,table1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+            mt.create_share(req).await?;
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+            mt.create_table(req).await?;
+
+            // A table can only be granted once its database is granted.
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: db_object.clone(),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+        }
+
+        info!("--- grant the privileges invalid for the kind of the object");
+        {
+            for (object, privilege) in [
+                (&db_object, ShareGrantObjectPrivilege::Select),
+                (&tbl_object, ShareGrantObjectPrivilege::Usage),
+                (&tbl_object, ShareGrantObjectPrivilege::ReferenceUsage),
+            ] {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object: object.clone(),
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: vec![],
+                };
+
+                let res = mt.grant_share_object(req).await;
+                info!("grant object res: {:?}", res);
+                let err = res.unwrap_err();
+                assert_eq!(
+                    ErrorCode::InvalidSharePrivilege("").code(),
+                    ErrorCode::from(err).code()
+                );
+            }
+        }
+
+        info!("--- grant the privileges valid for the kind of the object");
+        {
+            for (object, privilege) in [
+                (&db_object, ShareGrantObjectPrivilege::ReferenceUsage.into()),
+                (&db_object, GrantSharePrivilege::All),
+                (&tbl_object, ShareGrantObjectPrivilege::Select.into()),
+                (&tbl_object, GrantSharePrivilege::All),
+            ] {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object: object.clone(),
+                    grant_on: create_on,
+                    privilege,
+                    protected_databases: vec![],
+                };
+
+                let res = mt.grant_share_object(req).await;
+                info!("grant object res: {:?}", res);
+                assert!(res.is_ok());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn share_clone<MT: ShareApi + AsKVApi + SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let share2 = "share2";
+        let account = "account1";
+        let db_name = "db1";
+        let tbl_names = ["table1", "table2", "table3"];
+
+        let share_name1 = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let share_name2 = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share2.to_string(),
+        };
+        let share_id1: u64;
+        let db_id: u64;
+        let mut table_ids = vec![];
+
+        info!("--- create share1 granting db1,table1,table2,table3 to account1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name1.clone(),
+                comment: Some("share comment".to_string()),
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await?;
+            share_id1 = res.share_id;
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            db_id = res.db_id;
+
+            let req = GrantShareObjectReq {
+                share_name: share_name1.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: TableMeta::default(),
+                };
+
+                let res = mt.create_table(req).await?;
+                table_ids.push(res.table_id);
+
+                let req = GrantShareObjectReq {
+                    share_name: share_name1.clone(),
+                    object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                    grant_on: create_on,
+                    privilege: ShareGrantObjectPrivilege::Select.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+            }
+
+            let req = AddShareAccountsReq {
+                share_name: share_name1.clone(),
+                share_on: create_on,
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+
+            // table3 no longer resolves.
+            let req = DropTableReq {
+                if_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_names[2].to_string(),
+                },
+            };
+            mt.drop_table(req).await?;
+        }
+
+        info!("--- clone share1 to share2");
+        let share_id2 = {
+            let req = CloneShareReq {
+                if_not_exists: false,
+                source: share_name1.clone(),
+                target_name: share2.to_string(),
+                with_accounts: true,
+                create_on,
+                protected_databases: vec![],
+            };
+
+            let res = mt.clone_share(req.clone()).await?;
+            info!("clone share res: {:?}", res);
+            assert_ne!(res.share_id, share_id1);
+
+            // A clone to an existing share fails unless if_not_exists.
+            let err = mt.clone_share(req.clone()).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareAlreadyExists("").code(),
+                ErrorCode::from(err).code()
+            );
+            let res2 = mt
+                .clone_share(CloneShareReq {
+                    if_not_exists: true,
+                    ..req
+                })
+                .await?;
+            assert_eq!(res2.share_id, res.share_id);
+
+            res.share_id
+        };
+
+        info!("--- share2 has the grants of share1 except the dropped table");
+        {
+            let (share_name_seq, share_name_ret) =
+                get_share_id_to_name_or_err(mt.as_kv_api(), share_id2, "").await?;
+            assert!(share_name_seq > 0);
+            assert_eq!(share_name2, share_name_ret);
+
+            let (_share_meta_seq, share_meta1) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id1, "").await?;
+            let (_share_meta_seq, share_meta2) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id2, "").await?;
+            assert_eq!(share_meta2.comment, share_meta1.comment);
+            assert_eq!(share_meta2.get_accounts(), vec![account.to_string()]);
+            assert_eq!(
+                share_meta2.database.as_ref().unwrap().object,
+                ShareGrantObject::Database(db_id)
+            );
+            assert_eq!(
+                share_meta2.database.as_ref().unwrap().privileges,
+                BitFlags::from(ShareGrantObjectPrivilege::Usage)
+            );
+            assert_eq!(share_meta2.entries.len(), 2);
+            for table_id in &table_ids[..2] {
+                let entry = share_meta2
+                    .entries
+                    .get(&ShareGrantObject::Table(*table_id).to_string())
+                    .unwrap();
+                assert_eq!(
+                    entry.privileges,
+                    BitFlags::from(ShareGrantObjectPrivilege::Select)
+                );
+            }
+
+            let mut objects = vec![ShareGrantObject::Database(db_id)];
+            objects.extend(table_ids[..2].iter().map(|id| ShareGrantObject::Table(*id)));
+            for object in objects {
+                let (_seq, share_ids): (_, Option<ObjectSharedByShareIds>) =
+                    get_struct_value(mt.as_kv_api(), &object).await?;
+                let share_ids = share_ids.unwrap().share_ids;
+                assert!(share_ids.contains(&share_id1));
+                assert!(share_ids.contains(&share_id2));
+            }
+
+            let (_seq, db_meta): (_, Option<DatabaseMeta>) =
+                get_struct_value(mt.as_kv_api(), &DatabaseId { db_id }).await?;
+            let shared_by = db_meta.unwrap().shared_by;
+            assert!(shared_by.contains(&share_id1));
+            assert!(shared_by.contains(&share_id2));
+
+            let share_account_name = ShareAccountNameIdent {
+                account: account.to_string(),
+                share_id: share_id2,
+            };
+            let (_seq, share_account_meta) =
+                get_share_account_meta_or_err(mt.as_kv_api(), &share_account_name, "").await?;
+            assert_eq!(share_account_meta.share_id, share_id2);
+        }
+
+        info!("--- the grants of share2 are independent of share1");
+        {
+            let req = RevokeShareObjectReq {
+                share_name: share_name2.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_names[0].to_string()),
+                privilege: GrantSharePrivilege::All,
+            };
+            mt.revoke_share_object(req).await?;
+
+            let (_share_meta_seq, share_meta1) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id1, "").await?;
+            let (_share_meta_seq, share_meta2) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id2, "").await?;
+            let key = ShareGrantObject::Table(table_ids[0]).to_string();
+            assert!(share_meta1.entries.contains_key(&key));
+            assert!(!share_meta2.entries.contains_key(&key));
+        }
+
+        info!("--- clone a share whose entries hold a database fails");
+        {
+            let id_key = ShareId {
+                share_id: share_id1,
+            };
+            let (_seq, mut share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id1, "").await?;
+            let object = ShareGrantObject::Database(db_id);
+            share_meta.entries.insert(
+                object.to_string(),
+                ShareGrantEntry::new(object, ShareGrantObjectPrivilege::Usage, create_on),
+            );
+            mt.as_kv_api()
+                .upsert_kv(UpsertKVReq::new(
+                    &id_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Update(serialize_struct(&share_meta)?),
+                    None,
+                ))
+                .await?;
+
+            let req = CloneShareReq {
+                if_not_exists: false,
+                source: share_name1.clone(),
+                target_name: "share3".to_string(),
+                with_accounts: false,
+                create_on,
+                protected_databases: vec![],
+            };
+            let err = mt.clone_share(req).await.unwrap_err();
+            assert_eq!(
+                ErrorCode::WrongShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn get_share_grant_objects<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+
+        info!("--- get unknown share");
+        {
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: false,
+            };
+
+            let res = mt.get_share_grant_objects(req).await;
+            info!("get_share_grant_objects res: {:?}", res);
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- create share1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+
+            let res = mt.create_share(req).await;
+            info!("create share res: {:?}", res);
+            let res = res.unwrap();
+            assert_eq!(1, res.share_id, "first database id is 1");
+        }
+
+        info!("--- get share");
+        {
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: false,
+            };
+
+            let res = mt.get_share_grant_objects(req).await;
+            info!("get_share_grant_objects res: {:?}", res);
+            let res = res.unwrap();
+            assert!(res.objects.is_empty());
+        }
+
+        info!("--- create db1,table1");
+        let db_id: u64;
+        let table_id: u64;
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+
+            let res = mt.create_database(plan).await?;
+            info!("create database res: {:?}", res);
+            db_id = res.db_id;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+
+            let res = mt.create_table(req.clone()).await?;
+            info!("create table res: {:?}", res);
+            table_id = res.table_id;
+        }
+
+        info!("--- share db1 and table1");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let tbl_ob_name =
+                ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: tbl_ob_name.clone(),
+                grant_on: create_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+        }
+
+        info!("--- get all share objects");
+        {
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: false,
+            };
+
+            let res = mt.get_share_grant_objects(req).await;
+            info!("get_share_grant_objects res: {:?}", res);
+            let res = res.unwrap();
+            assert_eq!(res.objects.len(), 2);
+            assert!(res
+                .objects
+                .iter()
+                .all(|object| object.status == ShareGrantObjectStatus::Granted));
+        }
+
+        info!("--- grant reference_usage of db1 later, each privilege keeps its grant time");
+        {
+            let grant_on = create_on + Duration::seconds(1);
+            let req = GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on,
+                privilege: ShareGrantObjectPrivilege::ReferenceUsage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: false,
+            };
+
+            let res = mt.get_share_grant_objects(req).await?;
+            info!("get_share_grant_objects res: {:?}", res);
+            let database = res
+                .objects
+                .iter()
+                .find(|object| object.object == ShareGrantObjectName::Database(db_name.to_string()))
+                .unwrap();
+            assert_eq!(
+                database.privileges,
+                ShareGrantObjectPrivilege::Usage | ShareGrantObjectPrivilege::ReferenceUsage
+            );
+            assert_eq!(database.privilege_names(), vec![
+                "USAGE".to_string(),
+                "REFERENCE_USAGE".to_string()
+            ]);
+            assert_eq!(
+                database.privilege_grant_on,
+                BTreeMap::from([
+                    (ShareGrantObjectPrivilege::Usage, create_on),
+                    (ShareGrantObjectPrivilege::ReferenceUsage, grant_on),
+                ])
+            );
+
+            // The privileges are named in the JSON of the reply.
+            let json = serde_json::to_value(&res)?;
+            let database_json = json["objects"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|object| object["object"]["Database"] == db_name)
+                .unwrap();
+            assert_eq!(
+                database_json["privileges"],
+                serde_json::json!(["USAGE", "REFERENCE_USAGE"])
+            );
+            let decoded: GetShareGrantObjectReply = serde_json::from_value(json)?;
+            assert_eq!(decoded, res);
+        }
+
+        info!("--- resolve object names in a batch");
+        {
+            let database_name = db_name.to_string();
+            let database_name = Some(&database_name);
+            let objects = vec![
+                ShareGrantObject::Database(db_id),
+                ShareGrantObject::Table(table_id),
+                ShareGrantObject::Database(db_id + 1000),
+                ShareGrantObject::Table(table_id + 1000),
+            ];
+
+            let names = resolve_object_names(mt.as_kv_api(), &database_name, &objects).await?;
+            let mut expected = vec![];
+            for object in objects {
+                expected
+                    .push(get_object_name_from_id(mt.as_kv_api(), &database_name, object).await?);
+            }
+            assert_eq!(names, expected);
+            assert_eq!(names, vec![
+                Some(ShareGrantObjectName::Database(db_name.to_string())),
+                Some(ShareGrantObjectName::Table(
+                    db_name.to_string(),
+                    tbl_name.to_string()
+                )),
+                None,
+                None,
+            ]);
+
+            // A table can not be named without the name of the shared database.
+            let res =
+                get_object_name_from_id(mt.as_kv_api(), &None, ShareGrantObject::Table(table_id))
+                    .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::ShareDatabaseNotGranted("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- drop table1");
+        {
+            let req = DropTableReq {
+                if_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+            };
+
+            let res = mt.drop_table(req).await?;
+            info!("drop table res: {:?}", res);
+        }
+
+        info!("--- get share objects omits the dropped table");
+        {
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: false,
+            };
+
+            let res = mt.get_share_grant_objects(req).await?;
+            info!("get_share_grant_objects res: {:?}", res);
+            assert_eq!(res.objects.len(), 1);
+            assert_eq!(
+                res.objects[0].object,
+                ShareGrantObjectName::Database(db_name.to_string())
+            );
+        }
+
+        info!("--- get share objects with the dropped table");
+        {
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: true,
+            };
+
+            let res = mt.get_share_grant_objects(req).await?;
+            info!("get_share_grant_objects res: {:?}", res);
+            assert_eq!(res.objects.len(), 2);
+
+            let table = res
+                .objects
+                .iter()
+                .find(|object| {
+                    object.object
+                        == ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string())
+                })
+                .unwrap();
+            assert_eq!(table.status, ShareGrantObjectStatus::Dropped);
+            assert_eq!(table.status.to_string(), "DROPPED");
+
+            let database = res
+                .objects
+                .iter()
+                .find(|object| object.object == ShareGrantObjectName::Database(db_name.to_string()))
+                .unwrap();
+            assert_eq!(database.status, ShareGrantObjectStatus::Granted);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn get_share_object_privilege<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+        let tbl2_name = "table2";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+
+        info!("--- create share1, db1, table1 and table2");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+            mt.create_share(req).await?;
+
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(req).await?;
+
+            for table_name in [tbl_name, tbl2_name] {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: table_name.to_string(),
+                    },
+                    table_meta: TableMeta::default(),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let db_object = ShareGrantObjectName::Database(db_name.to_string());
+        let tbl_object = ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
+        let tbl2_object = ShareGrantObjectName::Table(db_name.to_string(), tbl2_name.to_string());
+
+        info!("--- nothing is granted to share1");
+        {
+            let res = mt
+                .get_share_object_privilege(share_name.clone(), db_object.clone())
+                .await?;
+            assert_eq!(res, None);
+        }
+
+        info!("--- grant db1 and table1 to share1");
+        {
+            for (object, privilege) in [
+                (db_object.clone(), ShareGrantObjectPrivilege::Usage),
+                (tbl_object.clone(), ShareGrantObjectPrivilege::Select),
+            ] {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object,
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+            }
+        }
+
+        info!("--- the point query matches the entry of the full list");
+        {
+            let req = GetShareGrantObjectReq {
+                share_name: share_name.clone(),
+                include_dropped: false,
+            };
+            let objects = mt.get_share_grant_objects(req).await?.objects;
+            assert_eq!(objects.len(), 2);
+
+            for object in [&db_object, &tbl_object] {
+                let res = mt
+                    .get_share_object_privilege(share_name.clone(), object.clone())
+                    .await?;
+                info!("get_share_object_privilege res: {:?}", res);
+                let expected = objects.iter().find(|entry| &entry.object == object);
+                assert!(expected.is_some());
+                assert_eq!(res.as_ref(), expected);
+            }
+        }
+
+        info!("--- table2 is not granted to share1");
+        {
+            let res = mt
+                .get_share_object_privilege(share_name.clone(), tbl2_object)
+                .await?;
+            assert_eq!(res, None);
+        }
+
+        info!("--- unknown table");
+        {
+            let object =
+                ShareGrantObjectName::Table(db_name.to_string(), "unknown_table".to_string());
+            let res = mt
+                .get_share_object_privilege(share_name.clone(), object)
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn validate_share<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let account = "account1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let share_id: u64;
+        let db_id: u64;
+        let table_id: u64;
+
+        info!("--- create share1, db1 and table1");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on,
+                request_id: None,
+            };
+            share_id = mt.create_share(req).await?.share_id;
+
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            db_id = mt.create_database(req).await?.db_id;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+            table_id = mt.create_table(req).await?.table_id;
+        }
+
+        info!("--- share db1 and table1 with account1");
+        {
+            for (object, privilege) in [
+                (
+                    ShareGrantObjectName::Database(db_name.to_string()),
+                    ShareGrantObjectPrivilege::Usage,
+                ),
+                (
+                    ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                    ShareGrantObjectPrivilege::Select,
+                ),
+            ] {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object,
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+            }
+
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: create_on,
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+
+            let report = mt.validate_share(share_name.clone()).await?;
+            info!("validate_share report: {:?}", report);
+            assert_eq!(report.share_id, share_id);
+            assert!(report.is_consistent());
+        }
+
+        info!("--- break each key of share1");
+        {
+            let kv_api = mt.as_kv_api();
+            let id_name_key = ShareIdToName { share_id };
+            kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &id_name_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+
+            let share_account_key = ShareAccountNameIdent {
+                account: account.to_string(),
+                share_id,
+            };
+            kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &share_account_key.to_key(),
+                    MatchSeq::Any,
+                    Operation::Delete,
+                    None,
+                ))
+                .await?;
+
+            let req = DropTableReq {
+                if_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+            };
+            mt.drop_table(req).await?;
+
+            let db_object = ShareGrantObject::Database(db_id);
+            kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &db_object.to_key(),
+                    MatchSeq::Any,
+                    Operation::Update(serialize_struct(&ObjectSharedByShareIds::new())?),
+                    None,
+                ))
+                .await?;
+        }
+
+        info!("--- validate share1 reports each inconsistency");
+        {
+            let report = mt.validate_share(share_name.clone()).await?;
+            info!("validate_share report: {:?}", report);
+            assert!(!report.is_consistent());
+            assert_eq!(report.inconsistencies, vec![
+                ShareInconsistency::IdToNameMismatch { found: None },
+                ShareInconsistency::MissingAccountMeta {
+                    account: account.to_string()
+                },
+                ShareInconsistency::ObjectNotSharedByShare {
+                    object: ShareGrantObject::Database(db_id)
+                },
+                ShareInconsistency::UnresolvedObject {
+                    object: ShareGrantObject::Table(table_id)
+                },
+            ]);
+        }
+
+        info!("--- validate share1 reports a table entry of another database");
+        {
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: "db2".to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            let db2_id = mt.create_database(req).await?.db_id;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: "db2".to_string(),
+                    table_name: "table2".to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+            let table2_id = mt.create_table(req).await?.table_id;
+
+            // Grant table2 of db2 behind the back of check_share_object.
+            let (_share_meta_seq, mut share_meta) =
+                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
+            share_meta.grant_object_privileges(
+                ShareGrantObject::Table(table2_id),
+                BitFlags::from(ShareGrantObjectPrivilege::Select),
+                create_on,
+            );
+            mt.as_kv_api()
+                .upsert_kv(UpsertKVReq::new(
+                    &ShareId { share_id }.to_key(),
+                    MatchSeq::Any,
+                    Operation::Update(serialize_struct(&share_meta)?),
+                    None,
+                ))
+                .await?;
+
+            let report = mt.validate_share(share_name.clone()).await?;
+            info!("validate_share report: {:?}", report);
+            assert!(report.inconsistencies.contains(
+                &ShareInconsistency::TableOutsideSharedDatabase {
+                    object: ShareGrantObject::Table(table2_id),
+                    db_id: db2_id,
+                }
+            ));
+            // The dropped table1 still belongs to db1.
+            assert!(!report.inconsistencies.contains(
+                &ShareInconsistency::TableOutsideSharedDatabase {
+                    object: ShareGrantObject::Table(table_id),
+                    db_id,
+                }
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn get_share_meta_raw<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let share1 = "share1";
+        let account = "account1";
+        let db_name = "db1";
+        let tbl_names = ["table1", "table2"];
+
+        let share_name = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share1.to_string(),
+        };
+        let db_id: u64;
+        let mut table_ids = vec![];
+
+        info!("--- get_share_meta_raw of an unknown share");
+        {
+            let res = mt.get_share_meta_raw(share_name.clone()).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- create share1, db1 and its tables");
+        let create_on = Utc::now();
+        {
+            let req = CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: Some("comment".to_string()),
+                create_on,
+                request_id: None,
+            };
+            mt.create_share(req).await?;
+
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            db_id = mt.create_database(req).await?.db_id;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: TableMeta::default(),
+                };
+                table_ids.push(mt.create_table(req).await?.table_id);
+            }
+        }
+
+        info!("--- grant db1 and its tables to share1");
+        let mut grants = 0;
+        {
+            let mut objects = vec![ShareGrantObjectName::Database(db_name.to_string())];
+            for tbl_name in tbl_names {
+                objects.push(ShareGrantObjectName::Table(
+                    db_name.to_string(),
+                    tbl_name.to_string(),
+                ));
+            }
+
+            for object in objects {
+                let privilege = match object {
+                    ShareGrantObjectName::Database(_) => ShareGrantObjectPrivilege::Usage,
+                    ShareGrantObjectName::Table(_, _) => ShareGrantObjectPrivilege::Select,
+                };
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object,
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+                grants += 1;
+            }
+
+            let req = AddShareAccountsReq {
+                share_name: share_name.clone(),
+                share_on: create_on,
+                if_exists: false,
+                accounts: vec![account.to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+            };
+            mt.add_share_tenants(req).await?;
+        }
+
+        info!("--- drop table1, get_share_meta_raw still returns its id");
+        {
+            let req = DropTableReq {
+                if_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_names[0].to_string(),
+                },
+            };
+            mt.drop_table(req).await?;
+
+            let (seq, share_meta) = mt.get_share_meta_raw(share_name.clone()).await?;
+            assert!(seq > 0);
+
+            let database = share_meta.database.as_ref().unwrap();
+            assert_eq!(database.object, ShareGrantObject::Database(db_id));
+            assert_eq!(
+                share_meta.entries.len() + share_meta.database.iter().count(),
+                grants
+            );
+            for table_id in &table_ids {
+                let object = ShareGrantObject::Table(*table_id);
+                assert!(share_meta
+                    .entries
+                    .values()
+                    .any(|entry| entry.object == object));
+            }
+
+            assert_eq!(share_meta.accounts.len(), 1);
+            assert!(share_meta.accounts.contains(account));
+            assert_eq!(share_meta.comment, Some("comment".to_string()));
+            assert!(share_meta.update_on.is_some());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn list_outbound_shares<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let share_names = ["share2", "share1"].map(|share_name| ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: share_name.to_string(),
+        });
+
+        info!("--- a tenant without shares lists none");
+        {
+            let shares = mt.list_outbound_shares(tenant.to_string()).await?;
+            assert!(shares.is_empty());
+        }
+
+        info!("--- create share1,share2 and grant db1 to share1");
+        {
+            for share_name in &share_names {
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on: Utc::now(),
+                    request_id: None,
+                };
+                mt.create_share(req).await?;
+            }
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let req = GrantShareObjectReq {
+                share_name: share_names[1].clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on: Utc::now(),
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
+            };
+            mt.grant_share_object(req).await?;
+        }
+
+        info!("--- the shares are listed by name with their metas");
+        {
+            let shares = mt.list_outbound_shares(tenant.to_string()).await?;
+            assert_eq!(
+                shares
+                    .iter()
+                    .map(|(reply, _share_meta)| reply.share_name.share_name.as_str())
+                    .collect::<Vec<_>>(),
+                vec!["share1", "share2"]
+            );
+
+            let (reply, share_meta) = &shares[0];
+            assert_eq!(reply.database_name, Some(db_name.to_string()));
+            assert!(share_meta.database.is_some());
+            assert_eq!(
+                *share_meta,
+                mt.get_share_meta_raw(share_names[1].clone()).await?.1
+            );
+
+            let (reply, share_meta) = &shares[1];
+            assert_eq!(reply.database_name, None);
+            assert!(share_meta.database.is_none());
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn diff_share_grants<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["table1", "table2", "table3", "table4"];
+
+        let share_name_a = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share_a".to_string(),
+        };
+        let share_name_b = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share_b".to_string(),
+        };
+        let mut table_ids = vec![];
+
+        info!("--- create share_a, share_b, db1 and its tables");
+        let create_on = Utc::now();
+        {
+            for share_name in [&share_name_a, &share_name_b] {
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on,
+                    request_id: None,
+                };
+                mt.create_share(req).await?;
+            }
+
+            let req = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(req).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: TableMeta::default(),
+                };
+                table_ids.push(mt.create_table(req).await?.table_id);
+            }
+        }
+
+        info!(
+            "--- grant db1, table1, table2 to share_a and db1, table2, table3, table4 to share_b"
+        );
+        {
+            let grants = [
+                (&share_name_a, vec![tbl_names[0], tbl_names[1]]),
+                (&share_name_b, vec![
+                    tbl_names[1],
+                    tbl_names[2],
+                    tbl_names[3],
+                ]),
+            ];
+            for (share_name, tables) in grants {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object: ShareGrantObjectName::Database(db_name.to_string()),
+                    grant_on: create_on,
+                    privilege: ShareGrantObjectPrivilege::Usage.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+
+                for tbl_name in tables {
+                    let req = GrantShareObjectReq {
+                        share_name: share_name.clone(),
+                        object: ShareGrantObjectName::Table(
+                            db_name.to_string(),
+                            tbl_name.to_string(),
+                        ),
+                        grant_on: create_on,
+                        privilege: ShareGrantObjectPrivilege::Select.into(),
+                        protected_databases: vec![],
+                    };
+                    mt.grant_share_object(req).await?;
+                }
+            }
+        }
+
+        info!("--- drop table4, its grant of share_b no longer resolves");
+        {
+            let req = DropTableReq {
+                if_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_names[3].to_string(),
+                },
             };
+            mt.drop_table(req).await?;
+        }
 
-            let res = mt.grant_share_object(req).await?;
-            info!("grant object res: {:?}", res);
+        info!("--- diff share_a and share_b");
+        {
+            let diff = mt
+                .diff_share_grants(share_name_a.clone(), share_name_b.clone())
+                .await?;
+
+            assert_eq!(diff.only_in_a, vec![ShareGrantPrivileges {
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_names[0].to_string()),
+                privileges: BitFlags::from(ShareGrantObjectPrivilege::Select),
+            }]);
+            assert_eq!(diff.only_in_b, vec![ShareGrantPrivileges {
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_names[2].to_string()),
+                privileges: BitFlags::from(ShareGrantObjectPrivilege::Select),
+            }]);
+            assert_eq!(diff.unresolved, vec![ShareUnresolvedGrant {
+                share_name: share_name_b.clone(),
+                object: ShareGrantObject::Table(table_ids[3]),
+            }]);
+        }
 
-            // assert table share exists
-            let (_share_meta_seq, share_meta) =
-                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
-            let object = ShareGrantObject::Table(table_id);
-            assert!(share_meta.entries.get(&object.to_string()).is_some());
+        info!("--- a share has no difference with itself");
+        {
+            let diff = mt
+                .diff_share_grants(share_name_a.clone(), share_name_a.clone())
+                .await?;
+            assert!(diff.only_in_a.is_empty());
+            assert!(diff.only_in_b.is_empty());
+            assert!(diff.unresolved.is_empty());
+        }
 
-            // then revoke the database
-            let req = RevokeShareObjectReq {
-                share_name: share_name.clone(),
-                object: ShareGrantObjectName::Database(db_name.to_string()),
-                update_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+        info!("--- diff with an unknown share");
+        {
+            let share_name = ShareNameIdent {
+                tenant: tenant.to_string(),
+                share_name: "share_c".to_string(),
             };
-
-            let res = mt.revoke_share_object(req).await?;
-            info!("revoke object res: {:?}", res);
-
-            // assert share_meta.database is none, and share_meta.entries is empty
-            let (_share_meta_seq, share_meta) =
-                get_share_meta_by_id_or_err(mt.as_kv_api(), share_id, "").await?;
-            assert!(share_meta.database.is_none());
-            assert!(share_meta.entries.is_empty());
+            let res = mt.diff_share_grants(share_name_a.clone(), share_name).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
         }
 
         Ok(())
     }
 
     #[tracing::instrument(level = "debug", skip_all)]
-    async fn get_share_grant_objects<MT: ShareApi + AsKVApi + SchemaApi>(
+    async fn get_share_create_statement<MT: ShareApi + AsKVApi + SchemaApi>(
         &self,
         mt: &MT,
     ) -> anyhow::Result<()> {
         let tenant = "tenant1";
         let share1 = "share1";
         let db_name = "db1";
-        let tbl_name = "table1";
+        let tbl_names = ["table1", "table2"];
 
         let share_name = ShareNameIdent {
             tenant: tenant.to_string(),
             share_name: share1.to_string(),
         };
 
-        info!("--- get unknown share");
-        {
-            let req = GetShareGrantObjectReq {
-                share_name: share_name.clone(),
-            };
-
-            let res = mt.get_share_grant_objects(req).await;
-            info!("get_share_grant_objects res: {:?}", res);
-            let err = res.unwrap_err();
-            assert_eq!(
-                ErrorCode::UnknownShare("").code(),
-                ErrorCode::from(err).code()
-            );
-        }
-
-        info!("--- create share1");
+        info!("--- create share1,db1,table1,table2");
         let create_on = Utc::now();
         {
             let req = CreateShareReq {
                 if_not_exists: false,
                 share_name: share_name.clone(),
-                comment: None,
+                comment: Some("share comment".to_string()),
                 create_on,
+                request_id: None,
             };
 
-            let res = mt.create_share(req).await;
+            let res = mt.create_share(req).await?;
             info!("create share res: {:?}", res);
-            let res = res.unwrap();
-            assert_eq!(1, res.share_id, "first database id is 1");
-        }
-
-        info!("--- get share");
-        {
-            let req = GetShareGrantObjectReq {
-                share_name: share_name.clone(),
-            };
-
-            let res = mt.get_share_grant_objects(req).await;
-            info!("get_share_grant_objects res: {:?}", res);
-            let res = res.unwrap();
-            assert!(res.objects.is_empty());
-        }
 
-        info!("--- create db1,table1");
-        {
             let plan = CreateDatabaseReq {
                 if_not_exists: false,
                 name_ident: DatabaseNameIdent {
@@ -823,55 +5073,122 @@ impl ShareApiTestSuite {
             let res = mt.create_database(plan).await?;
             info!("create database res: {:?}", res);
 
-            let req = CreateTableReq {
-                if_not_exists: false,
-                name_ident: TableNameIdent {
-                    tenant: tenant.to_string(),
-                    db_name: db_name.to_string(),
-                    table_name: tbl_name.to_string(),
-                },
-                table_meta: TableMeta::default(),
-            };
-
-            let res = mt.create_table(req.clone()).await?;
-            info!("create table res: {:?}", res);
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: TableMeta::default(),
+                };
+
+                let res = mt.create_table(req).await?;
+                info!("create table res: {:?}", res);
+            }
         }
 
-        info!("--- share db1 and table1");
+        info!("--- grant db1,table1,table2 and add accounts");
         {
             let req = GrantShareObjectReq {
                 share_name: share_name.clone(),
                 object: ShareGrantObjectName::Database(db_name.to_string()),
                 grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                privilege: GrantSharePrivilege::All,
+                protected_databases: vec![],
             };
 
             let res = mt.grant_share_object(req).await?;
             info!("grant object res: {:?}", res);
 
-            let tbl_ob_name =
-                ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
-            let req = GrantShareObjectReq {
+            for tbl_name in tbl_names {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                    grant_on: create_on,
+                    privilege: ShareGrantObjectPrivilege::Select.into(),
+                    protected_databases: vec![],
+                };
+
+                let res = mt.grant_share_object(req).await?;
+                info!("grant object res: {:?}", res);
+            }
+
+            let req = AddShareAccountsReq {
                 share_name: share_name.clone(),
-                object: tbl_ob_name.clone(),
-                grant_on: create_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                share_on: create_on,
+                if_exists: false,
+                accounts: vec!["tenant3".to_string(), "tenant2".to_string()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
             };
 
-            let res = mt.grant_share_object(req).await?;
-            info!("grant object res: {:?}", res);
+            let res = mt.add_share_tenants(req).await?;
+            info!("add share account res: {:?}", res);
         }
 
-        info!("--- get all share objects");
+        info!("--- drop table2, its grant is commented out");
         {
-            let req = GetShareGrantObjectReq {
+            let req = DropTableReq {
+                if_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_names[1].to_string(),
+                },
+            };
+
+            let res = mt.drop_table(req).await?;
+            info!("drop table res: {:?}", res);
+
+            let statement = mt.get_share_create_statement(share_name.clone()).await?;
+            info!("get_share_create_statement res: {}", statement);
+            assert_eq!(statement.lines().collect::<Vec<_>>(), vec![
+                "CREATE SHARE share1 COMMENT = 'share comment';",
+                "GRANT USAGE ON DATABASE db1 TO SHARE share1;",
+                "GRANT REFERENCE_USAGE ON DATABASE db1 TO SHARE share1;",
+                "GRANT SELECT ON TABLE db1.table1 TO SHARE share1;",
+                "-- dropped: GRANT SELECT ON TABLE db1.table2 TO SHARE share1;",
+                "ALTER SHARE share1 ADD TENANTS = tenant2,tenant3;",
+            ]);
+        }
+
+        info!("--- quotes in the comment are escaped");
+        {
+            let share_name = ShareNameIdent {
+                tenant: tenant.to_string(),
+                share_name: "share3".to_string(),
+            };
+            let req = CreateShareReq {
+                if_not_exists: false,
                 share_name: share_name.clone(),
+                comment: Some("it's shared".to_string()),
+                create_on,
+                request_id: None,
             };
 
-            let res = mt.get_share_grant_objects(req).await;
-            info!("get_share_grant_objects res: {:?}", res);
-            let res = res.unwrap();
-            assert_eq!(res.objects.len(), 2);
+            let res = mt.create_share(req).await?;
+            info!("create share res: {:?}", res);
+
+            let statement = mt.get_share_create_statement(share_name).await?;
+            assert_eq!(statement, "CREATE SHARE share3 COMMENT = 'it''s shared';");
+        }
+
+        info!("--- get create statement of unknown share");
+        {
+            let res = mt
+                .get_share_create_statement(ShareNameIdent {
+                    tenant: tenant.to_string(),
+                    share_name: "share2".to_string(),
+                })
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownShare("").code(),
+                ErrorCode::from(err).code()
+            );
         }
 
         Ok(())
@@ -902,6 +5219,7 @@ impl ShareApiTestSuite {
             let req = GetObjectGrantPrivilegesReq {
                 tenant: tenant1.to_string(),
                 object: ShareGrantObjectName::Database("db".to_string()),
+                privilege: None,
             };
 
             let res = mt.get_grant_privileges_of_object(req).await;
@@ -915,6 +5233,7 @@ impl ShareApiTestSuite {
             let req = GetObjectGrantPrivilegesReq {
                 tenant: tenant1.to_string(),
                 object: ShareGrantObjectName::Table("db".to_string(), "table".to_string()),
+                privilege: None,
             };
 
             let res = mt.get_grant_privileges_of_object(req).await;
@@ -935,6 +5254,7 @@ impl ShareApiTestSuite {
                 share_name: share_name1.clone(),
                 comment: None,
                 create_on,
+                request_id: None,
             };
 
             let res = mt.create_share(req).await;
@@ -945,6 +5265,7 @@ impl ShareApiTestSuite {
                 share_name: share_name2.clone(),
                 comment: None,
                 create_on,
+                request_id: None,
             };
 
             let res = mt.create_share(req).await;
@@ -979,13 +5300,54 @@ impl ShareApiTestSuite {
             info!("create table res: {:?}", res);
         }
 
+        info!("--- db1 and table1 have never been shared");
+        {
+            // The id of each name on the path, then the share ids of the object.
+            for (object, expected_reads) in [
+                (ShareGrantObjectName::Database(db_name.to_string()), 2),
+                (
+                    ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                    3,
+                ),
+            ] {
+                let counting = CountingKVApi {
+                    inner: mt.as_kv_api(),
+                    reads: AtomicU64::new(0),
+                };
+                let req = GetObjectGrantPrivilegesReq {
+                    tenant: tenant1.to_string(),
+                    object,
+                    privilege: None,
+                };
+
+                let res = counting.get_grant_privileges_of_object(req).await?;
+                assert!(res.privileges.is_empty());
+                assert_eq!(counting.reads(), expected_reads);
+            }
+
+            // The object still has to exist.
+            let req = GetObjectGrantPrivilegesReq {
+                tenant: tenant1.to_string(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), "unknown".to_string()),
+                privilege: None,
+            };
+
+            let res = mt.get_grant_privileges_of_object(req).await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
         info!("--- share db1 and table1");
         {
             let req = GrantShareObjectReq {
                 share_name: share_name1.clone(),
                 object: ShareGrantObjectName::Database(db_name.to_string()),
                 grant_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
             };
 
             let res = mt.grant_share_object(req).await?;
@@ -995,7 +5357,8 @@ impl ShareApiTestSuite {
                 share_name: share_name2.clone(),
                 object: ShareGrantObjectName::Database(db_name.to_string()),
                 grant_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                privilege: ShareGrantObjectPrivilege::Usage.into(),
+                protected_databases: vec![],
             };
 
             let res = mt.grant_share_object(req).await?;
@@ -1007,7 +5370,8 @@ impl ShareApiTestSuite {
                 share_name: share_name1.clone(),
                 object: tbl_ob_name.clone(),
                 grant_on,
-                privilege: ShareGrantObjectPrivilege::Usage,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
             };
 
             let res = mt.grant_share_object(req).await?;
@@ -1019,6 +5383,7 @@ impl ShareApiTestSuite {
             let req = GetObjectGrantPrivilegesReq {
                 tenant: tenant1.to_string(),
                 object: ShareGrantObjectName::Database(db_name.to_string()),
+                privilege: None,
             };
 
             let res = mt.get_grant_privileges_of_object(req).await;
@@ -1031,6 +5396,7 @@ impl ShareApiTestSuite {
             let req = GetObjectGrantPrivilegesReq {
                 tenant: tenant1.to_string(),
                 object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                privilege: None,
             };
 
             let res = mt.get_grant_privileges_of_object(req).await;
@@ -1041,6 +5407,201 @@ impl ShareApiTestSuite {
             assert_eq!(res.privileges[0].grant_on, grant_on);
         }
 
+        info!("--- grant select on table1 and reference_usage on db1 to share2");
+        {
+            let req = GrantShareObjectReq {
+                share_name: share_name2.clone(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                grant_on,
+                privilege: ShareGrantObjectPrivilege::Select.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+
+            let req = GrantShareObjectReq {
+                share_name: share_name2.clone(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                grant_on,
+                privilege: ShareGrantObjectPrivilege::ReferenceUsage.into(),
+                protected_databases: vec![],
+            };
+
+            let res = mt.grant_share_object(req).await?;
+            info!("grant object res: {:?}", res);
+        }
+
+        info!("--- get_grant_privileges_of_object filtered by privilege");
+        {
+            let req = GetObjectGrantPrivilegesReq {
+                tenant: tenant1.to_string(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                privilege: None,
+            };
+
+            let res = mt.get_grant_privileges_of_object(req).await?;
+            assert_eq!(res.privileges.len(), 2);
+
+            let req = GetObjectGrantPrivilegesReq {
+                tenant: tenant1.to_string(),
+                object: ShareGrantObjectName::Database(db_name.to_string()),
+                privilege: Some(ShareGrantObjectPrivilege::ReferenceUsage),
+            };
+
+            let res = mt.get_grant_privileges_of_object(req).await?;
+            assert_eq!(res.privileges.len(), 1);
+            assert_eq!(&res.privileges[0].share_name, share2);
+            assert_eq!(res.privileges[0].privilege_names(), vec![
+                "USAGE".to_string(),
+                "REFERENCE_USAGE".to_string()
+            ]);
+
+            let req = GetObjectGrantPrivilegesReq {
+                tenant: tenant1.to_string(),
+                object: ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string()),
+                privilege: Some(ShareGrantObjectPrivilege::Select),
+            };
+
+            let res = mt.get_grant_privileges_of_object(req).await?;
+            assert_eq!(res.privileges.len(), 2);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "debug", skip_all)]
+    async fn get_shares_by_object<MT: ShareApi + AsKVApi + SchemaApi>(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_name = "table1";
+
+        let share_name1 = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share1".to_string(),
+        };
+        let share_name2 = ShareNameIdent {
+            tenant: tenant.to_string(),
+            share_name: "share2".to_string(),
+        };
+        let db_object = ShareGrantObjectName::Database(db_name.to_string());
+        let tbl_object = ShareGrantObjectName::Table(db_name.to_string(), tbl_name.to_string());
+
+        info!("--- create share1, share2, db1 and table1");
+        let create_on = Utc::now();
+        {
+            for share_name in [&share_name1, &share_name2] {
+                let req = CreateShareReq {
+                    if_not_exists: false,
+                    share_name: share_name.clone(),
+                    comment: None,
+                    create_on,
+                    request_id: None,
+                };
+                mt.create_share(req).await?;
+            }
+
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta::default(),
+            };
+            mt.create_database(plan).await?;
+
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: tbl_name.to_string(),
+                },
+                table_meta: TableMeta::default(),
+            };
+            mt.create_table(req).await?;
+        }
+
+        info!("--- an object never shared is granted to no share");
+        {
+            let res = mt
+                .get_shares_by_object(tbl_object.clone(), tenant.to_string())
+                .await?;
+            assert!(res.is_empty());
+
+            let res = mt
+                .get_shares_by_object(
+                    ShareGrantObjectName::Table(db_name.to_string(), "unknown".to_string()),
+                    tenant.to_string(),
+                )
+                .await;
+            let err = res.unwrap_err();
+            assert_eq!(
+                ErrorCode::UnknownTable("").code(),
+                ErrorCode::from(err).code()
+            );
+        }
+
+        info!("--- share db1 and table1 with share1 and share2");
+        {
+            let grants = [
+                (&share_name1, &db_object, ShareGrantObjectPrivilege::Usage),
+                (&share_name1, &tbl_object, ShareGrantObjectPrivilege::Select),
+                (&share_name2, &db_object, ShareGrantObjectPrivilege::Usage),
+                (
+                    &share_name2,
+                    &db_object,
+                    ShareGrantObjectPrivilege::ReferenceUsage,
+                ),
+                (&share_name2, &tbl_object, ShareGrantObjectPrivilege::Select),
+            ];
+            for (share_name, object, privilege) in grants {
+                let req = GrantShareObjectReq {
+                    share_name: share_name.clone(),
+                    object: object.clone(),
+                    grant_on: create_on,
+                    privilege: privilege.into(),
+                    protected_databases: vec![],
+                };
+                mt.grant_share_object(req).await?;
+            }
+        }
+
+        info!("--- get_shares_by_object returns both shares with their privileges");
+        {
+            let res = mt
+                .get_shares_by_object(db_object.clone(), tenant.to_string())
+                .await?;
+            assert_eq!(res, vec![
+                (
+                    share_name1.clone(),
+                    BitFlags::from(ShareGrantObjectPrivilege::Usage)
+                ),
+                (
+                    share_name2.clone(),
+                    ShareGrantObjectPrivilege::Usage | ShareGrantObjectPrivilege::ReferenceUsage
+                ),
+            ]);
+
+            let res = mt
+                .get_shares_by_object(tbl_object.clone(), tenant.to_string())
+                .await?;
+            assert_eq!(res, vec![
+                (
+                    share_name1.clone(),
+                    BitFlags::from(ShareGrantObjectPrivilege::Select)
+                ),
+                (
+                    share_name2.clone(),
+                    BitFlags::from(ShareGrantObjectPrivilege::Select)
+                ),
+            ]);
+        }
+
         Ok(())
     }
 }
@@ -163,6 +163,10 @@ pub struct TableStatistics {
     pub compressed_data_bytes: u64,
     /// Size of index data in bytes
     pub index_data_bytes: u64,
+    /// Number of segments, None if the table was last written before it was kept
+    pub number_of_segments: Option<u64>,
+    /// Number of blocks, None if the table was last written before it was kept
+    pub number_of_blocks: Option<u64>,
 }
 
 /// The essential state that defines what a table is.
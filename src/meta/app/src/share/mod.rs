@@ -15,8 +15,12 @@
 #[allow(clippy::module_inception)]
 mod share;
 
+pub use share::privilege_names;
+pub use share::valid_privileges_for;
 pub use share::AddShareAccountsReply;
 pub use share::AddShareAccountsReq;
+pub use share::CloneShareReply;
+pub use share::CloneShareReq;
 pub use share::CreateShareReply;
 pub use share::CreateShareReq;
 pub use share::DropShareReply;
@@ -27,8 +31,11 @@ pub use share::GetShareGrantObjectReply;
 pub use share::GetShareGrantObjectReq;
 pub use share::GetShareGrantTenantsReply;
 pub use share::GetShareGrantTenantsReq;
+pub use share::GrantObjectToSharesReply;
+pub use share::GrantObjectToSharesReq;
 pub use share::GrantShareObjectReply;
 pub use share::GrantShareObjectReq;
+pub use share::GrantSharePrivilege;
 pub use share::ObjectGrantPrivilege;
 pub use share::ObjectSharedByShareIds;
 pub use share::RemoveShareAccountsReply;
@@ -38,17 +45,25 @@ pub use share::RevokeShareObjectReq;
 pub use share::ShareAccountMeta;
 pub use share::ShareAccountNameIdent;
 pub use share::ShareAccountReply;
+pub use share::ShareGrantDiff;
 pub use share::ShareGrantEntry;
 pub use share::ShareGrantObject;
+pub use share::ShareGrantObjectKind;
 pub use share::ShareGrantObjectName;
 pub use share::ShareGrantObjectPrivilege;
 pub use share::ShareGrantObjectSeqAndId;
+pub use share::ShareGrantObjectStatus;
+pub use share::ShareGrantPrivileges;
 pub use share::ShareGrantReplyObject;
 pub use share::ShareId;
 pub use share::ShareIdToName;
 pub use share::ShareIdent;
+pub use share::ShareInconsistency;
 pub use share::ShareInfo;
 pub use share::ShareMeta;
 pub use share::ShareNameIdent;
+pub use share::ShareUnresolvedGrant;
+pub use share::ShareValidationReport;
 pub use share::ShowSharesReply;
 pub use share::ShowSharesReq;
+pub use share::TransferShareAccountsReply;
@@ -55,6 +55,23 @@ impl Display for ShareAccountNameIdent {
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ShowSharesReq {
     pub tenant: String,
+    // number of leading outbound shares to skip, shares are ordered by share name.
+    pub outbound_offset: usize,
+    // number of leading inbound shares to skip, shares are ordered by share name.
+    pub inbound_offset: usize,
+    // max number of shares of each list to return, return all the shares if it is None.
+    pub limit: Option<usize>,
+}
+
+impl ShowSharesReq {
+    pub fn new(tenant: impl Into<String>) -> ShowSharesReq {
+        ShowSharesReq {
+            tenant: tenant.into(),
+            outbound_offset: 0,
+            inbound_offset: 0,
+            limit: None,
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -67,6 +84,8 @@ pub struct ShareAccountReply {
     // if is inbound share, then accounts is None
     pub accounts: Option<Vec<String>>,
     pub comment: Option<String>,
+    // the last time a consumer accessed the share, None if it has never been accessed.
+    pub last_accessed_on: Option<DateTime<Utc>>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -75,6 +94,14 @@ pub struct ShowSharesReply {
     pub outbound_accounts: Vec<ShareAccountReply>,
     // be shared by other accounts(inbound shares)
     pub inbound_accounts: Vec<ShareAccountReply>,
+    // the offsets to request the next pages with. A share skipped because it can not be
+    // resolved still counts, so they may advance more than the number of returned shares.
+    pub next_outbound_offset: usize,
+    pub next_inbound_offset: usize,
+    // true if there are more outbound shares after this page.
+    pub outbound_has_more: bool,
+    // true if there are more inbound shares after this page.
+    pub inbound_has_more: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -83,6 +110,8 @@ pub struct CreateShareReq {
     pub share_name: ShareNameIdent,
     pub comment: Option<String>,
     pub create_on: DateTime<Utc>,
+    /// Identifies the request, a retried request with the same id returns the share it created.
+    pub request_id: Option<String>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -90,25 +119,69 @@ pub struct CreateShareReply {
     pub share_id: u64,
 }
 
+/// Creates the share `target_name` of the tenant of `source` with the granted objects of `source`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CloneShareReq {
+    pub if_not_exists: bool,
+    pub source: ShareNameIdent,
+    pub target_name: String,
+    /// Also add the accounts of `source` to the new share.
+    pub with_accounts: bool,
+    pub create_on: DateTime<Utc>,
+    /// The databases whose objects can not be cloned into the new share.
+    pub protected_databases: Vec<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CloneShareReply {
+    pub share_id: u64,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct DropShareReq {
     pub share_name: ShareNameIdent,
     pub if_exists: bool,
+    /// Delete the sub keys of the share without checking their seq,
+    /// so that a share with missing or concurrently changed sub keys can still be removed.
+    pub force: bool,
+    /// Only report what the drop would remove, without removing anything.
+    pub dry_run: bool,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
-pub struct DropShareReply {}
+/// What a drop removed, or would remove with `dry_run`. All zero if the share does not exist.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DropShareReply {
+    pub accounts_to_remove: u64,
+    pub objects_to_revoke: u64,
+}
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct AddShareAccountsReq {
     pub share_name: ShareNameIdent,
     pub if_exists: bool,
     pub accounts: Vec<String>,
+    /// Reject the accounts that already share to the tenant, directly or through other tenants.
+    ///
+    /// It reads the shares of every tenant on the way, so it is off by default.
+    pub check_cycle: bool,
+    /// The max number of accounts added in one transaction, a default is used if it is None.
+    pub chunk_size: Option<usize>,
+    /// Refresh the `share_on` of the accounts already added, instead of skipping them.
+    pub update_existing: bool,
     pub share_on: DateTime<Utc>,
 }
 
-#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
-pub struct AddShareAccountsReply {}
+/// The accounts are added in chunks of `AddShareAccountsReq::chunk_size`, each chunk in its own
+/// transaction. A chunk is added all or nothing, but an error leaves the chunks committed before it.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddShareAccountsReply {
+    pub committed_chunks: u64,
+    pub added_accounts: Vec<String>,
+    /// The accounts already added, whose `share_on` is refreshed by `update_existing`.
+    pub updated_accounts: Vec<String>,
+    /// The requested accounts left as they are: the tenant itself, or the accounts already added.
+    pub skipped_accounts: Vec<String>,
+}
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RemoveShareAccountsReq {
@@ -118,7 +191,19 @@ pub struct RemoveShareAccountsReq {
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
-pub struct RemoveShareAccountsReply {}
+pub struct RemoveShareAccountsReply {
+    pub removed_accounts: Vec<String>,
+    /// The requested accounts that the share is not granted to.
+    pub not_found_accounts: Vec<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferShareAccountsReply {
+    /// The accounts moved to the target share.
+    pub transferred_accounts: Vec<String>,
+    /// The accounts removed from the source share, that the target share is already granted to.
+    pub skipped_accounts: Vec<String>,
+}
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ShowShareOfReq {
@@ -162,18 +247,36 @@ pub struct GrantShareObjectReq {
     pub share_name: ShareNameIdent,
     pub object: ShareGrantObjectName,
     pub grant_on: DateTime<Utc>,
-    pub privilege: ShareGrantObjectPrivilege,
+    pub privilege: GrantSharePrivilege,
+    /// The databases whose objects can not be granted.
+    pub protected_databases: Vec<String>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GrantShareObjectReply {}
 
+/// Grants one object to several shares of the same tenant at once,
+/// the object is resolved in the tenant of the first share.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GrantObjectToSharesReq {
+    pub share_names: Vec<ShareNameIdent>,
+    pub object: ShareGrantObjectName,
+    pub grant_on: DateTime<Utc>,
+    pub privilege: GrantSharePrivilege,
+    /// The databases whose objects can not be granted.
+    pub protected_databases: Vec<String>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct GrantObjectToSharesReply {}
+
+/// The `update_on` of the revoked grant entry is stamped by the meta service,
+/// so that the clock skew of the query nodes does not reorder the updates.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct RevokeShareObjectReq {
     pub share_name: ShareNameIdent,
     pub object: ShareGrantObjectName,
-    pub privilege: ShareGrantObjectPrivilege,
-    pub update_on: DateTime<Utc>,
+    pub privilege: GrantSharePrivilege,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -182,13 +285,42 @@ pub struct RevokeShareObjectReply {}
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetShareGrantObjectReq {
     pub share_name: ShareNameIdent,
+    /// Also return the grants whose object has been dropped, marked as `Dropped`.
+    pub include_dropped: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ShareGrantReplyObject {
     pub object: ShareGrantObjectName,
+    /// Serialized as the privilege names, e.g. `["USAGE", "REFERENCE_USAGE"]`, rather than the bits.
+    #[serde(with = "privilege_names_serde")]
     pub privileges: BitFlags<ShareGrantObjectPrivilege>,
     pub grant_on: DateTime<Utc>,
+    /// When each of the privileges was granted.
+    pub privilege_grant_on: BTreeMap<ShareGrantObjectPrivilege, DateTime<Utc>>,
+    pub status: ShareGrantObjectStatus,
+}
+
+impl ShareGrantReplyObject {
+    pub fn privilege_names(&self) -> Vec<String> {
+        privilege_names(self.privileges)
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShareGrantObjectStatus {
+    Granted,
+    /// The granted object has been dropped, the grant is dangling.
+    Dropped,
+}
+
+impl Display for ShareGrantObjectStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareGrantObjectStatus::Granted => write!(f, "GRANTED"),
+            ShareGrantObjectStatus::Dropped => write!(f, "DROPPED"),
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -197,20 +329,51 @@ pub struct GetShareGrantObjectReply {
     pub objects: Vec<ShareGrantReplyObject>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ShareGrantPrivileges {
+    pub object: ShareGrantObjectName,
+    pub privileges: BitFlags<ShareGrantObjectPrivilege>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ShareUnresolvedGrant {
+    pub share_name: ShareNameIdent,
+    pub object: ShareGrantObject,
+}
+
+/// The difference of the grants of two shares, the objects are compared by name.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ShareGrantDiff {
+    /// The privileges the first share grants but the second one does not.
+    pub only_in_a: Vec<ShareGrantPrivileges>,
+    /// The privileges the second share grants but the first one does not.
+    pub only_in_b: Vec<ShareGrantPrivileges>,
+    /// The granted objects of either share that do not resolve to a name, e.g. the dropped ones.
+    pub unresolved: Vec<ShareUnresolvedGrant>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetShareGrantTenantsReq {
     pub share_name: ShareNameIdent,
+    /// Also return the `ShareAccountMeta` of every account.
+    pub include_meta: bool,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetShareGrantTenantsReply {
     pub accounts: Vec<String>,
+    /// The metas of `accounts` in the same order, empty unless `include_meta` is set.
+    ///
+    /// An account whose meta is removed in the meantime is left out.
+    pub account_metas: Vec<ShareAccountMeta>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetObjectGrantPrivilegesReq {
     pub tenant: String,
     pub object: ShareGrantObjectName,
+    /// Only return the shares granting this privilege on the object, if given.
+    pub privilege: Option<ShareGrantObjectPrivilege>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -220,11 +383,75 @@ pub struct ObjectGrantPrivilege {
     pub grant_on: DateTime<Utc>,
 }
 
+impl ObjectGrantPrivilege {
+    pub fn privilege_names(&self) -> Vec<String> {
+        privilege_names(self.privileges)
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetObjectGrantPrivilegesReply {
     pub privileges: Vec<ObjectGrantPrivilege>,
 }
 
+/// An inconsistency between the keys of a share found by `validate_share`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum ShareInconsistency {
+    /// (share_id) -> (tenant, share_name) is missing or refers to another share name.
+    IdToNameMismatch { found: Option<ShareNameIdent> },
+    /// An account of the share has no `ShareAccountMeta`.
+    MissingAccountMeta { account: String },
+    /// A granted object does not exist any more.
+    UnresolvedObject { object: ShareGrantObject },
+    /// The `ObjectSharedByShareIds` of a granted object does not contain the share.
+    ObjectNotSharedByShare { object: ShareGrantObject },
+    /// A granted table belongs to the database `db_id` rather than the granted database.
+    TableOutsideSharedDatabase {
+        object: ShareGrantObject,
+        db_id: u64,
+    },
+}
+
+impl Display for ShareInconsistency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareInconsistency::IdToNameMismatch { found } => match found {
+                Some(found) => write!(f, "share id refers to share name {}", found),
+                None => write!(f, "share id refers to no share name"),
+            },
+            ShareInconsistency::MissingAccountMeta { account } => {
+                write!(f, "account {} has no share account meta", account)
+            }
+            ShareInconsistency::UnresolvedObject { object } => {
+                write!(f, "granted object {} does not exist", object)
+            }
+            ShareInconsistency::ObjectNotSharedByShare { object } => {
+                write!(f, "granted object {} is not shared by the share", object)
+            }
+            ShareInconsistency::TableOutsideSharedDatabase { object, db_id } => {
+                write!(
+                    f,
+                    "granted table {} belongs to db/{}, not the granted database",
+                    object, db_id
+                )
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ShareValidationReport {
+    pub share_name: ShareNameIdent,
+    pub share_id: u64,
+    pub inconsistencies: Vec<ShareInconsistency>,
+}
+
+impl ShareValidationReport {
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ShareAccountMeta {
     pub account: String,
@@ -277,6 +504,93 @@ impl ShareGrantObject {
             }
         }
     }
+
+    pub fn kind(&self) -> ShareGrantObjectKind {
+        match self {
+            ShareGrantObject::Database(_) => ShareGrantObjectKind::Database,
+            ShareGrantObject::Table(_) => ShareGrantObjectKind::Table,
+        }
+    }
+
+    /// Returns the privileges of the object the grant of `privilege` stands for,
+    /// `All` is expanded to all the privileges applicable to the kind of the object.
+    pub fn expand_privilege(
+        &self,
+        privilege: GrantSharePrivilege,
+    ) -> BitFlags<ShareGrantObjectPrivilege> {
+        match privilege {
+            GrantSharePrivilege::Privilege(privilege) => BitFlags::from(privilege),
+            GrantSharePrivilege::All => valid_privileges_for(self.kind()),
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShareGrantObjectKind {
+    Database,
+    Table,
+}
+
+impl Display for ShareGrantObjectKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareGrantObjectKind::Database => write!(f, "DATABASE"),
+            ShareGrantObjectKind::Table => write!(f, "TABLE"),
+        }
+    }
+}
+
+/// Returns the privileges that can be granted on an object of `kind`.
+pub fn valid_privileges_for(kind: ShareGrantObjectKind) -> BitFlags<ShareGrantObjectPrivilege> {
+    match kind {
+        ShareGrantObjectKind::Database => {
+            ShareGrantObjectPrivilege::Usage | ShareGrantObjectPrivilege::ReferenceUsage
+        }
+        ShareGrantObjectKind::Table => BitFlags::from(ShareGrantObjectPrivilege::Select),
+    }
+}
+
+/// Returns the names of the privileges, e.g. `["USAGE", "REFERENCE_USAGE"]`, in bit order.
+pub fn privilege_names(privileges: BitFlags<ShareGrantObjectPrivilege>) -> Vec<String> {
+    privileges.iter().map(|p| p.to_string()).collect()
+}
+
+/// Serde representation of the privileges as the array of their names.
+mod privilege_names_serde {
+    use enumflags2::BitFlags;
+    use serde::de::Error;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serializer;
+
+    use super::privilege_names;
+    use super::ShareGrantObjectPrivilege;
+
+    pub fn serialize<S>(
+        privileges: &BitFlags<ShareGrantObjectPrivilege>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(privilege_names(*privileges))
+    }
+
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<BitFlags<ShareGrantObjectPrivilege>, D::Error>
+    where D: Deserializer<'de> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        names
+            .iter()
+            .map(|name| {
+                BitFlags::<ShareGrantObjectPrivilege>::all()
+                    .iter()
+                    .find(|privilege| privilege.to_string() == *name)
+                    .ok_or_else(|| D::Error::custom(format!("unknown share privilege: {}", name)))
+            })
+            .collect()
+    }
 }
 
 impl Display for ShareGrantObject {
@@ -330,6 +644,8 @@ impl ObjectSharedByShareIds {
     Debug,
     Eq,
     PartialEq,
+    Ord,
+    PartialOrd,
     num_derive::FromPrimitive,
 )]
 pub enum ShareGrantObjectPrivilege {
@@ -351,45 +667,84 @@ impl Display for ShareGrantObjectPrivilege {
     }
 }
 
+/// The privilege of a grant or revoke request.
+///
+/// `All` stands for all the privileges applicable to the kind of the object, it is expanded
+/// into the concrete privileges before the share meta is updated.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GrantSharePrivilege {
+    Privilege(ShareGrantObjectPrivilege),
+    All,
+}
+
+impl From<ShareGrantObjectPrivilege> for GrantSharePrivilege {
+    fn from(privilege: ShareGrantObjectPrivilege) -> Self {
+        GrantSharePrivilege::Privilege(privilege)
+    }
+}
+
+impl Display for GrantSharePrivilege {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrantSharePrivilege::Privilege(privilege) => write!(f, "{}", privilege),
+            GrantSharePrivilege::All => write!(f, "ALL"),
+        }
+    }
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct ShareGrantEntry {
     pub object: ShareGrantObject,
     pub privileges: BitFlags<ShareGrantObjectPrivilege>,
     pub grant_on: DateTime<Utc>,
     pub update_on: Option<DateTime<Utc>>,
+    /// When each of the privileges was granted, empty for the entries granted before it was tracked.
+    pub privilege_grant_on: BTreeMap<ShareGrantObjectPrivilege, DateTime<Utc>>,
 }
 
 impl ShareGrantEntry {
     pub fn new(
         object: ShareGrantObject,
-        privileges: ShareGrantObjectPrivilege,
+        privileges: impl Into<BitFlags<ShareGrantObjectPrivilege>>,
         grant_on: DateTime<Utc>,
     ) -> Self {
+        let privileges = privileges.into();
         Self {
             object,
-            privileges: BitFlags::from(privileges),
+            privileges,
             grant_on,
             update_on: None,
+            privilege_grant_on: privileges.iter().map(|p| (p, grant_on)).collect(),
         }
     }
 
+    // add the privileges, the ones already granted keep their grant time.
     pub fn grant_privileges(
         &mut self,
-        privileges: ShareGrantObjectPrivilege,
+        privileges: impl Into<BitFlags<ShareGrantObjectPrivilege>>,
         grant_on: DateTime<Utc>,
     ) {
+        let privileges = privileges.into();
+        for privilege in privileges.iter() {
+            if !self.privileges.contains(privilege) {
+                self.privilege_grant_on.insert(privilege, grant_on);
+            }
+        }
         self.update_on = Some(grant_on);
-        self.privileges = BitFlags::from(privileges);
+        self.privileges.insert(privileges);
     }
 
     // return true if all privileges are empty.
     pub fn revoke_privileges(
         &mut self,
-        privileges: ShareGrantObjectPrivilege,
+        privileges: impl Into<BitFlags<ShareGrantObjectPrivilege>>,
         update_on: DateTime<Utc>,
     ) -> bool {
+        let privileges = privileges.into();
         self.update_on = Some(update_on);
-        self.privileges.remove(BitFlags::from(privileges));
+        self.privileges.remove(privileges);
+        self.privilege_grant_on
+            .retain(|privilege, _| !privileges.contains(*privilege));
         self.privileges.is_empty()
     }
 
@@ -401,8 +756,25 @@ impl ShareGrantEntry {
         &self.privileges
     }
 
-    pub fn has_granted_privileges(&self, privileges: ShareGrantObjectPrivilege) -> bool {
-        self.privileges.contains(privileges)
+    pub fn has_granted_privileges(
+        &self,
+        privileges: impl Into<BitFlags<ShareGrantObjectPrivilege>>,
+    ) -> bool {
+        self.privileges.contains(privileges.into())
+    }
+
+    /// Returns when each granted privilege was granted, the untracked ones fall back to `grant_on`.
+    pub fn get_privilege_grant_on(&self) -> BTreeMap<ShareGrantObjectPrivilege, DateTime<Utc>> {
+        self.privileges
+            .iter()
+            .map(|privilege| {
+                let grant_on = self
+                    .privilege_grant_on
+                    .get(&privilege)
+                    .unwrap_or(&self.grant_on);
+                (privilege, *grant_on)
+            })
+            .collect()
     }
 }
 
@@ -419,7 +791,20 @@ pub struct ShareMeta {
     pub accounts: BTreeSet<String>,
     pub comment: Option<String>,
     pub share_on: DateTime<Utc>,
+    /// The last time an object is granted or revoked, or an account is added or removed.
+    ///
+    /// None if the share is not modified since it is created.
     pub update_on: Option<DateTime<Utc>>,
+    /// The `request_id` of the `CreateShareReq` that created the share.
+    pub create_request_id: Option<String>,
+    /// Set when the shared database is renamed, the consumers have to re-resolve the object names.
+    pub needs_refresh: bool,
+    /// The last time a consumer accessed the share, None if it has never been accessed.
+    ///
+    /// It is not bumped on every access, see `ShareApi::record_share_access`.
+    pub last_accessed_on: Option<DateTime<Utc>>,
+    /// A frozen share rejects any change of its grants and accounts until it is unfrozen.
+    pub frozen: bool,
 }
 
 impl ShareMeta {
@@ -462,7 +847,7 @@ impl ShareMeta {
     pub fn grant_object_privileges(
         &mut self,
         object: ShareGrantObject,
-        privileges: ShareGrantObjectPrivilege,
+        privileges: impl Into<BitFlags<ShareGrantObjectPrivilege>>,
         grant_on: DateTime<Utc>,
     ) {
         let key = object.to_string();
@@ -487,12 +872,13 @@ impl ShareMeta {
                 };
             }
         }
+        self.update_on = Some(grant_on);
     }
 
     pub fn revoke_object_privileges(
         &mut self,
         object: ShareGrantObject,
-        privileges: ShareGrantObjectPrivilege,
+        privileges: impl Into<BitFlags<ShareGrantObjectPrivilege>>,
         update_on: DateTime<Utc>,
     ) -> Result<(), MetaError> {
         let key = object.to_string();
@@ -505,8 +891,8 @@ impl ShareMeta {
                             // all database privileges have been revoked, clear database and entries.
                             self.database = None;
                             self.entries.clear();
-                            self.update_on = Some(update_on);
                         }
+                        self.update_on = Some(update_on);
                     } else {
                         return Err(MetaError::AppError(AppError::WrongShareObject(
                             WrongShareObject::new(&key),
@@ -525,6 +911,7 @@ impl ShareMeta {
                             if entry.revoke_privileges(privileges, update_on) {
                                 self.entries.remove(&key);
                             }
+                            self.update_on = Some(update_on);
                         } else {
                             return Err(MetaError::AppError(AppError::WrongShareObject(
                                 WrongShareObject::new(object.to_string()),
@@ -544,8 +931,18 @@ impl ShareMeta {
         &self,
         obj_name: &ShareGrantObjectName,
         object: &ShareGrantObjectSeqAndId,
-        privileges: ShareGrantObjectPrivilege,
+        privileges: impl Into<BitFlags<ShareGrantObjectPrivilege>>,
     ) -> Result<bool, MetaError> {
+        let granted = self.get_granted_privileges(obj_name, object)?;
+        Ok(granted.contains(privileges.into()))
+    }
+
+    /// Returns the privileges granted on the object, empty if it is not granted.
+    pub fn get_granted_privileges(
+        &self,
+        obj_name: &ShareGrantObjectName,
+        object: &ShareGrantObjectSeqAndId,
+    ) -> Result<BitFlags<ShareGrantObjectPrivilege>, MetaError> {
         match object {
             ShareGrantObjectSeqAndId::Database(_seq, db_id, _meta) => match &self.database {
                 Some(db) => match db.object {
@@ -555,20 +952,20 @@ impl ShareMeta {
                                 WrongShareObject::new(obj_name.to_string()),
                             )))
                         } else {
-                            Ok(db.has_granted_privileges(privileges))
+                            Ok(db.privileges)
                         }
                     }
                     ShareGrantObject::Table(_) => {
                         unreachable!("grant database CANNOT be a table");
                     }
                 },
-                None => Ok(false),
+                None => Ok(BitFlags::empty()),
             },
             ShareGrantObjectSeqAndId::Table(_db_id, _table_seq, table_id) => {
                 let key = ShareGrantObject::Table(*table_id).to_string();
                 match self.entries.get(&key) {
-                    Some(entry) => Ok(entry.has_granted_privileges(privileges)),
-                    None => Ok(false),
+                    Some(entry) => Ok(entry.privileges),
+                    None => Ok(BitFlags::empty()),
                 }
             }
         }
@@ -48,6 +48,16 @@ impl KVApi for MetaEmbedded {
         sm.prefix_list_kv(prefix).await
     }
 
+    async fn prefix_list_kv_page(
+        &self,
+        prefix: &str,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> Result<ListKVReply, MetaError> {
+        let sm = self.inner.lock().await;
+        sm.prefix_list_kv_page(prefix, start_after, limit).await
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> Result<TxnReply, MetaError> {
         let sm = self.inner.lock().await;
         sm.transaction(txn).await
@@ -23,6 +23,7 @@ use common_datavalues::chrono::Utc;
 use common_meta_app::share as mt;
 use common_protos::pb;
 use enumflags2::BitFlags;
+use num::FromPrimitive;
 
 use crate::check_ver;
 use crate::FromToProto;
@@ -129,6 +130,17 @@ impl FromToProto for mt::ShareGrantEntry {
                     Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
                     None => None,
                 },
+                privilege_grant_on: {
+                    let mut privilege_grant_on = BTreeMap::new();
+                    for (privilege, grant_on) in p.privilege_grant_on {
+                        let privilege =
+                            FromPrimitive::from_u64(privilege).ok_or_else(|| Incompatible {
+                                reason: format!("invalid ShareGrantObjectPrivilege: {}", privilege),
+                            })?;
+                        privilege_grant_on.insert(privilege, DateTime::<Utc>::from_pb(grant_on)?);
+                    }
+                    privilege_grant_on
+                },
             }),
             Err(e) => Err(Incompatible {
                 reason: format!("UserPrivilegeType error: {}", e),
@@ -147,6 +159,13 @@ impl FromToProto for mt::ShareGrantEntry {
                 Some(t) => Some(t.to_pb()?),
                 None => None,
             },
+            privilege_grant_on: {
+                let mut privilege_grant_on = BTreeMap::new();
+                for (privilege, grant_on) in &self.privilege_grant_on {
+                    privilege_grant_on.insert(*privilege as u64, grant_on.to_pb()?);
+                }
+                privilege_grant_on
+            },
         })
     }
 }
@@ -174,6 +193,13 @@ impl FromToProto for mt::ShareMeta {
                 Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
                 None => None,
             },
+            create_request_id: p.create_request_id,
+            needs_refresh: p.needs_refresh,
+            last_accessed_on: match p.last_accessed_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+            frozen: p.frozen,
         })
     }
 
@@ -198,6 +224,13 @@ impl FromToProto for mt::ShareMeta {
                 Some(t) => Some(t.to_pb()?),
                 None => None,
             },
+            create_request_id: self.create_request_id.clone(),
+            needs_refresh: self.needs_refresh,
+            last_accessed_on: match &self.last_accessed_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            frozen: self.frozen,
         })
     }
 }
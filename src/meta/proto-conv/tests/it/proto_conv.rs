@@ -81,6 +81,29 @@ fn new_share_meta() -> share::ShareMeta {
         comment: Some(s("comment")),
         share_on: Utc.ymd(2014, 11, 28).and_hms(12, 0, 9),
         update_on: Some(Utc.ymd(2014, 11, 29).and_hms(12, 0, 9)),
+        create_request_id: Some(s("request_id")),
+        needs_refresh: true,
+        last_accessed_on: Some(Utc.ymd(2014, 11, 30).and_hms(12, 0, 9)),
+        frozen: true,
+    }
+}
+
+/// The ShareMeta stored with ver=2, before the grant time of each privilege and the share states.
+fn new_share_meta_v2() -> share::ShareMeta {
+    let mut share_meta = new_share_meta();
+    if let Some(db_entry) = share_meta.database.as_mut() {
+        db_entry.privilege_grant_on.clear();
+    }
+    for entry in share_meta.entries.values_mut() {
+        entry.privilege_grant_on.clear();
+    }
+
+    share::ShareMeta {
+        create_request_id: None,
+        needs_refresh: false,
+        last_accessed_on: None,
+        frozen: false,
+        ..share_meta
     }
 }
 
@@ -157,6 +180,17 @@ fn new_table_meta() -> mt::TableMeta {
     }
 }
 
+fn new_table_statistics() -> mt::TableStatistics {
+    mt::TableStatistics {
+        number_of_rows: 100,
+        data_bytes: 200,
+        compressed_data_bytes: 15,
+        index_data_bytes: 20,
+        number_of_segments: Some(2),
+        number_of_blocks: Some(3),
+    }
+}
+
 #[test]
 fn test_pb_from_to() -> anyhow::Result<()> {
     let db = new_db_meta();
@@ -169,6 +203,11 @@ fn test_pb_from_to() -> anyhow::Result<()> {
     let got = mt::TableMeta::from_pb(p)?;
     assert_eq!(tbl, got);
 
+    let table_statistics = new_table_statistics();
+    let p = table_statistics.to_pb()?;
+    let got = mt::TableStatistics::from_pb(p)?;
+    assert_eq!(table_statistics, got);
+
     let share = new_share_meta();
     let p = share.to_pb()?;
     let got = share::ShareMeta::from_pb(p)?;
@@ -181,17 +220,38 @@ fn test_pb_from_to() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_share_grant_entry_without_privilege_grant_on() -> anyhow::Result<()> {
+    let grant_on = Utc.ymd(2014, 11, 28).and_hms(12, 0, 9);
+    let entry = share::ShareGrantEntry::new(
+        share::ShareGrantObject::Database(1),
+        share::ShareGrantObjectPrivilege::Usage | share::ShareGrantObjectPrivilege::ReferenceUsage,
+        grant_on,
+    );
+
+    // An entry stored before the per privilege grant time was tracked.
+    let mut p = entry.to_pb()?;
+    p.privilege_grant_on.clear();
+    let got = share::ShareGrantEntry::from_pb(p)?;
+    assert!(got.privilege_grant_on.is_empty());
+    assert_eq!(got.get_privilege_grant_on(), btreemap! {
+        share::ShareGrantObjectPrivilege::Usage => grant_on,
+        share::ShareGrantObjectPrivilege::ReferenceUsage => grant_on,
+    });
+    Ok(())
+}
+
 #[test]
 fn test_incompatible() -> anyhow::Result<()> {
     let db_meta = new_db_meta();
     let mut p = db_meta.to_pb()?;
-    p.ver = 4;
-    p.min_compatible = 4;
+    p.ver = 6;
+    p.min_compatible = 6;
 
     let res = mt::DatabaseMeta::from_pb(p);
     assert_eq!(
         Incompatible {
-            reason: s("executable ver=3 is smaller than the message min compatible ver: 4")
+            reason: s("executable ver=5 is smaller than the message min compatible ver: 6")
         },
         res.unwrap_err()
     );
@@ -356,6 +416,34 @@ fn test_load_old() -> anyhow::Result<()> {
         assert_eq!(want, got);
     }
 
+    // TableStatistics is loadable
+    {
+        let table_statistics_v4: Vec<u8> =
+            vec![8, 100, 16, 200, 1, 24, 15, 32, 20, 160, 6, 4, 168, 6, 1];
+        let p: pb::TableStatistics =
+            common_protos::prost::Message::decode(table_statistics_v4.as_slice())
+                .map_err(print_err)?;
+
+        let got = mt::TableStatistics::from_pb(p).map_err(print_err)?;
+        let want = mt::TableStatistics {
+            number_of_segments: None,
+            number_of_blocks: None,
+            ..new_table_statistics()
+        };
+        assert_eq!(want, got);
+
+        let table_statistics_v5: Vec<u8> = vec![
+            8, 100, 16, 200, 1, 24, 15, 32, 20, 40, 2, 48, 3, 160, 6, 5, 168, 6, 1,
+        ];
+        let p: pb::TableStatistics =
+            common_protos::prost::Message::decode(table_statistics_v5.as_slice())
+                .map_err(print_err)?;
+
+        let got = mt::TableStatistics::from_pb(p).map_err(print_err)?;
+        let want = new_table_statistics();
+        assert_eq!(want, got);
+    }
+
     // ShareMeta is loadable
     {
         let share_meta_v2: Vec<u8> = vec![
@@ -371,6 +459,36 @@ fn test_load_old() -> anyhow::Result<()> {
         let p: pb::ShareMeta =
             common_protos::prost::Message::decode(share_meta_v2.as_slice()).map_err(print_err)?;
 
+        let got = share::ShareMeta::from_pb(p).map_err(print_err)?;
+        let want = new_share_meta_v2();
+        assert_eq!(want, got);
+
+        // Fields added after ver=2 are defaulted when an old meta is loaded.
+        assert_eq!(None, got.create_request_id);
+        assert!(!got.needs_refresh);
+        assert_eq!(None, got.last_accessed_on);
+        assert!(!got.frozen);
+    }
+
+    // ShareMeta with the fields added in ver=4 is loadable
+    {
+        let share_meta_v4: Vec<u8> = vec![
+            10, 72, 10, 8, 8, 1, 160, 6, 4, 168, 6, 1, 16, 1, 26, 23, 50, 48, 49, 52, 45, 49, 49,
+            45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 42, 27, 8, 1, 18, 23,
+            50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+            67, 160, 6, 4, 168, 6, 1, 18, 72, 10, 8, 16, 19, 160, 6, 4, 168, 6, 1, 16, 4, 26, 23,
+            50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+            67, 42, 27, 8, 4, 18, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48,
+            48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 4, 168, 6, 1, 26, 1, 97, 26, 1, 98, 34, 7, 99,
+            111, 109, 109, 101, 110, 116, 42, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49,
+            50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 50, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50,
+            57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 10, 114, 101, 113, 117,
+            101, 115, 116, 95, 105, 100, 64, 1, 74, 23, 50, 48, 49, 52, 45, 49, 49, 45, 51, 48, 32,
+            49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 80, 1, 160, 6, 4, 168, 6, 1,
+        ];
+        let p: pb::ShareMeta =
+            common_protos::prost::Message::decode(share_meta_v4.as_slice()).map_err(print_err)?;
+
         let got = share::ShareMeta::from_pb(p).map_err(print_err)?;
         let want = new_share_meta();
         assert_eq!(want, got);
@@ -115,13 +115,13 @@ fn test_user_incompatible() -> anyhow::Result<()> {
     {
         let user_info = test_user_info();
         let mut p = user_info.to_pb()?;
-        p.ver = 4;
-        p.min_compatible = 4;
+        p.ver = 6;
+        p.min_compatible = 6;
 
         let res = mt::UserInfo::from_pb(p);
         assert_eq!(
             Incompatible {
-                reason: s("executable ver=3 is smaller than the message min compatible ver: 4")
+                reason: s("executable ver=5 is smaller than the message min compatible ver: 6")
             },
             res.unwrap_err()
         );
@@ -130,13 +130,13 @@ fn test_user_incompatible() -> anyhow::Result<()> {
     {
         let user_stage_info = test_user_stage_info();
         let mut p = user_stage_info.to_pb()?;
-        p.ver = 4;
-        p.min_compatible = 4;
+        p.ver = 6;
+        p.min_compatible = 6;
 
         let res = mt::UserStageInfo::from_pb(p);
         assert_eq!(
             Incompatible {
-                reason: s("executable ver=3 is smaller than the message min compatible ver: 4")
+                reason: s("executable ver=5 is smaller than the message min compatible ver: 6")
             },
             res.unwrap_err()
         );
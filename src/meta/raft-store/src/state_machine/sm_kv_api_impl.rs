@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::ops::Bound;
+
 use common_meta_api::KVApi;
 use common_meta_types::AppliedState;
 use common_meta_types::Cmd;
@@ -108,4 +110,34 @@ impl KVApi for StateMachine {
 
         Ok(x.collect())
     }
+
+    async fn prefix_list_kv_page(
+        &self,
+        prefix: &str,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<(String, SeqV<Vec<u8>>)>, MetaError> {
+        let kvs = self.kvs();
+        let start = match start_after {
+            Some(after) => Bound::Excluded(after),
+            None => Bound::Included(prefix.to_string()),
+        };
+
+        // Scan the range lazily, only the kvs of the page are loaded.
+        let mut res = Vec::with_capacity(limit);
+        for item in kvs.range((start, Bound::Unbounded))? {
+            if res.len() >= limit {
+                break;
+            }
+            let (k, v) = item?;
+            if !k.starts_with(prefix) {
+                break;
+            }
+            if let Some(v) = Self::unexpired(v) {
+                res.push((k, v));
+            }
+        }
+
+        Ok(res)
+    }
 }
@@ -87,6 +87,18 @@ impl KVApi for MetaStore {
         }
     }
 
+    async fn prefix_list_kv_page(
+        &self,
+        prefix: &str,
+        start_after: Option<String>,
+        limit: usize,
+    ) -> std::result::Result<ListKVReply, MetaError> {
+        match self {
+            MetaStore::L(x) => x.prefix_list_kv_page(prefix, start_after, limit).await,
+            MetaStore::R(x) => x.prefix_list_kv_page(prefix, start_after, limit).await,
+        }
+    }
+
     async fn transaction(&self, txn: TxnRequest) -> std::result::Result<TxnReply, MetaError> {
         match self {
             MetaStore::L(x) => x.transaction(txn).await,
@@ -336,6 +336,96 @@ impl WrongShareObject {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("ShareDatabaseNotGranted: the database of {obj_name} is not granted to the share")]
+pub struct ShareDatabaseNotGranted {
+    obj_name: String,
+}
+
+impl ShareDatabaseNotGranted {
+    pub fn new(obj_name: impl Into<String>) -> Self {
+        Self {
+            obj_name: obj_name.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "ShareObjectDatabaseMismatch: {obj_name} does not belong to the database that is being shared"
+)]
+pub struct ShareObjectDatabaseMismatch {
+    obj_name: String,
+}
+
+impl ShareObjectDatabaseMismatch {
+    pub fn new(obj_name: impl Into<String>) -> Self {
+        Self {
+            obj_name: obj_name.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("CannotShareDroppedObject: {obj_name} has been dropped")]
+pub struct CannotShareDroppedObject {
+    obj_name: String,
+}
+
+impl CannotShareDroppedObject {
+    pub fn new(obj_name: impl Into<String>) -> Self {
+        Self {
+            obj_name: obj_name.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("CannotShareView: {obj_name} is a view")]
+pub struct CannotShareView {
+    obj_name: String,
+}
+
+impl CannotShareView {
+    pub fn new(obj_name: impl Into<String>) -> Self {
+        Self {
+            obj_name: obj_name.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("CannotShareProtectedObject: {obj_name} is in the protected database {db_name}")]
+pub struct CannotShareProtectedObject {
+    obj_name: String,
+    db_name: String,
+}
+
+impl CannotShareProtectedObject {
+    pub fn new(obj_name: impl Into<String>, db_name: impl Into<String>) -> Self {
+        Self {
+            obj_name: obj_name.into(),
+            db_name: db_name.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("InvalidSharePrivilege: {privilege} can not be granted on {obj_name}")]
+pub struct InvalidSharePrivilege {
+    privilege: String,
+    obj_name: String,
+}
+
+impl InvalidSharePrivilege {
+    pub fn new(privilege: impl Into<String>, obj_name: impl Into<String>) -> Self {
+        Self {
+            privilege: privilege.into(),
+            obj_name: obj_name.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("WrongShare: {share_name} has the wrong format")]
 pub struct WrongShare {
@@ -350,6 +440,44 @@ impl WrongShare {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("ShareCycleDetected: {account} of {share_name} while {context}")]
+pub struct ShareCycleDetected {
+    share_name: String,
+    account: String,
+    context: String,
+}
+
+impl ShareCycleDetected {
+    pub fn new(
+        share_name: impl Into<String>,
+        account: impl Into<String>,
+        context: impl Into<String>,
+    ) -> Self {
+        Self {
+            share_name: share_name.into(),
+            account: account.into(),
+            context: context.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
+#[error("ShareFrozen: {share_name} while {context}")]
+pub struct ShareFrozen {
+    share_name: String,
+    context: String,
+}
+
+impl ShareFrozen {
+    pub fn new(share_name: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            share_name: share_name.into(),
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
 #[error("UnknownShare: {share_name} while {context}")]
 pub struct UnknownShare {
@@ -383,10 +511,13 @@ impl UnknownShareId {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, thiserror::Error)]
-#[error("TxnRetryMaxTimes: Txn {op} has retry {max_retry} times, abort.")]
+#[error("TxnRetryMaxTimes: Txn {op} has retry {max_retry} times, abort.{}", last_conflict_suffix(.last_conflict))]
 pub struct TxnRetryMaxTimes {
     op: String,
     max_retry: u32,
+    /// The conditions failed in the last retry, None if unknown.
+    #[serde(default)]
+    last_conflict: Option<String>,
 }
 
 impl TxnRetryMaxTimes {
@@ -394,8 +525,21 @@ impl TxnRetryMaxTimes {
         Self {
             op: op.to_string(),
             max_retry,
+            last_conflict: None,
         }
     }
+
+    pub fn with_last_conflict(mut self, last_conflict: Option<String>) -> Self {
+        self.last_conflict = last_conflict;
+        self
+    }
+}
+
+fn last_conflict_suffix(last_conflict: &Option<String>) -> String {
+    match last_conflict {
+        Some(conflict) => format!(" last conflict: {}", conflict),
+        None => String::new(),
+    }
 }
 
 #[derive(thiserror::Error, serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -470,8 +614,32 @@ pub enum AppError {
     #[error(transparent)]
     WrongShareObject(#[from] WrongShareObject),
 
+    #[error(transparent)]
+    ShareDatabaseNotGranted(#[from] ShareDatabaseNotGranted),
+
+    #[error(transparent)]
+    ShareObjectDatabaseMismatch(#[from] ShareObjectDatabaseMismatch),
+
+    #[error(transparent)]
+    CannotShareDroppedObject(#[from] CannotShareDroppedObject),
+
+    #[error(transparent)]
+    CannotShareView(#[from] CannotShareView),
+
+    #[error(transparent)]
+    CannotShareProtectedObject(#[from] CannotShareProtectedObject),
+
+    #[error(transparent)]
+    InvalidSharePrivilege(#[from] InvalidSharePrivilege),
+
     #[error(transparent)]
     WrongShare(#[from] WrongShare),
+
+    #[error(transparent)]
+    ShareCycleDetected(#[from] ShareCycleDetected),
+
+    #[error(transparent)]
+    ShareFrozen(#[from] ShareFrozen),
 }
 
 impl AppErrorMessage for UnknownDatabase {
@@ -579,17 +747,88 @@ impl AppErrorMessage for WrongShareObject {
     }
 }
 
+impl AppErrorMessage for ShareDatabaseNotGranted {
+    fn message(&self) -> String {
+        format!(
+            "The database of {} must be granted to the share before it",
+            self.obj_name
+        )
+    }
+}
+
+impl AppErrorMessage for ShareObjectDatabaseMismatch {
+    fn message(&self) -> String {
+        format!(
+            "{} does not belong to the database that is being shared",
+            self.obj_name
+        )
+    }
+}
+
+impl AppErrorMessage for CannotShareDroppedObject {
+    fn message(&self) -> String {
+        format!("{} has been dropped and can not be shared", self.obj_name)
+    }
+}
+
+impl AppErrorMessage for CannotShareView {
+    fn message(&self) -> String {
+        format!(
+            "{} is a view, sharing views is not supported yet",
+            self.obj_name
+        )
+    }
+}
+
+impl AppErrorMessage for CannotShareProtectedObject {
+    fn message(&self) -> String {
+        format!(
+            "{} is in the protected database {}",
+            self.obj_name, self.db_name
+        )
+    }
+}
+
+impl AppErrorMessage for InvalidSharePrivilege {
+    fn message(&self) -> String {
+        format!(
+            "privilege {} can not be granted on {}",
+            self.privilege, self.obj_name
+        )
+    }
+}
+
 impl AppErrorMessage for WrongShare {
     fn message(&self) -> String {
         format!("share {} has the wrong format", self.share_name)
     }
 }
 
+impl AppErrorMessage for ShareCycleDetected {
+    fn message(&self) -> String {
+        format!(
+            "Account '{}' already shares to the provider of share '{}'",
+            self.account, self.share_name
+        )
+    }
+}
+
+impl AppErrorMessage for ShareFrozen {
+    fn message(&self) -> String {
+        format!(
+            "Share '{}' is frozen and can not be altered",
+            self.share_name
+        )
+    }
+}
+
 impl AppErrorMessage for TxnRetryMaxTimes {
     fn message(&self) -> String {
         format!(
-            "TxnRetryMaxTimes: Txn {} has retry {} times",
-            self.op, self.max_retry
+            "TxnRetryMaxTimes: Txn {} has retry {} times{}",
+            self.op,
+            self.max_retry,
+            last_conflict_suffix(&self.last_conflict)
         )
     }
 }
@@ -659,7 +898,23 @@ impl From<AppError> for ErrorCode {
             }
             AppError::UnknownShareAccounts(err) => ErrorCode::UnknownShareAccounts(err.message()),
             AppError::WrongShareObject(err) => ErrorCode::WrongShareObject(err.message()),
+            AppError::ShareDatabaseNotGranted(err) => {
+                ErrorCode::ShareDatabaseNotGranted(err.message())
+            }
+            AppError::ShareObjectDatabaseMismatch(err) => {
+                ErrorCode::ShareObjectDatabaseMismatch(err.message())
+            }
+            AppError::CannotShareDroppedObject(err) => {
+                ErrorCode::CannotShareDroppedObject(err.message())
+            }
+            AppError::CannotShareView(err) => ErrorCode::CannotShareView(err.message()),
+            AppError::CannotShareProtectedObject(err) => {
+                ErrorCode::CannotShareProtectedObject(err.message())
+            }
+            AppError::InvalidSharePrivilege(err) => ErrorCode::InvalidSharePrivilege(err.message()),
             AppError::WrongShare(err) => ErrorCode::WrongShare(err.message()),
+            AppError::ShareCycleDetected(err) => ErrorCode::ShareCycleDetected(err.message()),
+            AppError::ShareFrozen(err) => ErrorCode::ShareFrozen(err.message()),
             AppError::TxnRetryMaxTimes(err) => ErrorCode::TxnRetryMaxTimes(err.message()),
         }
     }
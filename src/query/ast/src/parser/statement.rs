@@ -816,9 +816,9 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let desc_share = map(
         rule! {
-            (DESC | DESCRIBE) ~ SHARE ~ #ident
+            (DESC | DESCRIBE) ~ SHARE ~ #peroid_separated_idents_1_to_2
         },
-        |(_, _, share)| Statement::DescShare(DescShareStmt { share }),
+        |(_, _, (tenant, share))| Statement::DescShare(DescShareStmt { tenant, share }),
     );
     let show_shares = map(
         rule! {
@@ -87,11 +87,42 @@ impl TryInto<Vec<u8>> for InitNodesChannel {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ReadTracingLogs {
+    pub limit: Option<usize>,
+}
+
+impl TryInto<ReadTracingLogs> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<ReadTracingLogs, Self::Error> {
+        match std::str::from_utf8(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(utf8_body) => match serde_json::from_str::<ReadTracingLogs>(utf8_body) {
+                Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+                Ok(action) => Ok(action),
+            },
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for ReadTracingLogs {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(ErrorCode::LogicalError, || {
+            "Logical error: cannot serialize ReadTracingLogs."
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     InitQueryFragmentsPlan(InitQueryFragmentsPlan),
     InitNodesChannel(InitNodesChannel),
     ExecutePartialQuery(String),
+    ReadTracingLogs(ReadTracingLogs),
+    ReadMetrics,
 }
 
 impl TryInto<FlightAction> for Action {
@@ -107,6 +138,8 @@ impl TryInto<FlightAction> for Action {
                 Ok(query_id) => Ok(FlightAction::ExecutePartialQuery(query_id)),
                 Err(cause) => Err(Status::invalid_argument(cause.to_string())),
             },
+            "ReadTracingLogs" => Ok(FlightAction::ReadTracingLogs(self.body.try_into()?)),
+            "ReadMetrics" => Ok(FlightAction::ReadMetrics),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -132,6 +165,14 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("ExecutePartialQuery"),
                 body: query_id.into_bytes(),
             }),
+            FlightAction::ReadTracingLogs(read_tracing_logs) => Ok(Action {
+                r#type: String::from("ReadTracingLogs"),
+                body: read_tracing_logs.try_into()?,
+            }),
+            FlightAction::ReadMetrics => Ok(Action {
+                r#type: String::from("ReadMetrics"),
+                body: vec![],
+            }),
         }
     }
 }
@@ -26,6 +26,8 @@ use common_arrow::arrow_format::flight::service::flight_service_client::FlightSe
 use common_base::base::tokio::time::Duration;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_metrics::MetricSample;
 use futures_util::StreamExt;
 use tonic::metadata::MetadataKey;
 use tonic::metadata::MetadataValue;
@@ -35,6 +37,7 @@ use tonic::Status;
 use tonic::Streaming;
 
 use crate::api::rpc::flight_actions::FlightAction;
+use crate::api::rpc::flight_actions::ReadTracingLogs;
 use crate::api::rpc::packets::DataPacket;
 use crate::api::rpc::packets::DataPacketStream;
 use crate::api::rpc::request_builder::RequestBuilder;
@@ -54,6 +57,27 @@ impl FlightClient {
         Ok(())
     }
 
+    /// Reads the lines of the log files of the node, at most `limit` lines.
+    pub async fn read_tracing_logs(
+        &mut self,
+        limit: Option<usize>,
+        timeout: u64,
+    ) -> Result<Vec<String>> {
+        let action = FlightAction::ReadTracingLogs(ReadTracingLogs { limit });
+        let body = self.do_action(action, timeout).await?;
+        serde_json::from_slice(&body).map_err_to_code(ErrorCode::BadBytes, || {
+            "Cannot deserialize the tracing logs from flight server."
+        })
+    }
+
+    /// Reads the metric samples of the node.
+    pub async fn read_metric_samples(&mut self, timeout: u64) -> Result<Vec<MetricSample>> {
+        let body = self.do_action(FlightAction::ReadMetrics, timeout).await?;
+        serde_json::from_slice(&body).map_err_to_code(ErrorCode::BadBytes, || {
+            "Cannot deserialize the metric samples from flight server."
+        })
+    }
+
     fn set_metadata<T>(request: &mut Request<T>, name: &'static str, value: &str) -> Result<()> {
         match MetadataValue::try_from(value) {
             Ok(metadata_value) => {
@@ -41,6 +41,12 @@ use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::rpc::request_builder::RequestGetter;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::storages::system::MetricsTable;
+use crate::storages::system::TracingTable;
+
+// The most log lines a node returns for a `ReadTracingLogs` action, so a query without limit
+// does not send the whole log directory over one response.
+const READ_TRACING_LOGS_MAX_LINES: usize = 10000;
 
 pub type FlightStream<T> =
     Pin<Box<dyn Stream<Item = Result<T, tonic::Status>> + Send + Sync + 'static>>;
@@ -167,6 +173,26 @@ impl FlightService for DatabendQueryFlightService {
 
                 FlightResult { body: vec![] }
             }
+            FlightAction::ReadTracingLogs(read_tracing_logs) => {
+                let log_dir = self.sessions.get_conf().log.file.dir;
+                let limit = read_tracing_logs
+                    .limit
+                    .map_or(READ_TRACING_LOGS_MAX_LINES, |limit| {
+                        limit.min(READ_TRACING_LOGS_MAX_LINES)
+                    });
+                let lines = TracingTable::read_log_lines(&log_dir, Some(limit))?;
+                let body = serde_json::to_vec(&lines)
+                    .map_err(|cause| Status::internal(cause.to_string()))?;
+
+                FlightResult { body }
+            }
+            FlightAction::ReadMetrics => {
+                let samples = MetricsTable::local_samples()?;
+                let body = serde_json::to_vec(&samples)
+                    .map_err(|cause| Status::internal(cause.to_string()))?;
+
+                FlightResult { body }
+            }
         };
 
         Ok(RawResponse::new(
@@ -42,10 +42,16 @@ impl SystemDatabase {
             system::TablesTableWithHistory::create(sys_db_meta.next_table_id()),
             system::ClustersTable::create(sys_db_meta.next_table_id()),
             system::DatabasesTable::create(sys_db_meta.next_table_id()),
-            Arc::new(system::TracingTable::create(sys_db_meta.next_table_id())),
+            Arc::new(
+                system::TracingTable::create(sys_db_meta.next_table_id())
+                    .with_remote_reader(Arc::new(system::FlightNodeReader)),
+            ),
             system::ProcessesTable::create(sys_db_meta.next_table_id()),
             system::ConfigsTable::create(sys_db_meta.next_table_id()),
-            system::MetricsTable::create(sys_db_meta.next_table_id()),
+            system::MetricsTable::create_with_remote_reader(
+                sys_db_meta.next_table_id(),
+                Arc::new(system::FlightNodeReader),
+            ),
             system::ColumnsTable::create(sys_db_meta.next_table_id()),
             system::UsersTable::create(sys_db_meta.next_table_id()),
             Arc::new(system::QueryLogTable::create(
@@ -55,6 +61,23 @@ impl SystemDatabase {
             system::EnginesTable::create(sys_db_meta.next_table_id()),
             system::RolesTable::create(sys_db_meta.next_table_id()),
             system::StagesTable::create(sys_db_meta.next_table_id()),
+            Arc::new(system::QueryProfileTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
+            Arc::new(system::ClusteringHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_clustering_history_size,
+            )),
+            system::TableOptionsTable::create(sys_db_meta.next_table_id()),
+            system::InboundSharesTable::create(sys_db_meta.next_table_id()),
+            system::SharesTable::create(sys_db_meta.next_table_id()),
+            Arc::new(system::VacuumHistoryTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_vacuum_history_size,
+            )),
+            system::StageUsageTable::create(sys_db_meta.next_table_id()),
+            system::SharePrivilegesTable::create(sys_db_meta.next_table_id()),
         ];
 
         for tbl in table_list.into_iter() {
@@ -22,6 +22,7 @@ use common_streams::ErrorStream;
 use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use parking_lot::Mutex;
+use tracing::warn;
 
 use crate::interpreters::access::ManagementModeAccess;
 use crate::interpreters::Interpreter;
@@ -99,6 +100,10 @@ impl Interpreter for InterceptorInterpreter {
     async fn start(&self) -> Result<()> {
         let session = self.ctx.get_current_session();
         let now = SystemTime::now();
+        session
+            .get_status()
+            .write()
+            .query_start(now, self.ctx.get_query_str());
         if session.get_type().is_user_session() {
             session
                 .get_session_manager()
@@ -121,7 +126,13 @@ impl Interpreter for InterceptorInterpreter {
                 .query_finish(now)
         }
         let error = self.ctx.get_error_value();
-        self.query_log.log_finish(now, error).await
+        self.query_log.log_finish(now, error).await?;
+
+        // The profiles are best effort, failing to log them does not fail the query.
+        if let Err(cause) = self.query_log.log_profiles().await {
+            warn!("failed to log the profiles of the query: {}", cause);
+        }
+        Ok(())
     }
 
     fn set_source_pipe_builder(&self, builder: Option<SourcePipeBuilder>) -> Result<()> {
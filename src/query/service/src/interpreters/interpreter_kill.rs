@@ -25,6 +25,7 @@ use common_streams::SendableDataBlockStream;
 
 use crate::interpreters::Interpreter;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 
 pub struct KillInterpreter {
     ctx: Arc<QueryContext>,
@@ -37,18 +38,28 @@ impl KillInterpreter {
     }
 
     async fn execute_kill(&self, session_id: &String) -> Result<SendableDataBlockStream> {
+        let aborted_by = format!(
+            "{} by {}",
+            if self.plan.kill_connection {
+                "KILL CONNECTION"
+            } else {
+                "KILL QUERY"
+            },
+            self.ctx.get_current_user()?.identity()
+        );
+
         match self.ctx.get_session_by_id(session_id).await {
             None => Err(ErrorCode::UnknownSession(format!(
                 "Not found session id {}",
                 session_id
             ))),
             Some(kill_session) if self.plan.kill_connection => {
-                kill_session.force_kill_session();
+                kill_session.force_kill_session_by(aborted_by);
                 let schema = Arc::new(DataSchema::empty());
                 Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
             }
             Some(kill_session) => {
-                kill_session.force_kill_query();
+                kill_session.force_kill_query_by(aborted_by);
                 let schema = Arc::new(DataSchema::empty());
                 Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
             }
@@ -35,6 +35,7 @@ use tracing::subscriber;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::storages::system::QueryLogTable;
+use crate::storages::system::QueryProfileTable;
 
 #[derive(Clone, Copy, Serialize_repr)]
 #[repr(u8)]
@@ -79,6 +80,7 @@ pub struct LogEvent {
     // Query.
     pub query_id: String,
     pub query_kind: String,
+    pub query_category: String,
     pub query_text: String,
 
     #[serde(serialize_with = "date_str")]
@@ -112,11 +114,13 @@ pub struct LogEvent {
     // Client.
     pub client_info: String,
     pub client_address: String,
+    pub client_user_agent: String,
 
     // Exception.
     pub exception_code: i32,
     pub exception_text: String,
     pub stack_trace: String,
+    pub aborted_by: Option<String>,
 
     // Server.
     pub server_version: String,
@@ -129,6 +133,28 @@ pub struct LogEvent {
     pub extra: String,
 }
 
+/// The coarse kind of the statement, by its leading keyword, `UNKNOWN` if it is not recognized.
+fn query_category(query_text: &str) -> &'static str {
+    let keyword = query_text
+        .trim_start_matches(|c: char| c.is_whitespace() || c == '(')
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+
+    match keyword.as_str() {
+        "SELECT" | "WITH" => "SELECT",
+        "INSERT" | "REPLACE" => "INSERT",
+        "DELETE" => "DELETE",
+        "COPY" => "COPY",
+        "CREATE" | "DROP" | "UNDROP" | "ALTER" | "RENAME" | "TRUNCATE" | "OPTIMIZE" => "DDL",
+        "GRANT" | "REVOKE" => "DCL",
+        "SHOW" | "DESC" | "DESCRIBE" | "EXISTS" => "SHOW",
+        "EXPLAIN" => "EXPLAIN",
+        _ => "UNKNOWN",
+    }
+}
+
 #[derive(Clone)]
 pub struct InterpreterQueryLog {
     ctx: Arc<QueryContext>,
@@ -168,7 +194,11 @@ impl InterpreterQueryLog {
             .ctx
             .get_table(CATALOG_DEFAULT, "system", "query_log")
             .await?;
-        let schema = query_log.get_table_info().meta.schema.clone();
+        let query_log_table: &QueryLogTable = query_log.as_any().downcast_ref().unwrap();
+        let schema = query_log_table.log_schema();
+
+        // Exception columns are NULL for the queries without error.
+        let failed = event.exception_code != 0;
 
         let block = DataBlock::create(schema.clone(), vec![
             // Type.
@@ -183,6 +213,7 @@ impl InterpreterQueryLog {
             // Query.
             Series::from_data(vec![event.query_id.as_str()]),
             Series::from_data(vec![event.query_kind.as_str()]),
+            Series::from_data(vec![event.query_category.as_str()]),
             Series::from_data(vec![event.query_text.as_str()]),
             Series::from_data(vec![event.event_date as i32]),
             Series::from_data(vec![event.event_time as i64]),
@@ -210,10 +241,12 @@ impl InterpreterQueryLog {
             // Client.
             Series::from_data(vec![event.client_info.as_str()]),
             Series::from_data(vec![event.client_address.as_str()]),
+            Series::from_data(vec![event.client_user_agent.as_str()]),
             // Exception.
-            Series::from_data(vec![event.exception_code]),
-            Series::from_data(vec![event.exception_text.as_str()]),
+            Series::from_data(vec![failed.then(|| event.exception_code)]),
+            Series::from_data(vec![failed.then(|| event.exception_text.as_str())]),
             Series::from_data(vec![event.stack_trace.as_str()]),
+            Series::from_data(vec![event.aborted_by.as_deref()]),
             // Server.
             Series::from_data(vec![event.server_version.as_str()]),
             // Session settings
@@ -224,7 +257,6 @@ impl InterpreterQueryLog {
         let blocks = vec![Ok(block)];
         let input_stream = futures::stream::iter::<Vec<Result<DataBlock>>>(blocks);
 
-        let query_log_table: &QueryLogTable = query_log.as_any().downcast_ref().unwrap();
         query_log_table
             .append_data(self.ctx.clone(), Box::pin(input_stream))
             .await?;
@@ -265,6 +297,7 @@ impl InterpreterQueryLog {
         let query_id = self.ctx.get_id();
         let query_kind = self.query_kind.clone();
         let query_text = self.ctx.get_query_str();
+        let query_category = query_category(&query_text).to_string();
         // Schema.
         let current_database = self.ctx.get_current_database();
 
@@ -295,6 +328,7 @@ impl InterpreterQueryLog {
             Some(addr) => format!("{:?}", addr),
             None => "".to_string(),
         };
+        let client_user_agent = self.ctx.get_client_user_agent().unwrap_or_default();
 
         // Session settings
         let mut session_settings = String::new();
@@ -311,6 +345,7 @@ impl InterpreterQueryLog {
         // Error
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Start, err);
+        let aborted_by = None;
 
         let log_event = LogEvent {
             log_type,
@@ -322,6 +357,7 @@ impl InterpreterQueryLog {
             sql_user_privileges,
             query_id,
             query_kind,
+            query_category,
             query_text,
             event_date,
             event_time,
@@ -346,10 +382,12 @@ impl InterpreterQueryLog {
             memory_usage,
             client_info: "".to_string(),
             client_address,
+            client_user_agent,
 
             exception_code,
             exception_text,
             stack_trace,
+            aborted_by,
             server_version: "".to_string(),
             session_settings,
             extra: "".to_string(),
@@ -372,6 +410,7 @@ impl InterpreterQueryLog {
         let query_id = self.ctx.get_id();
         let query_kind = self.query_kind.clone();
         let query_text = self.ctx.get_query_str();
+        let query_category = query_category(&query_text).to_string();
 
         // Stats.
         let event_time = now
@@ -405,6 +444,7 @@ impl InterpreterQueryLog {
             Some(addr) => format!("{:?}", addr),
             None => "".to_string(),
         };
+        let client_user_agent = self.ctx.get_client_user_agent().unwrap_or_default();
 
         // Schema.
         let current_database = self.ctx.get_current_database();
@@ -424,6 +464,8 @@ impl InterpreterQueryLog {
         // Error
         let (log_type, exception_code, exception_text, stack_trace) =
             error_fields(LogType::Finish, err);
+        // Only set if the query is killed, errors and normal completions leave it NULL.
+        let aborted_by = self.ctx.get_aborted_by();
 
         let log_event = LogEvent {
             log_type,
@@ -435,6 +477,7 @@ impl InterpreterQueryLog {
             sql_user_privileges,
             query_id,
             query_kind,
+            query_category,
             query_text,
             event_date,
             event_time,
@@ -458,11 +501,13 @@ impl InterpreterQueryLog {
             memory_usage,
             client_info: "".to_string(),
             client_address,
+            client_user_agent,
             current_database,
 
             exception_code,
             exception_text,
             stack_trace,
+            aborted_by,
             server_version: "".to_string(),
             session_settings,
             extra: "".to_string(),
@@ -470,4 +515,57 @@ impl InterpreterQueryLog {
 
         self.write_log(&log_event).await
     }
+
+    /// Writes the operator profiles of the executors attached to the query to system.query_profile.
+    pub async fn log_profiles(&self) -> Result<()> {
+        let executor_profiles = self.ctx.get_executor_profiles();
+        if executor_profiles.is_empty() {
+            return Ok(());
+        }
+
+        let query_profile = self
+            .ctx
+            .get_table(CATALOG_DEFAULT, "system", "query_profile")
+            .await?;
+        let schema = query_profile.schema();
+        let query_profile_table: &QueryProfileTable =
+            query_profile.as_any().downcast_ref().unwrap();
+
+        let query_id = self.ctx.get_id();
+        for profiles in executor_profiles {
+            let profiles = profiles.get_profiles();
+            let block = DataBlock::create(schema.clone(), vec![
+                Series::from_data(vec![query_id.as_str(); profiles.len()]),
+                Series::from_data(profiles.iter().map(|p| p.id as u64).collect::<Vec<_>>()),
+                Series::from_data(profiles.iter().map(|p| p.name).collect::<Vec<_>>()),
+                Series::from_data(
+                    profiles
+                        .iter()
+                        .map(|p| p.output_rows as u64)
+                        .collect::<Vec<_>>(),
+                ),
+                Series::from_data(
+                    profiles
+                        .iter()
+                        .map(|p| p.output_bytes as u64)
+                        .collect::<Vec<_>>(),
+                ),
+                Series::from_data(
+                    profiles
+                        .iter()
+                        .map(|p| p.wall_time.as_micros() as u64)
+                        .collect::<Vec<_>>(),
+                ),
+                Series::from_data(
+                    profiles
+                        .iter()
+                        .map(|p| p.cpu_time.as_micros() as u64)
+                        .collect::<Vec<_>>(),
+                ),
+            ]);
+            query_profile_table.store().append(&query_id, block);
+        }
+
+        Ok(())
+    }
 }
@@ -91,9 +91,11 @@ impl Interpreter for SelectInterpreter {
         let build_res = self.build_pipeline().await?;
         let async_runtime = self.ctx.get_storage_runtime();
         let query_need_abort = self.ctx.query_need_abort();
-        Ok(Box::pin(ProcessorExecutorStream::create(
-            PipelinePullingExecutor::from_pipelines(async_runtime, query_need_abort, build_res)?,
-        )?))
+        let executor =
+            PipelinePullingExecutor::from_pipelines(async_runtime, query_need_abort, build_res)?;
+        self.ctx
+            .attach_executor_profiles(executor.get_inner().get_profiles())?;
+        Ok(Box::pin(ProcessorExecutorStream::create(executor)?))
     }
 
     /// This method will create a new pipeline
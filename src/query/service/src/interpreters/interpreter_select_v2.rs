@@ -102,12 +102,15 @@ impl Interpreter for SelectInterpreterV2 {
                 .await;
         }
 
+        let executor = PipelinePullingExecutor::from_pipelines(
+            self.ctx.get_storage_runtime(),
+            self.ctx.query_need_abort(),
+            build_res,
+        )?;
+        self.ctx
+            .attach_executor_profiles(executor.get_inner().get_profiles())?;
         Ok(Box::pin(Box::pin(ProcessorExecutorStream::create(
-            PipelinePullingExecutor::from_pipelines(
-                self.ctx.get_storage_runtime(),
-                self.ctx.query_need_abort(),
-                build_res,
-            )?,
+            executor,
         )?)))
     }
 
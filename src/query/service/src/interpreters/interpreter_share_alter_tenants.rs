@@ -14,7 +14,11 @@
 
 use std::sync::Arc;
 
+use common_datablocks::DataBlock;
 use common_datavalues::chrono::Utc;
+use common_datavalues::prelude::DataSchemaRef;
+use common_datavalues::prelude::Series;
+use common_datavalues::SeriesFrom;
 use common_exception::Result;
 use common_meta_api::ShareApi;
 use common_meta_app::share::AddShareAccountsReq;
@@ -45,10 +49,20 @@ impl Interpreter for AlterShareTenantsInterpreter {
         "AlterShareTenantsInterpreter"
     }
 
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let tenant = self.ctx.get_tenant();
         let user_mgr = self.ctx.get_user_manager();
         let meta_api = user_mgr.get_meta_store_client();
+        let mut accounts: Vec<String> = vec![];
+        let mut actions: Vec<&str> = vec![];
+        let mut push = |names: Vec<String>, action| {
+            actions.extend(std::iter::repeat(action).take(names.len()));
+            accounts.extend(names);
+        };
         if self.plan.is_add {
             let req = AddShareAccountsReq {
                 share_name: ShareNameIdent {
@@ -57,9 +71,15 @@ impl Interpreter for AlterShareTenantsInterpreter {
                 },
                 if_exists: self.plan.if_exists,
                 accounts: self.plan.accounts.clone(),
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
                 share_on: Utc::now(),
             };
-            meta_api.add_share_tenants(req).await?;
+            let reply = meta_api.add_share_tenants(req).await?;
+            push(reply.added_accounts, "ADDED");
+            push(reply.updated_accounts, "UPDATED");
+            push(reply.skipped_accounts, "SKIPPED");
         } else {
             let req = RemoveShareAccountsReq {
                 share_name: ShareNameIdent {
@@ -69,13 +89,16 @@ impl Interpreter for AlterShareTenantsInterpreter {
                 if_exists: self.plan.if_exists,
                 accounts: self.plan.accounts.clone(),
             };
-            meta_api.remove_share_tenants(req).await?;
+            let reply = meta_api.remove_share_tenants(req).await?;
+            push(reply.removed_accounts, "REMOVED");
+            push(reply.not_found_accounts, "SKIPPED");
         }
 
-        Ok(Box::pin(DataBlockStream::create(
-            self.plan.schema(),
-            None,
-            vec![],
-        )))
+        let schema = self.plan.schema();
+        let block = DataBlock::create(schema.clone(), vec![
+            Series::from_data(accounts),
+            Series::from_data(actions),
+        ]);
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![block])))
     }
 }
@@ -56,13 +56,29 @@ impl Interpreter for DescShareInterpreter {
     async fn execute(&self) -> Result<SendableDataBlockStream> {
         let user_mgr = self.ctx.get_user_manager();
         let meta_api = user_mgr.get_meta_store_client();
-        let req = GetShareGrantObjectReq {
-            share_name: ShareNameIdent {
-                tenant: self.ctx.get_tenant(),
-                share_name: self.plan.share.clone(),
-            },
+        let tenant = self.ctx.get_tenant();
+        let resp = match &self.plan.tenant {
+            // A consumer describing a share of another tenant accesses it.
+            Some(provider) if *provider != tenant => {
+                let share_name = ShareNameIdent {
+                    tenant: provider.clone(),
+                    share_name: self.plan.share.clone(),
+                };
+                meta_api
+                    .get_inbound_share_grant_objects(tenant, share_name)
+                    .await?
+            }
+            _ => {
+                let req = GetShareGrantObjectReq {
+                    share_name: ShareNameIdent {
+                        tenant,
+                        share_name: self.plan.share.clone(),
+                    },
+                    include_dropped: false,
+                };
+                meta_api.get_share_grant_objects(req).await?
+            }
         };
-        let resp = meta_api.get_share_grant_objects(req).await?;
         if resp.objects.is_empty() {
             return Ok(Box::pin(DataBlockStream::create(
                 DataSchemaRefExt::create(vec![]),
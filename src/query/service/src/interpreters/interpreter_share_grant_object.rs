@@ -54,8 +54,9 @@ impl Interpreter for GrantShareObjectInterpreter {
                 share_name: self.plan.share.clone(),
             },
             object: self.plan.object.clone(),
-            privilege: self.plan.privilege,
+            privilege: self.plan.privilege.into(),
             grant_on: Utc::now(),
+            protected_databases: self.ctx.get_config().query.share_protected_databases,
         };
         meta_api.grant_share_object(req).await?;
 
@@ -14,7 +14,6 @@
 
 use std::sync::Arc;
 
-use common_datavalues::chrono::Utc;
 use common_exception::Result;
 use common_meta_api::ShareApi;
 use common_meta_app::share::RevokeShareObjectReq;
@@ -54,8 +53,7 @@ impl Interpreter for RevokeShareObjectInterpreter {
                 share_name: self.plan.share.clone(),
             },
             object: self.plan.object.clone(),
-            privilege: self.plan.privilege,
-            update_on: Utc::now(),
+            privilege: self.plan.privilege.into(),
         };
         meta_api.revoke_share_object(req).await?;
 
@@ -55,9 +55,7 @@ impl Interpreter for ShowSharesInterpreter {
         let user_mgr = self.ctx.get_user_manager();
         let meta_api = user_mgr.get_meta_store_client();
         let tenant = self.ctx.get_tenant();
-        let req = ShowSharesReq {
-            tenant: tenant.clone(),
-        };
+        let req = ShowSharesReq::new(tenant.clone());
         let resp = meta_api.show_shares(req).await?;
         if resp.inbound_accounts.is_empty() && resp.outbound_accounts.is_empty() {
             return Ok(Box::pin(DataBlockStream::create(
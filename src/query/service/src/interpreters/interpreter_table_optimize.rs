@@ -13,7 +13,15 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::Series;
+use common_datavalues::prelude::SeriesFrom;
+use common_datavalues::ColumnRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::OptimizeTableAction;
 use common_planners::OptimizeTablePlan;
@@ -25,7 +33,11 @@ use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::Pipeline;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::storages::fuse::operations::PurgeStatistics;
 use crate::storages::fuse::FuseTable;
+use crate::storages::system::ClusteringHistoryTable;
+use crate::storages::system::VacuumHistoryTable;
+
 pub struct OptimizeTableInterpreter {
     ctx: Arc<QueryContext>,
     plan: OptimizeTablePlan,
@@ -53,6 +65,7 @@ impl OptimizeTableInterpreter {
             .await?;
         let table = FuseTable::try_from_table(table.as_ref())?;
 
+        let start_time = SystemTime::now();
         let mut pipeline = Pipeline::create();
 
         let mutator = table.try_get_recluster_mutator(ctx.clone()).await?;
@@ -85,10 +98,96 @@ impl OptimizeTableInterpreter {
         drop(executor);
 
         let catalog_name = ctx.get_current_catalog();
-        mutator.commit_recluster(&catalog_name).await?;
+        let segments_reclustered = mutator.segments_reclustered() as u64;
+        let written = mutator.commit_recluster(&catalog_name).await?;
+
+        self.log_clustering_history(
+            database,
+            tbl_name,
+            start_time,
+            segments_reclustered,
+            written.compressed_byte_size,
+        )
+        .await?;
 
         Ok(false)
     }
+
+    /// Writes a committed recluster to system.clustering_history.
+    async fn log_clustering_history(
+        &self,
+        database: &str,
+        tbl_name: &str,
+        start_time: SystemTime,
+        segments_reclustered: u64,
+        bytes_written: u64,
+    ) -> Result<()> {
+        let columns = vec![
+            Series::from_data(vec![database]),
+            Series::from_data(vec![tbl_name]),
+            Series::from_data(vec![unix_micros(start_time)]),
+            Series::from_data(vec![unix_micros(SystemTime::now())]),
+            Series::from_data(vec![segments_reclustered]),
+            Series::from_data(vec![bytes_written]),
+        ];
+        self.log_history(
+            "clustering_history",
+            columns,
+            |table: &ClusteringHistoryTable, block| table.store().append(block),
+        )
+        .await
+    }
+
+    /// Writes a purge of the table history to system.vacuum_history.
+    async fn log_vacuum_history(
+        &self,
+        database: &str,
+        tbl_name: &str,
+        purged: PurgeStatistics,
+    ) -> Result<()> {
+        let columns = vec![
+            Series::from_data(vec![database]),
+            Series::from_data(vec![tbl_name]),
+            Series::from_data(vec![purged.purged_snapshots]),
+            Series::from_data(vec![purged.purged_bytes]),
+            Series::from_data(vec![unix_micros(SystemTime::now())]),
+        ];
+        self.log_history(
+            "vacuum_history",
+            columns,
+            |table: &VacuumHistoryTable, block| table.store().append(block),
+        )
+        .await
+    }
+
+    /// Appends a row of `columns` to the in-memory history table `system.<table_name>` of type `T`.
+    async fn log_history<T: 'static>(
+        &self,
+        table_name: &str,
+        columns: Vec<ColumnRef>,
+        append: impl FnOnce(&T, DataBlock),
+    ) -> Result<()> {
+        let table = self
+            .ctx
+            .get_table(CATALOG_DEFAULT, "system", table_name)
+            .await?;
+        let history_table: &T = table.as_any().downcast_ref().ok_or_else(|| {
+            ErrorCode::LogicalError(format!(
+                "system.{} is not a {}",
+                table_name,
+                std::any::type_name::<T>()
+            ))
+        })?;
+
+        append(history_table, DataBlock::create(table.schema(), columns));
+        Ok(())
+    }
+}
+
+fn unix_micros(t: SystemTime) -> i64 {
+    t.duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as i64
 }
 
 #[async_trait::async_trait]
@@ -152,7 +251,14 @@ impl Interpreter for OptimizeTableInterpreter {
         }
 
         if do_purge {
-            table.optimize(self.ctx.clone(), true).await?;
+            match FuseTable::try_from_table(table.as_ref()) {
+                Ok(fuse_table) => {
+                    let purged = fuse_table.purge(self.ctx.clone(), true).await?;
+                    self.log_vacuum_history(&plan.database, &plan.table, purged)
+                        .await?;
+                }
+                Err(_) => table.optimize(self.ctx.clone(), true).await?,
+            }
         }
 
         Ok(Box::pin(DataBlockStream::create(
@@ -27,6 +27,7 @@ use petgraph::prelude::StableGraph;
 use petgraph::Direction;
 use tracing::debug;
 
+use crate::pipelines::executor::executor_profile::ExecutorProfiles;
 use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::executor::executor_worker_context::ExecutorTask;
 use crate::pipelines::executor::executor_worker_context::ExecutorWorkerContext;
@@ -55,7 +56,6 @@ struct Node {
     updated_list: Arc<UpdateList>,
     #[allow(dead_code)]
     inputs_port: Vec<Arc<InputPort>>,
-    #[allow(dead_code)]
     outputs_port: Vec<Arc<OutputPort>>,
 }
 
@@ -368,6 +368,22 @@ impl RunningGraph {
         Ok(schedule_queue)
     }
 
+    pub fn create_profiles(&self) -> ExecutorProfiles {
+        // The graph never removes nodes, so the node indices are contiguous.
+        let processors = self
+            .0
+            .graph
+            .node_indices()
+            .map(|index| {
+                let node = &self.0.graph[index];
+                let name = unsafe { node.processor.name() };
+                (name, node.outputs_port.clone())
+            })
+            .collect();
+
+        ExecutorProfiles::create(processors)
+    }
+
     pub fn check_finished(&self) -> Result<()> {
         let mut unfinished_nodes = vec![];
         for node_index in self.0.graph.node_indices() {
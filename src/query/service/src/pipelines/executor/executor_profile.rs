@@ -0,0 +1,131 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use petgraph::prelude::NodeIndex;
+
+use crate::pipelines::processors::port::OutputPort;
+
+/// The profile of one processor of the executed pipeline.
+#[derive(Clone, Debug)]
+pub struct ProcessorProfile {
+    pub id: usize,
+    pub name: &'static str,
+    pub output_rows: usize,
+    pub output_bytes: usize,
+    /// Time spent in `process` and `async_process`.
+    pub wall_time: Duration,
+    /// CPU time of the executor threads spent in `process`.
+    pub cpu_time: Duration,
+}
+
+struct ProcessorCounters {
+    name: &'static str,
+    outputs_port: Vec<Arc<OutputPort>>,
+    wall_time_ns: AtomicU64,
+    cpu_time_ns: AtomicU64,
+}
+
+/// Collects the profile of each processor while the pipeline is executing.
+///
+/// Nothing is collected until it is enabled, see the `enable_query_profiling` setting.
+/// It only holds the output ports of the processors,
+/// so it can outlive the executor to be read after the query is finished.
+pub struct ExecutorProfiles {
+    enabled: AtomicBool,
+    // Indexed by the node index of the processor in the executing graph.
+    processors: Vec<ProcessorCounters>,
+}
+
+impl ExecutorProfiles {
+    pub(crate) fn create(processors: Vec<(&'static str, Vec<Arc<OutputPort>>)>) -> Self {
+        ExecutorProfiles {
+            enabled: AtomicBool::new(false),
+            processors: processors
+                .into_iter()
+                .map(|(name, outputs_port)| ProcessorCounters {
+                    name,
+                    outputs_port,
+                    wall_time_ns: AtomicU64::new(0),
+                    cpu_time_ns: AtomicU64::new(0),
+                })
+                .collect(),
+        }
+    }
+
+    /// Start collecting, it has to be called before the pipeline is executed.
+    pub fn enable(&self) {
+        self.enabled.store(true, Ordering::Relaxed);
+        for counters in &self.processors {
+            for output_port in &counters.outputs_port {
+                output_port.enable_profiling();
+            }
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record_sync(&self, index: NodeIndex, wall_time: Duration, cpu_time: Duration) {
+        if let Some(counters) = self.processors.get(index.index()) {
+            counters
+                .wall_time_ns
+                .fetch_add(wall_time.as_nanos() as u64, Ordering::Relaxed);
+            counters
+                .cpu_time_ns
+                .fetch_add(cpu_time.as_nanos() as u64, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_async(&self, index: NodeIndex, elapsed: Duration) {
+        if !self.is_enabled() {
+            return;
+        }
+        if let Some(counters) = self.processors.get(index.index()) {
+            let elapsed = elapsed.as_nanos() as u64;
+            counters.wall_time_ns.fetch_add(elapsed, Ordering::Relaxed);
+        }
+    }
+
+    /// The thread CPU time spent by all the processors, zero unless profiling is enabled.
+    pub fn total_cpu_time(&self) -> Duration {
+        Duration::from_nanos(
+            self.processors
+                .iter()
+                .map(|counters| counters.cpu_time_ns.load(Ordering::Relaxed))
+                .sum(),
+        )
+    }
+
+    pub fn get_profiles(&self) -> Vec<ProcessorProfile> {
+        self.processors
+            .iter()
+            .enumerate()
+            .map(|(id, counters)| ProcessorProfile {
+                id,
+                name: counters.name,
+                output_rows: counters.outputs_port.iter().map(|x| x.pushed_rows()).sum(),
+                output_bytes: counters.outputs_port.iter().map(|x| x.pushed_bytes()).sum(),
+                wall_time: Duration::from_nanos(counters.wall_time_ns.load(Ordering::Relaxed)),
+                cpu_time: Duration::from_nanos(counters.cpu_time_ns.load(Ordering::Relaxed)),
+            })
+            .collect()
+    }
+}
@@ -16,6 +16,7 @@ use std::collections::VecDeque;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::tokio::sync::Notify;
 use common_exception::Result;
@@ -198,14 +199,21 @@ pub struct CompletedAsyncTask {
     pub id: NodeIndex,
     pub worker_id: usize,
     pub res: Result<()>,
+    pub elapsed: Duration,
 }
 
 impl CompletedAsyncTask {
-    pub fn create(proc: ProcessorPtr, worker_id: usize, res: Result<()>) -> Self {
+    pub fn create(
+        proc: ProcessorPtr,
+        worker_id: usize,
+        res: Result<()>,
+        elapsed: Duration,
+    ) -> Self {
         CompletedAsyncTask {
             id: unsafe { proc.id() },
             worker_id,
             res,
+            elapsed,
         }
     }
 }
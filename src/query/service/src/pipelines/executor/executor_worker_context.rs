@@ -15,7 +15,9 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Instant;
 
+use common_base::base::Thread;
 use common_base::base::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -69,17 +71,36 @@ impl ExecutorWorkerContext {
     pub unsafe fn execute_task(&mut self, exec: &PipelineExecutor) -> Result<Option<NodeIndex>> {
         match std::mem::replace(&mut self.task, ExecutorTask::None) {
             ExecutorTask::None => Err(ErrorCode::LogicalError("Execute none task.")),
-            ExecutorTask::Sync(processor) => self.execute_sync_task(processor),
+            ExecutorTask::Sync(processor) => self.execute_sync_task(processor, exec),
             ExecutorTask::Async(processor) => self.execute_async_task(processor, exec),
-            ExecutorTask::AsyncCompleted(task) => match task.res {
-                Ok(_) => Ok(Some(task.id)),
-                Err(cause) => Err(cause),
-            },
+            ExecutorTask::AsyncCompleted(task) => {
+                exec.profiles.record_async(task.id, task.elapsed);
+                match task.res {
+                    Ok(_) => Ok(Some(task.id)),
+                    Err(cause) => Err(cause),
+                }
+            }
         }
     }
 
-    unsafe fn execute_sync_task(&mut self, processor: ProcessorPtr) -> Result<Option<NodeIndex>> {
+    unsafe fn execute_sync_task(
+        &mut self,
+        processor: ProcessorPtr,
+        executor: &PipelineExecutor,
+    ) -> Result<Option<NodeIndex>> {
+        if !executor.profiles.is_enabled() {
+            processor.process()?;
+            return Ok(Some(processor.id()));
+        }
+
+        let start = Instant::now();
+        let start_cpu_time = Thread::cpu_time();
         processor.process()?;
+        executor.profiles.record_sync(
+            processor.id(),
+            start.elapsed(),
+            Thread::cpu_time().saturating_sub(start_cpu_time),
+        );
         Ok(Some(processor.id()))
     }
 
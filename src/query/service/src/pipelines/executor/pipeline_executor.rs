@@ -26,6 +26,7 @@ use tracing::warn;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
 use crate::pipelines::executor::executor_graph::RunningGraph;
+use crate::pipelines::executor::executor_profile::ExecutorProfiles;
 use crate::pipelines::executor::executor_tasks::ExecutorTasksQueue;
 use crate::pipelines::executor::executor_worker_context::ExecutorWorkerContext;
 use crate::pipelines::pipeline::Pipeline;
@@ -40,6 +41,7 @@ pub struct PipelineExecutor {
     query_need_abort: Arc<AtomicBool>,
     pub async_runtime: Arc<Runtime>,
     pub global_tasks_queue: Arc<ExecutorTasksQueue>,
+    pub profiles: Arc<ExecutorProfiles>,
     on_finished_callback: FinishedCallback,
 }
 
@@ -117,8 +119,10 @@ impl PipelineExecutor {
             }
             global_tasks_queue.init_tasks(tasks);
 
+            let profiles = Arc::new(graph.create_profiles());
             Ok(Arc::new(PipelineExecutor {
                 graph,
+                profiles,
                 threads_num,
                 workers_condvar,
                 query_need_abort,
@@ -138,6 +142,10 @@ impl PipelineExecutor {
         self.global_tasks_queue.is_finished()
     }
 
+    pub fn get_profiles(&self) -> Arc<ExecutorProfiles> {
+        self.profiles.clone()
+    }
+
     pub fn execute(self: &Arc<Self>) -> Result<()> {
         let mut thread_join_handles = self.execute_threads(self.threads_num);
 
@@ -37,6 +37,7 @@ pub struct ProcessorAsyncTask {
     processor: ProcessorPtr,
     queue: Arc<ExecutorTasksQueue>,
     workers_condvar: Arc<WorkersCondvar>,
+    start: Instant,
     inner: BoxFuture<'static, Result<()>>,
 }
 
@@ -92,6 +93,7 @@ impl ProcessorAsyncTask {
             processor,
             queue,
             workers_condvar,
+            start: Instant::now(),
             inner: inner.boxed(),
         }
     }
@@ -116,7 +118,12 @@ impl Future for ProcessorAsyncTask {
             Ok(Poll::Ready(res)) => {
                 self.queue.completed_async_task(
                     self.workers_condvar.clone(),
-                    CompletedAsyncTask::create(self.processor.clone(), self.worker_id, res),
+                    CompletedAsyncTask::create(
+                        self.processor.clone(),
+                        self.worker_id,
+                        res,
+                        self.start.elapsed(),
+                    ),
                 );
                 Poll::Ready(())
             }
@@ -131,7 +138,12 @@ impl Future for ProcessorAsyncTask {
 
                 self.queue.completed_async_task(
                     self.workers_condvar.clone(),
-                    CompletedAsyncTask::create(self.processor.clone(), self.worker_id, res),
+                    CompletedAsyncTask::create(
+                        self.processor.clone(),
+                        self.worker_id,
+                        res,
+                        self.start.elapsed(),
+                    ),
                 );
 
                 Poll::Ready(())
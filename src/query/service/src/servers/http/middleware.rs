@@ -20,6 +20,7 @@ use headers::authorization::Basic;
 use headers::authorization::Bearer;
 use headers::authorization::Credentials;
 use http::header::AUTHORIZATION;
+use http::header::USER_AGENT;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
@@ -126,6 +127,11 @@ impl<E> HTTPSessionEndpoint<E> {
             let tenant_id = tenant_id.to_str().unwrap().to_string();
             session.set_current_tenant(tenant_id);
         }
+        if let Some(user_agent) = req.headers().get(USER_AGENT) {
+            if let Ok(user_agent) = user_agent.to_str() {
+                session.set_client_user_agent(user_agent.to_string());
+            }
+        }
         ctx.get_auth_manager()
             .auth(ctx.get_current_session(), &credential)
             .await?;
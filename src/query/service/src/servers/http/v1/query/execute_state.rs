@@ -381,6 +381,7 @@ impl HttpQueryHandle {
         let async_runtime = ctx.get_storage_runtime();
         let async_runtime_clone = async_runtime.clone();
         let query_need_abort = ctx.query_need_abort();
+        let run_ctx = ctx.clone();
 
         let run = move || -> Result<()> {
             let mut pipelines = build_res.sources_pipelines;
@@ -390,6 +391,7 @@ impl HttpQueryHandle {
                 query_need_abort,
                 pipelines,
             )?;
+            run_ctx.attach_executor_profiles(pipeline_executor.get_inner().get_profiles())?;
             pipeline_executor.execute()
         };
 
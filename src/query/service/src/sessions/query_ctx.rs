@@ -60,6 +60,7 @@ use crate::auth::AuthMgr;
 use crate::catalogs::Catalog;
 use crate::catalogs::CatalogManager;
 use crate::clusters::Cluster;
+use crate::pipelines::executor::ExecutorProfiles;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -232,6 +233,11 @@ impl QueryContext {
         self.shared.session.session_ctx.get_client_host()
     }
 
+    /// Get the driver string the client sent, such as the `User-Agent` of an HTTP request.
+    pub fn get_client_user_agent(&self) -> Option<String> {
+        self.shared.session.session_ctx.get_client_user_agent()
+    }
+
     pub fn query_need_abort(self: &Arc<Self>) -> Arc<AtomicBool> {
         self.shared.query_need_abort()
     }
@@ -244,6 +250,26 @@ impl QueryContext {
         self.shared.set_affect(affect)
     }
 
+    /// Attach the profiles of an executor running this query, they are logged when it finishes.
+    ///
+    /// Nothing is attached unless `enable_query_profiling` is set, it has to be called before
+    /// the executor is executed.
+    pub fn attach_executor_profiles(&self, profiles: Arc<ExecutorProfiles>) -> Result<()> {
+        if self.get_settings().get_enable_query_profiling()? {
+            profiles.enable();
+            self.shared.attach_executor_profiles(profiles);
+        }
+        Ok(())
+    }
+
+    pub fn get_executor_profiles(&self) -> Vec<Arc<ExecutorProfiles>> {
+        self.shared.get_executor_profiles()
+    }
+
+    pub fn get_aborted_by(&self) -> Option<String> {
+        self.shared.get_aborted_by()
+    }
+
     pub fn get_query_logger(&self) -> Option<Arc<dyn Subscriber + Send + Sync>> {
         self.shared.session.session_mgr.get_query_logger()
     }
@@ -273,6 +299,12 @@ impl TableContext for QueryContext {
     fn get_scan_progress_value(&self) -> ProgressValues {
         self.shared.scan_progress.as_ref().get_values()
     }
+    fn get_total_scan_progress(&self) -> Arc<Progress> {
+        self.shared.total_scan_progress.clone()
+    }
+    fn get_total_scan_progress_value(&self) -> ProgressValues {
+        self.shared.total_scan_progress.as_ref().get_values()
+    }
     fn get_write_progress(&self) -> Arc<Progress> {
         self.shared.write_progress.clone()
     }
@@ -363,6 +395,9 @@ impl TableContext for QueryContext {
     fn set_current_user(&self, user: UserInfo) {
         self.shared.set_current_user(user)
     }
+    fn get_current_role(&self) -> Option<String> {
+        self.shared.get_current_role()
+    }
     fn get_fuse_version(&self) -> String {
         self.version.clone()
     }
@@ -456,6 +491,10 @@ impl TableContext for QueryContext {
             .processes_info()
             .await
     }
+
+    fn is_aborting(&self) -> bool {
+        self.shared.query_need_abort.load(Acquire)
+    }
 }
 
 impl TrySpawn for QueryContext {
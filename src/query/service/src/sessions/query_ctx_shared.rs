@@ -36,6 +36,7 @@ use uuid::Uuid;
 use crate::auth::AuthMgr;
 use crate::catalogs::CatalogManager;
 use crate::clusters::Cluster;
+use crate::pipelines::executor::ExecutorProfiles;
 use crate::servers::http::v1::HttpQueryHandle;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
@@ -58,6 +59,8 @@ type DatabaseAndTable = (String, String, String);
 pub struct QueryContextShared {
     /// scan_progress for scan metrics of datablocks (uncompressed)
     pub(in crate::sessions) scan_progress: Arc<Progress>,
+    /// total_scan_progress for the estimated total of scan_progress, from the statistics of the read plans
+    pub(in crate::sessions) total_scan_progress: Arc<Progress>,
     /// write_progress for write/commit metrics of datablocks (uncompressed)
     pub(in crate::sessions) write_progress: Arc<Progress>,
     /// result_progress for metrics of result datablocks (uncompressed)
@@ -78,6 +81,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) user_manager: Arc<UserApiProvider>,
     pub(in crate::sessions) auth_manager: Arc<AuthMgr>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
+    pub(in crate::sessions) executor_profiles: Arc<RwLock<Vec<Arc<ExecutorProfiles>>>>,
+    /// Who aborted the query by KILL, None if the query is not killed.
+    pub(in crate::sessions) aborted_by: Arc<RwLock<Option<String>>>,
 
     pub(in crate::sessions) query_need_abort: Arc<AtomicBool>,
 }
@@ -96,6 +102,7 @@ impl QueryContextShared {
             cluster_cache,
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             scan_progress: Arc::new(Progress::create()),
+            total_scan_progress: Arc::new(Progress::create()),
             result_progress: Arc::new(Progress::create()),
             write_progress: Arc::new(Progress::create()),
             error: Arc::new(Mutex::new(None)),
@@ -112,6 +119,8 @@ impl QueryContextShared {
             auth_manager: Arc::new(AuthMgr::create(conf, user_manager.clone()).await?),
             query_need_abort: Arc::new(AtomicBool::new(false)),
             affect: Arc::new(Mutex::new(None)),
+            executor_profiles: Arc::new(RwLock::new(Vec::new())),
+            aborted_by: Arc::new(RwLock::new(None)),
         }))
     }
 
@@ -138,6 +147,16 @@ impl QueryContextShared {
         // TODO: Wait for the query to be processed (write out the last error)
     }
 
+    /// Kills the query on behalf of `aborted_by`, which is recorded in the query log.
+    pub fn kill_by(&self, aborted_by: String) {
+        *self.aborted_by.write() = Some(aborted_by);
+        self.kill();
+    }
+
+    pub fn get_aborted_by(&self) -> Option<String> {
+        self.aborted_by.read().clone()
+    }
+
     pub fn get_cluster(&self) -> Arc<Cluster> {
         self.cluster_cache.clone()
     }
@@ -162,6 +181,10 @@ impl QueryContextShared {
         self.session.set_current_user(user);
     }
 
+    pub fn get_current_role(&self) -> Option<String> {
+        self.session.get_current_role()
+    }
+
     pub fn set_current_tenant(&self, tenant: String) {
         self.session.set_current_tenant(tenant);
     }
@@ -304,6 +327,15 @@ impl QueryContextShared {
         let mut guard = self.affect.lock();
         *guard = Some(affect);
     }
+
+    pub fn attach_executor_profiles(&self, profiles: Arc<ExecutorProfiles>) {
+        let mut executor_profiles = self.executor_profiles.write();
+        executor_profiles.push(profiles);
+    }
+
+    pub fn get_executor_profiles(&self) -> Vec<Arc<ExecutorProfiles>> {
+        self.executor_profiles.read().clone()
+    }
 }
 
 impl Session {
@@ -129,6 +129,21 @@ impl Session {
         }
     }
 
+    /// Same as `force_kill_session`, the killed query is logged as aborted by `aborted_by`.
+    pub fn force_kill_session_by(self: &Arc<Self>, aborted_by: String) {
+        self.force_kill_query_by(aborted_by);
+        self.kill(/* shutdown io stream */);
+    }
+
+    /// Same as `force_kill_query`, the killed query is logged as aborted by `aborted_by`.
+    pub fn force_kill_query_by(self: &Arc<Self>, aborted_by: String) {
+        let session_ctx = self.session_ctx.clone();
+
+        if let Some(context_shared) = session_ctx.take_query_context_shared() {
+            context_shared.kill_by(aborted_by);
+        }
+    }
+
     /// Create a query context for query.
     /// For a query, execution environment(e.g cluster) should be immutable.
     /// We can bind the environment to the context in create_context method.
@@ -211,6 +226,10 @@ impl Session {
         self.session_ctx.set_current_tenant(tenant);
     }
 
+    pub fn set_client_user_agent(self: &Arc<Self>, user_agent: String) {
+        self.session_ctx.set_client_user_agent(user_agent);
+    }
+
     pub fn get_current_user(self: &Arc<Self>) -> Result<UserInfo> {
         self.session_ctx
             .get_current_user()
@@ -225,6 +244,16 @@ impl Session {
         self.session_ctx.set_auth_role(role)
     }
 
+    // returns the role the session is acting as: the role granted on the authentication
+    // phase, otherwise the default role of the current user.
+    pub fn get_current_role(self: &Arc<Self>) -> Option<String> {
+        self.session_ctx.get_auth_role().or_else(|| {
+            self.session_ctx
+                .get_current_user()
+                .and_then(|user| user.option.default_role().cloned())
+        })
+    }
+
     // returns all the roles the current session has, which includes the roles of
     // the current user and the roles granted on the authentication phase.
     pub fn get_all_roles(self: &Arc<Self>) -> Result<Vec<String>> {
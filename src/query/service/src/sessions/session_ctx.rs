@@ -34,6 +34,7 @@ pub struct SessionContext {
     current_user: RwLock<Option<UserInfo>>,
     auth_role: RwLock<Option<String>>,
     client_host: RwLock<Option<SocketAddr>>,
+    client_user_agent: RwLock<Option<String>>,
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     query_context_shared: RwLock<Option<Arc<QueryContextShared>>>,
 }
@@ -47,6 +48,7 @@ impl SessionContext {
             auth_role: Default::default(),
             current_tenant: Default::default(),
             client_host: Default::default(),
+            client_user_agent: Default::default(),
             current_catalog: RwLock::new("default".to_string()),
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
@@ -136,6 +138,16 @@ impl SessionContext {
         *lock = sock
     }
 
+    pub fn get_client_user_agent(&self) -> Option<String> {
+        let lock = self.client_user_agent.read();
+        lock.clone()
+    }
+
+    pub fn set_client_user_agent(&self, user_agent: String) {
+        let mut lock = self.client_user_agent.write();
+        *lock = Some(user_agent)
+    }
+
     pub fn set_io_shutdown_tx(&self, tx: Option<Sender<Sender<()>>>) {
         let mut lock = self.io_shutdown_tx.write();
         *lock = tx
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::ProgressValues;
 pub use common_catalog::table_context::ProcessInfo;
@@ -30,18 +31,23 @@ impl Session {
 
     fn to_process_info(self: &Arc<Self>, status: &SessionContext) -> ProcessInfo {
         let mut memory_usage = 0;
+        let mut peak_memory_usage = None;
 
         if let Some(shared) = &status.get_query_context_shared() {
             if let Ok(runtime) = shared.try_get_runtime() {
                 let runtime_tracker = runtime.get_tracker();
                 let runtime_memory_tracker = runtime_tracker.get_memory_tracker();
                 memory_usage = runtime_memory_tracker.get_memory_usage();
+                peak_memory_usage = Some(runtime_memory_tracker.get_peak_memory_usage());
             }
         }
 
+        let session_status = self.get_status();
+        let session_status = session_status.read();
         ProcessInfo {
             id: self.id.clone(),
             typ: self.get_type().to_string(),
+            handler: self.get_type().handler().map(String::from),
             state: self.process_state(status),
             database: status.get_current_database(),
             user: status.get_current_user(),
@@ -49,9 +55,15 @@ impl Session {
             client_address: status.get_client_host(),
             session_extra_info: self.process_extra_info(status),
             memory_usage,
+            peak_memory_usage,
+            cpu_time_ms: Session::query_cpu_time_ms(status),
             dal_metrics: Session::query_dal_metrics(status),
             scan_progress_value: Session::query_scan_progress_value(status),
+            total_scan_progress_value: Session::query_total_scan_progress_value(status),
             mysql_connection_id: self.mysql_connection_id,
+            session_started_on: session_status.session_started_on,
+            last_query_started_on: session_status.last_query_started_on,
+            query: Session::process_query(status, &session_status.last_query),
         }
     }
 
@@ -83,6 +95,14 @@ impl Session {
             .map(|context_shared| context_shared.get_query_str())
     }
 
+    fn process_query(status: &SessionContext, last_query: &Option<String>) -> Option<String> {
+        let running_query = status
+            .get_query_context_shared()
+            .map(|context_shared| context_shared.get_query_str())
+            .filter(|query| !query.is_empty());
+        running_query.or_else(|| last_query.clone().filter(|query| !query.is_empty()))
+    }
+
     fn query_dal_metrics(status: &SessionContext) -> Option<DalMetrics> {
         status
             .get_query_context_shared()
@@ -90,10 +110,36 @@ impl Session {
             .map(|context_shared| context_shared.dal_ctx.get_metrics().as_ref().clone())
     }
 
+    // The executors only read the thread CPU clock when profiling, the others report nothing.
+    fn query_cpu_time_ms(status: &SessionContext) -> Option<u64> {
+        let executor_profiles = status
+            .get_query_context_shared()?
+            .get_executor_profiles()
+            .into_iter()
+            .filter(|profiles| profiles.is_enabled())
+            .collect::<Vec<_>>();
+        if executor_profiles.is_empty() {
+            return None;
+        }
+
+        let cpu_time = executor_profiles
+            .iter()
+            .map(|profiles| profiles.total_cpu_time())
+            .sum::<Duration>();
+        Some(cpu_time.as_millis() as u64)
+    }
+
     fn query_scan_progress_value(status: &SessionContext) -> Option<ProgressValues> {
         status
             .get_query_context_shared()
             .as_ref()
             .map(|context_shared| context_shared.scan_progress.get_values())
     }
+
+    fn query_total_scan_progress_value(status: &SessionContext) -> Option<ProgressValues> {
+        status
+            .get_query_context_shared()
+            .as_ref()
+            .map(|context_shared| context_shared.total_scan_progress.get_values())
+    }
 }
@@ -24,6 +24,7 @@ use backon::ExponentialBackoff;
 use common_base::base::tokio;
 use common_base::base::Runtime;
 use common_base::base::SignalStream;
+use common_catalog::catalog::CATALOG_DEFAULT;
 use common_contexts::DalRuntime;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -47,6 +48,7 @@ use tracing::Subscriber;
 use tracing_appender::non_blocking::WorkerGuard;
 
 use crate::api::DataExchangeManager;
+use crate::catalogs::Catalog;
 use crate::catalogs::CatalogManager;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
@@ -58,8 +60,13 @@ use crate::sessions::ProcessInfo;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
 use crate::storages::cache::CacheManager;
+use crate::storages::system::QueryLogSink;
+use crate::storages::system::QueryLogTable;
 use crate::Config;
 
+// The number of query log blocks buffered for each sink before they are dropped.
+const QUERY_LOG_SINK_QUEUE_SIZE: usize = 1024;
+
 pub struct SessionManager {
     pub(in crate::sessions) conf: Config,
     pub(in crate::sessions) discovery: Arc<ClusterDiscovery>,
@@ -91,6 +98,15 @@ pub struct SessionManager {
 
 impl SessionManager {
     pub async fn from_conf(conf: Config) -> Result<Arc<SessionManager>> {
+        Self::from_conf_with_catalogs(conf, vec![]).await
+    }
+
+    /// Creates the session manager with the catalogs besides the ones of the config,
+    /// a catalog replaces the one of the config with the same name.
+    pub async fn from_conf_with_catalogs(
+        conf: Config,
+        extra_catalogs: Vec<(String, Arc<dyn Catalog>)>,
+    ) -> Result<Arc<SessionManager>> {
         let app_name = format!(
             "databend-query-{}-{}",
             conf.query.tenant_id, conf.query.cluster_id
@@ -108,7 +124,9 @@ impl SessionManager {
         };
         _log_guards.extend(_guards);
 
-        let catalogs = Arc::new(CatalogManager::try_new(&conf).await?);
+        let mut catalogs = CatalogManager::try_new(&conf).await?;
+        catalogs.catalogs.extend(extra_catalogs);
+        let catalogs = Arc::new(catalogs);
         let storage_cache_manager = Arc::new(CacheManager::init(&conf.query));
 
         // Cluster discovery.
@@ -439,4 +457,23 @@ impl SessionManager {
     pub fn get_query_logger(&self) -> Option<Arc<dyn Subscriber + Send + Sync>> {
         self.query_logger.write().clone()
     }
+
+    /// Forwards the query logs to the sink besides the in-memory `system.query_log`,
+    /// it is expected to be called at the server init.
+    pub async fn add_query_log_sink(&self, sink: Arc<dyn QueryLogSink>) -> Result<()> {
+        let query_log = self
+            .catalogs
+            .get_catalog(CATALOG_DEFAULT)?
+            .get_table(&self.conf.query.tenant_id, "system", "query_log")
+            .await?;
+        let query_log_table: &QueryLogTable = query_log
+            .as_any()
+            .downcast_ref()
+            .ok_or_else(|| ErrorCode::LogicalError("system.query_log is not a QueryLogTable"))?;
+
+        query_log_table
+            .store()
+            .add_sink(sink, QUERY_LOG_SINK_QUEUE_SIZE);
+        Ok(())
+    }
 }
@@ -13,13 +13,23 @@
 // limitations under the License.
 
 use std::time::Instant;
+use std::time::SystemTime;
 
 pub struct SessionStatus {
     pub session_started_at: Instant,
     pub last_query_finished_at: Option<Instant>,
+    /// The wall clock time of `session_started_at`, shown in `system.processes`.
+    pub session_started_on: SystemTime,
+    pub last_query_started_on: Option<SystemTime>,
+    pub last_query: Option<String>,
 }
 
 impl SessionStatus {
+    pub(crate) fn query_start(&mut self, now: SystemTime, query: String) {
+        self.last_query_started_on = Some(now);
+        self.last_query = Some(query);
+    }
+
     pub(crate) fn query_finish(&mut self) {
         self.last_query_finished_at = Some(Instant::now())
     }
@@ -35,6 +45,9 @@ impl Default for SessionStatus {
         SessionStatus {
             session_started_at: Instant::now(),
             last_query_finished_at: None,
+            session_started_on: SystemTime::now(),
+            last_query_started_on: None,
+            last_query: None,
         }
     }
 }
@@ -34,6 +34,19 @@ impl SessionType {
             SessionType::HTTPAPI(_) | SessionType::Dummy | SessionType::Fuzz
         )
     }
+
+    /// The protocol handler the session came in on, None for the internal sessions.
+    pub fn handler(&self) -> Option<&'static str> {
+        match self {
+            SessionType::Clickhouse | SessionType::ClickHouseHttpHandler => Some("ClickHouse"),
+            SessionType::MySQL => Some("MySQL"),
+            SessionType::HTTPQuery | SessionType::HTTPStreamingLoad | SessionType::HTTPAPI(_) => {
+                Some("HTTP")
+            }
+            SessionType::FlightRPC => Some("FlightSQL"),
+            SessionType::Dummy | SessionType::Fuzz => None,
+        }
+    }
 }
 
 impl fmt::Display for SessionType {
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use common_base::base::ProgressValues;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Extras;
@@ -32,6 +33,7 @@ use super::Project;
 use super::Sort;
 use super::TableScan;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::executor::util::check_physical;
 use crate::sql::executor::AggregateFunctionDesc;
 use crate::sql::executor::AggregateFunctionSignature;
@@ -139,6 +141,10 @@ impl PhysicalPlanBuilder {
                         Some(push_downs),
                     )
                     .await?;
+                self.ctx.get_total_scan_progress().incr(&ProgressValues {
+                    rows: source.statistics.read_rows,
+                    bytes: source.statistics.read_bytes,
+                });
                 Ok(PhysicalPlan::TableScan(TableScan {
                     name_mapping,
                     source: Box::new(source),
@@ -132,11 +132,14 @@ impl<'a> Binder {
         &mut self,
         stmt: &DescShareStmt<'a>,
     ) -> Result<Plan> {
-        let DescShareStmt { share } = stmt;
+        let DescShareStmt { tenant, share } = stmt;
 
+        let tenant = tenant
+            .as_ref()
+            .map(|tenant| normalize_identifier(tenant, &self.name_resolution_ctx).name);
         let share = normalize_identifier(share, &self.name_resolution_ctx).name;
 
-        let plan = DescSharePlan { share };
+        let plan = DescSharePlan { tenant, share };
         Ok(Plan::DescShare(Box::new(plan)))
     }
 
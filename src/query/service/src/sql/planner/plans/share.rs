@@ -44,6 +44,7 @@ impl From<CreateSharePlan> for CreateShareReq {
             },
             comment: p.comment,
             create_on: Utc::now(),
+            request_id: None,
         }
     }
 }
@@ -70,6 +71,8 @@ impl From<DropSharePlan> for DropShareReq {
                 tenant: p.tenant,
                 share_name: p.share,
             },
+            force: false,
+            dry_run: false,
         }
     }
 }
@@ -118,14 +121,20 @@ pub struct AlterShareTenantsPlan {
 }
 
 impl AlterShareTenantsPlan {
+    /// One row for each requested account, with the action taken on it.
     pub fn schema(&self) -> DataSchemaRef {
-        Arc::new(DataSchema::empty())
+        Arc::new(DataSchema::new(vec![
+            DataField::new("Account", Vu8::to_data_type()),
+            DataField::new("Action", Vu8::to_data_type()),
+        ]))
     }
 }
 
 // desc share
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct DescSharePlan {
+    /// The provider of an inbound share, None for a share of the current tenant.
+    pub tenant: Option<String>,
     pub share: String,
 }
 
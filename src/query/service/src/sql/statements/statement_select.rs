@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_base::base::ProgressValues;
 use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
@@ -305,6 +306,10 @@ impl DfQueryStatement {
                 let source_plan = table
                     .read_plan_with_catalog(ctx.clone(), catalog_name, push_downs)
                     .await?;
+                ctx.get_total_scan_progress().incr(&ProgressValues {
+                    rows: source_plan.statistics.read_rows,
+                    bytes: source_plan.statistics.read_bytes,
+                });
                 state.relation = QueryRelation::FromTable(Box::new(source_plan));
             }
             JoinedTableDesc::Subquery {
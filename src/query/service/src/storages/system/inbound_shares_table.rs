@@ -0,0 +1,91 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_api::ShareApi;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::share::ShowSharesReq;
+
+use crate::sessions::TableContext;
+use crate::storages::system::AsyncOneBlockSystemTable;
+use crate::storages::system::AsyncSystemTable;
+use crate::storages::Table;
+
+/// The shares the current tenant consumes, one row for each share granted to it by a provider tenant.
+pub struct InboundSharesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for InboundSharesTable {
+    const NAME: &'static str = "system.inbound_shares";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let meta_api = ctx.get_user_manager().get_meta_store_client();
+        let req = ShowSharesReq::new(ctx.get_tenant());
+        let inbound_accounts = meta_api.show_shares(req).await?.inbound_accounts;
+
+        let mut provider_tenants: Vec<Vec<u8>> = Vec::with_capacity(inbound_accounts.len());
+        let mut share_names: Vec<Vec<u8>> = Vec::with_capacity(inbound_accounts.len());
+        let mut database_names: Vec<Option<Vec<u8>>> = Vec::with_capacity(inbound_accounts.len());
+        let mut shared_ons: Vec<i64> = Vec::with_capacity(inbound_accounts.len());
+        for entry in inbound_accounts {
+            provider_tenants.push(entry.share_name.tenant.into_bytes());
+            share_names.push(entry.share_name.share_name.into_bytes());
+            database_names.push(entry.database_name.map(|name| name.into_bytes()));
+            shared_ons.push(entry.create_on.timestamp_micros());
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(provider_tenants),
+            Series::from_data(share_names),
+            Series::from_data(database_names),
+            Series::from_data(shared_ons),
+        ]))
+    }
+}
+
+impl InboundSharesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("provider_tenant", Vu8::to_data_type()),
+            DataField::new("share_name", Vu8::to_data_type()),
+            DataField::new_nullable("database_name", Vu8::to_data_type()),
+            DataField::new("shared_on", TimestampType::new_impl(3)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'inbound_shares'".to_string(),
+            name: "inbound_shares".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemInboundShares".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(InboundSharesTable { table_info })
+    }
+}
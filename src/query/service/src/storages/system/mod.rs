@@ -13,6 +13,14 @@
 // limitations under the License.
 
 mod clusters_table;
+mod inbound_shares_table;
+mod remote_node_reader;
+mod shares_table;
+mod stage_usage_table;
 
 pub use clusters_table::ClustersTable;
 pub use common_storages_preludes::system::*;
+pub use inbound_shares_table::InboundSharesTable;
+pub use remote_node_reader::FlightNodeReader;
+pub use shares_table::SharesTable;
+pub use stage_usage_table::StageUsageTable;
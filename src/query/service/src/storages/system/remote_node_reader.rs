@@ -0,0 +1,56 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use common_metrics::MetricSample;
+
+use crate::clusters::ClusterHelper;
+use crate::sessions::TableContext;
+use crate::storages::system::RemoteNodeReader;
+
+/// Reads the other nodes of the cluster with the actions of their flight service.
+pub struct FlightNodeReader;
+
+#[async_trait::async_trait]
+impl RemoteNodeReader for FlightNodeReader {
+    async fn read_log_lines(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        node: &NodeInfo,
+        limit: Option<usize>,
+    ) -> Result<Vec<String>> {
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
+        let mut conn = ctx
+            .get_cluster()
+            .create_node_conn(&node.id, &ctx.get_config())
+            .await?;
+        conn.read_tracing_logs(limit, timeout).await
+    }
+
+    async fn read_metric_samples(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        node: &NodeInfo,
+    ) -> Result<Vec<MetricSample>> {
+        let timeout = ctx.get_settings().get_flight_client_timeout()?;
+        let mut conn = ctx
+            .get_cluster()
+            .create_node_conn(&node.id, &ctx.get_config())
+            .await?;
+        conn.read_metric_samples(timeout).await
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_api::ShareApi;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::share::ShareGrantObject;
+
+use crate::sessions::TableContext;
+use crate::storages::system::AsyncOneBlockSystemTable;
+use crate::storages::system::AsyncSystemTable;
+use crate::storages::Table;
+
+/// The shares the current tenant provides, one row for each share.
+pub struct SharesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for SharesTable {
+    const NAME: &'static str = "system.shares";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let meta_api = ctx.get_user_manager().get_meta_store_client();
+        let shares = meta_api.list_outbound_shares(ctx.get_tenant()).await?;
+
+        let mut share_names: Vec<Vec<u8>> = Vec::with_capacity(shares.len());
+        let mut database_names: Vec<Option<Vec<u8>>> = Vec::with_capacity(shares.len());
+        let mut created_ons: Vec<i64> = Vec::with_capacity(shares.len());
+        let mut last_modified_ons: Vec<i64> = Vec::with_capacity(shares.len());
+        let mut database_counts: Vec<u64> = Vec::with_capacity(shares.len());
+        let mut table_counts: Vec<u64> = Vec::with_capacity(shares.len());
+        for (entry, share_meta) in shares {
+            // The grants of the dropped objects are still counted.
+            let table_count = share_meta
+                .entries
+                .values()
+                .filter(|entry| matches!(entry.object, ShareGrantObject::Table(_)))
+                .count();
+
+            share_names.push(entry.share_name.share_name.into_bytes());
+            database_names.push(entry.database_name.map(|name| name.into_bytes()));
+            created_ons.push(entry.create_on.timestamp_micros());
+            // A share never modified since it is created has no `update_on`.
+            let last_modified_on = share_meta.update_on.unwrap_or(entry.create_on);
+            last_modified_ons.push(last_modified_on.timestamp_micros());
+            database_counts.push(share_meta.database.is_some() as u64);
+            table_counts.push(table_count as u64);
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(share_names),
+            Series::from_data(database_names),
+            Series::from_data(created_ons),
+            Series::from_data(last_modified_ons),
+            Series::from_data(database_counts),
+            Series::from_data(table_counts),
+        ]))
+    }
+}
+
+impl SharesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("share_name", Vu8::to_data_type()),
+            DataField::new_nullable("database_name", Vu8::to_data_type()),
+            DataField::new("created_on", TimestampType::new_impl(3)),
+            DataField::new("last_modified_on", TimestampType::new_impl(3)),
+            DataField::new("database_count", u64::to_data_type()),
+            DataField::new("table_count", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'shares'".to_string(),
+            name: "shares".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemShares".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(SharesTable { table_info })
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use futures::TryStreamExt;
+use tracing::warn;
+
+use crate::sessions::TableContext;
+use crate::storages::stage::StageSourceHelper;
+use crate::storages::system::AsyncOneBlockSystemTable;
+use crate::storages::system::AsyncSystemTable;
+use crate::storages::Table;
+
+/// The files held by the stages, one row for each stage.
+pub struct StageUsageTable {
+    table_info: TableInfo,
+}
+
+/// The number of files, total bytes and last modified time (micros) of a stage.
+type StageUsage = (u64, u64, Option<i64>);
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for StageUsageTable {
+    const NAME: &'static str = "system.stage_usage";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let stages = ctx.get_user_manager().get_stages(&tenant).await?;
+
+        let mut stage_names: Vec<Vec<u8>> = Vec::with_capacity(stages.len());
+        let mut files: Vec<Option<u64>> = Vec::with_capacity(stages.len());
+        let mut bytes: Vec<Option<u64>> = Vec::with_capacity(stages.len());
+        let mut last_modifieds: Vec<Option<i64>> = Vec::with_capacity(stages.len());
+        for stage in stages {
+            // The usage of a stage whose storage can not be read is unknown.
+            let usage = match Self::stage_usage(&ctx, &stage).await {
+                Ok(usage) => Some(usage),
+                Err(e) => {
+                    warn!(
+                        "failed to read the usage of stage {}: {}",
+                        stage.stage_name, e
+                    );
+                    None
+                }
+            };
+
+            stage_names.push(stage.stage_name.into_bytes());
+            files.push(usage.map(|(files, _, _)| files));
+            bytes.push(usage.map(|(_, bytes, _)| bytes));
+            last_modifieds.push(usage.and_then(|(_, _, last_modified)| last_modified));
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(stage_names),
+            Series::from_data(files),
+            Series::from_data(bytes),
+            Series::from_data(last_modifieds),
+        ]))
+    }
+}
+
+impl StageUsageTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("stage_name", Vu8::to_data_type()),
+            DataField::new_nullable("files", u64::to_data_type()),
+            DataField::new_nullable("bytes", u64::to_data_type()),
+            DataField::new_nullable("last_modified", TimestampType::new_impl(3)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'stage_usage'".to_string(),
+            name: "stage_usage".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemStageUsage".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(StageUsageTable { table_info })
+    }
+
+    /// The files of an internal stage are tracked by the meta service,
+    /// those of an external stage are listed from its storage.
+    async fn stage_usage(ctx: &Arc<dyn TableContext>, stage: &UserStageInfo) -> Result<StageUsage> {
+        match stage.stage_type {
+            StageType::Internal => {
+                let files = ctx
+                    .get_user_manager()
+                    .list_files(&ctx.get_tenant(), &stage.stage_name)
+                    .await?;
+                Ok((
+                    files.len() as u64,
+                    files.iter().map(|file| file.size).sum(),
+                    files
+                        .iter()
+                        .map(|file| file.last_modified.timestamp_micros())
+                        .max(),
+                ))
+            }
+            StageType::External => {
+                let op = StageSourceHelper::get_op(ctx, stage).await?;
+                let mut usage: StageUsage = (0, 0, None);
+                let mut objects = op.batch().walk_top_down("/")?;
+                while let Some(de) = objects.try_next().await? {
+                    if de.mode().is_dir() {
+                        continue;
+                    }
+                    let meta = de.metadata().await?;
+                    let last_modified =
+                        meta.last_modified().map(|t| t.unix_timestamp() * 1_000_000);
+                    usage.0 += 1;
+                    usage.1 += meta.content_length();
+                    usage.2 = usage.2.max(last_modified);
+                }
+                Ok(usage)
+            }
+        }
+    }
+}
@@ -16,13 +16,17 @@ mod async_crash_me;
 mod memory_block_part;
 mod numbers_part;
 mod numbers_table;
+mod query_log_ipc;
 mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
+mod tracing_follow;
 
 pub use memory_block_part::generate_numbers_parts;
 pub use numbers_part::NumbersPartInfo;
 pub use numbers_table::NumbersTable;
+pub use query_log_ipc::QueryLogIpcTable;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableArgs;
 pub use table_function_factory::TableFunctionFactory;
+pub use tracing_follow::TracingFollowTable;
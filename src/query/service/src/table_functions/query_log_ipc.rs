@@ -0,0 +1,184 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::Utc;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::AsyncSource;
+use crate::pipelines::processors::AsyncSourcer;
+use crate::pipelines::Pipe;
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::storages::system::QueryLogTable;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// `query_log_ipc()` returns one row holding the query logs serialized as an Arrow IPC stream.
+pub struct QueryLogIpcTable {
+    table_info: TableInfo,
+}
+
+impl QueryLogIpcTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        if matches!(&table_args, Some(args) if !args.is_empty()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table function {} takes no arguments",
+                table_func_name
+            )));
+        }
+
+        let schema = DataSchemaRefExt::create(vec![DataField::new("ipc", Vu8::to_data_type())]);
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "QueryLogIpc".to_string(),
+                created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(QueryLogIpcTable { table_info }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for QueryLogIpcTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        _: Arc<dyn TableContext>,
+        _: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        Ok((Statistics::new_exact(1, 1, 1, 1), vec![]))
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        None
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let output = OutputPort::create();
+        pipeline.add_pipe(Pipe::SimplePipe {
+            inputs_port: vec![],
+            outputs_port: vec![output.clone()],
+            processors: vec![QueryLogIpcSource::create(ctx, output, self.schema())?],
+        });
+
+        Ok(())
+    }
+}
+
+struct QueryLogIpcSource {
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    finished: bool,
+}
+
+impl QueryLogIpcSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, QueryLogIpcSource {
+            ctx,
+            schema,
+            finished: false,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for QueryLogIpcSource {
+    const NAME: &'static str = "query_log_ipc";
+
+    #[async_trait::unboxed_simple]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finished {
+            return Ok(None);
+        }
+        self.finished = true;
+
+        let tenant = self.ctx.get_tenant();
+        let query_log = self
+            .ctx
+            .get_catalog(CATALOG_DEFAULT)?
+            .get_table(tenant.as_str(), "system", "query_log")
+            .await?;
+        let query_log_table: &QueryLogTable = query_log.as_any().downcast_ref().unwrap();
+        let ipc = query_log_table
+            .store()
+            .export_arrow_ipc(&query_log_table.log_schema())?;
+
+        Ok(Some(DataBlock::create(self.schema.clone(), vec![
+            Series::from_data(vec![ipc]),
+        ])))
+    }
+}
+
+impl TableFunction for QueryLogIpcTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -29,7 +29,9 @@ use crate::storages::fuse::table_functions::FuseSnapshotTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
 use crate::table_functions::NumbersTable;
+use crate::table_functions::QueryLogIpcTable;
 use crate::table_functions::TableFunction;
+use crate::table_functions::TracingFollowTable;
 
 pub type TableArgs = Option<Vec<Expression>>;
 type TableFunctionCreators = RwLock<HashMap<String, (MetaId, Arc<dyn TableFunctionCreator>)>>;
@@ -111,6 +113,16 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(ClusteringInformationTable::create)),
         );
 
+        creators.insert(
+            "query_log_ipc".to_string(),
+            (next_id(), Arc::new(QueryLogIpcTable::create)),
+        );
+
+        creators.insert(
+            "tracing_follow".to_string(),
+            (next_id(), Arc::new(TracingFollowTable::create)),
+        );
+
         creators.insert(
             "sync_crash_me".to_string(),
             (next_id(), Arc::new(SyncCrashMeTable::create)),
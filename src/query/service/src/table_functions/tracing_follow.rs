@@ -0,0 +1,128 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use common_datavalues::chrono::TimeZone;
+use common_datavalues::chrono::Utc;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::Partitions;
+use common_planners::ReadDataSourcePlan;
+use common_planners::Statistics;
+
+use crate::pipelines::Pipeline;
+use crate::sessions::TableContext;
+use crate::storages::system::TracingTable;
+use crate::storages::Table;
+use crate::table_functions::table_function_factory::TableArgs;
+use crate::table_functions::TableFunction;
+
+/// `tracing_follow()` returns the rows of system.tracing and then keeps streaming the lines
+/// appended to the active log file of the local node.
+///
+/// The query is long-lived, it ends when the `LIMIT` is reached or the query is killed.
+pub struct TracingFollowTable {
+    table_info: TableInfo,
+    tracing_table: TracingTable,
+}
+
+impl TracingFollowTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        if matches!(&table_args, Some(args) if !args.is_empty()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table function {} takes no arguments",
+                table_func_name
+            )));
+        }
+
+        let tracing_table = TracingTable::create(table_id).with_follow();
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: tracing_table.schema(),
+                engine: "TracingFollow".to_string(),
+                created_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                updated_on: Utc.from_utc_datetime(&NaiveDateTime::from_timestamp(0, 0)),
+                ..Default::default()
+            },
+        };
+
+        Ok(Arc::new(TracingFollowTable {
+            table_info,
+            tracing_table,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for TracingFollowTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<Extras>,
+    ) -> Result<(Statistics, Partitions)> {
+        self.tracing_table.read_partitions(ctx, push_downs).await
+    }
+
+    fn table_args(&self) -> Option<Vec<Expression>> {
+        None
+    }
+
+    fn read2(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &ReadDataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        self.tracing_table.read2(ctx, plan, pipeline)
+    }
+}
+
+impl TableFunction for TracingFollowTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -59,6 +59,8 @@ table_engine_memory_enabled = true
 database_engine_github_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
+max_vacuum_history_size = 10000
+max_clustering_history_size = 10000
 table_cache_enabled = false
 table_cache_snapshot_count = 256
 table_cache_segment_count = 10240
@@ -71,6 +73,7 @@ jwt_key_file = ""
 async_insert_max_data_size = 10000
 async_insert_busy_timeout = 200
 async_insert_stale_timeout = 0
+share_protected_databases = "system"
 
 [log]
 level = "INFO"
@@ -469,6 +472,8 @@ table_engine_memory_enabled = true
 database_engine_github_enabled = true
 wait_timeout_mills = 5000
 max_query_log_size = 10000
+max_vacuum_history_size = 10000
+max_clustering_history_size = 10000
 table_cache_enabled = false
 table_cache_snapshot_count = 256
 table_cache_segment_count = 10240
@@ -481,6 +486,7 @@ jwt_key_file = ""
 async_insert_max_data_size = 10000
 async_insert_busy_timeout = 200
 async_insert_stale_timeout = 0
+share_protected_databases = "system"
 
 [log]
 level = "INFO"
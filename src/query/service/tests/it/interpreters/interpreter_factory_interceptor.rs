@@ -114,3 +114,48 @@ async fn test_interpreter_interceptor_for_insert() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_interpreter_interceptor_result_counts() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let (rows, bytes) = {
+        let query = "select number, number + 1 from numbers_mt(100000) where number % 3 = 0";
+        ctx.attach_query_str(query);
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        interpreter.start().await?;
+        let stream = interpreter.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        interpreter.finish().await?;
+
+        (
+            result.iter().map(|block| block.num_rows()).sum::<usize>(),
+            result
+                .iter()
+                .map(|block| block.memory_size())
+                .sum::<usize>(),
+        )
+    };
+
+    // The finish log of the query counts the rows and bytes of the blocks it produced.
+    {
+        let query = "select result_rows, result_bytes from system.query_log where log_type = 2";
+        let plan = PlanParser::parse(ctx.clone(), query).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+        let stream = interpreter.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+
+        assert_eq!(rows, 33334);
+        let expected = vec![
+            "+-------------+--------------+".to_string(),
+            "| result_rows | result_bytes |".to_string(),
+            "+-------------+--------------+".to_string(),
+            format!("| {:<11} | {:<12} |", rows, bytes),
+            "+-------------+--------------+".to_string(),
+        ];
+        let expected = expected.iter().map(|line| line.as_str()).collect();
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
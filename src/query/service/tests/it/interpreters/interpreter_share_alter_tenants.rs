@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_alter_share_tenants_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+
+    {
+        let query = "create share s1";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let _ = executor.execute().await?.try_collect::<Vec<_>>().await?;
+    }
+
+    // add tenants, x is skipped the second time.
+    {
+        let query = "alter share s1 add tenants = x,y";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        assert_eq!(executor.name(), "AlterShareTenantsInterpreter");
+        let _ = executor.execute().await?.try_collect::<Vec<_>>().await?;
+
+        let query = "alter share s1 add tenants = x,z";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let result = executor.execute().await?.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---------+---------+",
+            "| Account | Action  |",
+            "+---------+---------+",
+            "| x       | SKIPPED |",
+            "| z       | ADDED   |",
+            "+---------+---------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // remove tenants, w is skipped as it is not added.
+    {
+        let query = "alter share s1 remove tenants = y,w";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let result = executor.execute().await?.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+---------+---------+",
+            "| Account | Action  |",
+            "+---------+---------+",
+            "| w       | SKIPPED |",
+            "| y       | REMOVED |",
+            "+---------+---------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    Ok(())
+}
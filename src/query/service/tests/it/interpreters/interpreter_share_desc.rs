@@ -13,8 +13,15 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_datavalues::chrono::Utc;
 use common_exception::Result;
+use common_meta_api::ShareApi;
+use common_meta_app::share::AddShareAccountsReq;
+use common_meta_app::share::CreateShareReq;
+use common_meta_app::share::ShareNameIdent;
+use common_meta_app::share::ShowSharesReq;
 use databend_query::interpreters::*;
+use databend_query::sessions::TableContext;
 use databend_query::sql::Planner;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
@@ -44,3 +51,57 @@ async fn test_desc_share_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_desc_inbound_share_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+
+    // A share of another tenant granted to the current one.
+    let meta_api = ctx.get_user_manager().get_meta_store_client();
+    let share_name = ShareNameIdent {
+        tenant: "provider1".to_string(),
+        share_name: "s1".to_string(),
+    };
+    meta_api
+        .create_share(CreateShareReq {
+            if_not_exists: false,
+            share_name: share_name.clone(),
+            comment: None,
+            create_on: Utc::now(),
+            request_id: None,
+        })
+        .await?;
+    meta_api
+        .add_share_tenants(AddShareAccountsReq {
+            share_name: share_name.clone(),
+            share_on: Utc::now(),
+            if_exists: false,
+            accounts: vec![ctx.get_tenant()],
+            check_cycle: false,
+            chunk_size: None,
+            update_existing: false,
+        })
+        .await?;
+
+    let resp = meta_api
+        .show_shares(ShowSharesReq::new("provider1"))
+        .await?;
+    assert_eq!(resp.outbound_accounts[0].last_accessed_on, None);
+
+    // Describing the inbound share is an access of the consumer.
+    {
+        let query = "desc share provider1.s1";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let stream = executor.execute().await?;
+        let _ = stream.try_collect::<Vec<_>>().await?;
+    }
+
+    let resp = meta_api
+        .show_shares(ShowSharesReq::new("provider1"))
+        .await?;
+    assert!(resp.outbound_accounts[0].last_accessed_on.is_some());
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+
+async fn execute(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(query).await?;
+    let executor = InterpreterFactoryV2::get(ctx, &plan)?;
+    let _ = executor.execute().await?.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grant_share_object_protected_database() -> Result<()> {
+    // The system database is protected by default.
+    {
+        let ctx = crate::tests::create_query_context().await?;
+        execute(ctx.clone(), "create share s1").await?;
+
+        for query in [
+            "grant usage on database system to share s1",
+            "grant select on table system.tables to share s1",
+        ] {
+            let err = execute(ctx.clone(), query).await.unwrap_err();
+            assert_eq!(
+                err.code(),
+                ErrorCode::CannotShareProtectedObject("").code(),
+                "{}",
+                query
+            );
+        }
+    }
+
+    // The protected databases are configurable, they are checked before the table is looked up.
+    {
+        let mut conf = crate::tests::ConfigBuilder::create().config();
+        conf.query.share_protected_databases = vec!["db1".to_string()];
+        let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+        execute(ctx.clone(), "create share s1").await?;
+
+        let err = execute(ctx.clone(), "grant select on table db1.t1 to share s1")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::CannotShareProtectedObject("").code());
+    }
+
+    Ok(())
+}
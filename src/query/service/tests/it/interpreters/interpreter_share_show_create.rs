@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_meta_api::ShareApi;
+use common_meta_app::share::ShareNameIdent;
+use databend_query::interpreters::*;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::sql::Planner;
+use futures::TryStreamExt;
+use pretty_assertions::assert_eq;
+
+async fn execute(ctx: Arc<QueryContext>, query: &str) -> Result<()> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(query).await?;
+    let executor = InterpreterFactoryV2::get(ctx, &plan)?;
+    let _ = executor.execute().await?.try_collect::<Vec<_>>().await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_share_create_statement_round_trip() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let meta_api = ctx.get_user_manager().get_meta_store_client();
+    let share_name = ShareNameIdent {
+        tenant: ctx.get_tenant(),
+        share_name: "s1".to_string(),
+    };
+
+    for query in [
+        "create database db1",
+        "create table db1.t1(a int)",
+        "create share s1 comment = 'share comment'",
+        "grant usage on database db1 to share s1",
+        "grant select on table db1.t1 to share s1",
+        "alter share s1 add tenants = x,y",
+    ] {
+        execute(ctx.clone(), query).await?;
+    }
+    let statement = meta_api
+        .get_share_create_statement(share_name.clone())
+        .await?;
+
+    // Recreate the share from its statements, which must give the same share.
+    execute(ctx.clone(), "drop share s1").await?;
+    for query in statement.lines() {
+        execute(ctx.clone(), query.trim_end_matches(';')).await?;
+    }
+    let recreated = meta_api.get_share_create_statement(share_name).await?;
+
+    assert_eq!(statement, recreated);
+    assert_eq!(statement.lines().collect::<Vec<_>>(), vec![
+        "CREATE SHARE s1 COMMENT = 'share comment';",
+        "GRANT USAGE ON DATABASE db1 TO SHARE s1;",
+        "GRANT SELECT ON TABLE db1.t1 TO SHARE s1;",
+        "ALTER SHARE s1 ADD TENANTS = x,y;",
+    ]);
+
+    Ok(())
+}
@@ -90,6 +90,23 @@ async fn test_optimize_recluster_interpreter() -> Result<()> {
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
+    // clustering history.
+    {
+        let query = "select database, table, segments_reclustered, bytes_written > 0 as written, end_time >= start_time as ordered from system.clustering_history";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------+-------+----------------------+---------+---------+",
+            "| database | table | segments_reclustered | written | ordered |",
+            "+----------+-------+----------------------+---------+---------+",
+            "| default  | t     | 2                    | true    | true    |",
+            "+----------+-------+----------------------+---------+---------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
     // snapshot count.
     {
         let query = "select count(*) from fuse_snapshot('default', 't')";
@@ -107,5 +124,47 @@ async fn test_optimize_recluster_interpreter() -> Result<()> {
         common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     }
 
+    // the table is never purged, so has no vacuum history.
+    {
+        let query = "select count(*) from system.vacuum_history where table = 't'";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------+",
+            "| COUNT(*) |",
+            "+----------+",
+            "| 0        |",
+            "+----------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
+    // purge, the snapshots before the last one are removed.
+    {
+        let query = "OPTIMIZE TABLE default.t PURGE";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let _ = executor.execute().await?;
+    }
+
+    // vacuum history.
+    {
+        let query = "select database, table, purged_snapshots, purged_bytes > 0 as purged from system.vacuum_history";
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let stream = executor.execute().await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        let expected = vec![
+            "+----------+-------+------------------+--------+",
+            "| database | table | purged_snapshots | purged |",
+            "+----------+-------+------------------+--------+",
+            "| default  | t     | 2                | true   |",
+            "+----------+-------+------------------+--------+",
+        ];
+        common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    }
+
     Ok(())
 }
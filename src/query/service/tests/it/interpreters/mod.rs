@@ -33,7 +33,10 @@ mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_select;
 mod interpreter_setting;
+mod interpreter_share_alter_tenants;
 mod interpreter_share_desc;
+mod interpreter_share_grant_object;
+mod interpreter_share_show_create;
 mod interpreter_show_databases;
 mod interpreter_show_engines;
 mod interpreter_show_functions;
@@ -46,17 +46,17 @@ async fn test_expression_transform_optimizer() -> Result<()> {
             name: "Like and is_not_null expression",
             query: "select * from system.databases where not (is_not_null(name) and name LIKE '%sys%')",
             expect: "\
-                Projection: name:String\
+                Projection: name:String, catalog:String, engine:String\
                 \n  Filter: (is_null(name) or (name not like %sys%))\
-                \n    ReadDataSource: scan schema: [name:String], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [(NOT (is_not_null(name) AND (name LIKE %sys%)))]]",
+                \n    ReadDataSource: scan schema: [name:String, catalog:String, engine:String], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0, 1, 2], filters: [(NOT (is_not_null(name) AND (name LIKE %sys%)))]]",
         },
         Test {
             name: "Not like and is_null expression",
             query: "select * from system.databases where not (name is null or name not like 'a%')",
             expect: "\
-                Projection: name:String\
+                Projection: name:String, catalog:String, engine:String\
                 \n  Filter: (is_not_null(name) and (name like a%))\
-                \n    ReadDataSource: scan schema: [name:String], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0], filters: [(NOT (is_null(name) OR (name NOT LIKE a%)))]]",
+                \n    ReadDataSource: scan schema: [name:String, catalog:String, engine:String], statistics: [read_rows: 0, read_bytes: 0, partitions_scanned: 0, partitions_total: 0], push_downs: [projections: [0, 1, 2], filters: [(NOT (is_null(name) OR (name NOT LIKE a%)))]]",
         },
         Test {
             name: "Equal expression",
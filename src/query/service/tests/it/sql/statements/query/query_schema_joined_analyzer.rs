@@ -37,7 +37,7 @@ async fn test_joined_schema_analyzer() -> Result<()> {
         TestCase {
             name: "Table query",
             query: "SELECT * FROM system.databases",
-            expect: "QuerySchema { short_names: [\"name\"] }",
+            expect: "QuerySchema { short_names: [\"name\", \"catalog\", \"engine\"] }",
         },
         TestCase {
             name: "Subquery query",
@@ -47,7 +47,7 @@ async fn test_joined_schema_analyzer() -> Result<()> {
         TestCase {
             name: "Subquery query with wildcard",
             query: "SELECT * FROM (SELECT * FROM system.databases)",
-            expect: "QuerySchema { short_names: [\"name\"] }",
+            expect: "QuerySchema { short_names: [\"name\", \"catalog\", \"engine\"] }",
         },
     ];
 
@@ -39,27 +39,27 @@ async fn test_statement_select_analyze() -> Result<()> {
         TestCase {
             name: "Simple filter query",
             query: "SELECT * FROM system.databases WHERE name = 'xxx'",
-            expect: "QueryAnalyzeState { filter: (name = xxx), before_projection: [name], projection: [name] }",
+            expect: "QueryAnalyzeState { filter: (name = xxx), before_projection: [name, catalog, engine], projection: [name, catalog, engine] }",
         },
         TestCase {
             name: "Simple filter query between",
             query: "SELECT * FROM system.databases WHERE name = 'xxx' AND (name between 'aaa' and 'bbb')",
-            expect: "QueryAnalyzeState { filter: ((name = xxx) AND ((name >= aaa) and (name <= bbb))), before_projection: [name], projection: [name] }",
+            expect: "QueryAnalyzeState { filter: ((name = xxx) AND ((name >= aaa) and (name <= bbb))), before_projection: [name, catalog, engine], projection: [name, catalog, engine] }",
         },
         TestCase {
             name: "Simple having query",
             query: "SELECT * FROM system.databases HAVING name = 'xxx'",
-            expect: "QueryAnalyzeState { before_projection: [name], having: (name = xxx), projection: [name] }",
+            expect: "QueryAnalyzeState { before_projection: [name, catalog, engine], having: (name = xxx), projection: [name, catalog, engine] }",
         },
         TestCase {
             name: "Simple order by query",
             query: "SELECT * FROM system.databases ORDER BY name",
-            expect: "QueryAnalyzeState { before_order_by: [name], order_by: [name], projection: [name] }",
+            expect: "QueryAnalyzeState { before_order_by: [name, catalog, engine], order_by: [name], projection: [name, catalog, engine] }",
         },
         TestCase {
             name: "Simple order by query 2",
             query: "SELECT * FROM system.databases ORDER BY name = 'xxx'",
-            expect: "QueryAnalyzeState { before_order_by: [name, (name = xxx)], order_by: [(name = 'xxx')], projection: [name] }",
+            expect: "QueryAnalyzeState { before_order_by: [name, catalog, engine, (name = xxx)], order_by: [(name = 'xxx')], projection: [name, catalog, engine] }",
         },
         TestCase {
             name: "Group by query with filter",
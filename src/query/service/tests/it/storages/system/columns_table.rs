@@ -13,12 +13,22 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
 use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Expression;
+use common_planners::Extras;
 use databend_query::storages::system::ColumnsTable;
 use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+use crate::storages::system::check_schema_reused;
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_columns_table() -> Result<()> {
     let ctx = crate::tests::create_query_context().await?;
@@ -29,6 +39,202 @@ async fn test_columns_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 8);
+    assert_eq!(block.num_columns(), 14);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_columns_table_schema_reused() -> Result<()> {
+    check_schema_reused(ColumnsTable::create(1)).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_columns_table_push_down() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    execute_command(
+        ctx.clone(),
+        "create table default.t1 (a int, b int) engine = Memory",
+    )
+    .await?;
+    execute_command(
+        ctx.clone(),
+        "create table default.t2 (c int) engine = Memory",
+    )
+    .await?;
+
+    let table = ColumnsTable::create(1);
+    let read_columns = |filters: Vec<Expression>| {
+        let ctx = ctx.clone();
+        let table = table.clone();
+        async move {
+            let push_downs = Extras {
+                filters,
+                ..Extras::default()
+            };
+            let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+            let stream = table.read(ctx, &source_plan).await?;
+            let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+            let databases = block.try_column_by_name("database")?;
+            let tables = block.try_column_by_name("table")?;
+            let names = block.try_column_by_name("name")?;
+            (0..block.num_rows())
+                .map(|row| {
+                    Ok(format!(
+                        "{}.{}.{}",
+                        databases.get_checked(row)?,
+                        tables.get_checked(row)?,
+                        names.get_checked(row)?
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()
+        }
+    };
+
+    // Only the columns of the table are enumerated.
+    let columns = read_columns(vec![col("database")
+        .eq(lit("default".as_bytes()))
+        .and(col("table").eq(lit("t1".as_bytes())))])
+    .await?;
+    assert_eq!(columns, vec!["default.t1.a", "default.t1.b"]);
+
+    // The database alone narrows to its tables.
+    let columns = read_columns(vec![col("database").eq(lit("default".as_bytes()))]).await?;
+    assert_eq!(columns, vec![
+        "default.t1.a",
+        "default.t1.b",
+        "default.t2.c"
+    ]);
+
+    // Unknown database or table.
+    let columns = read_columns(vec![col("database").eq(lit("no_such_db".as_bytes()))]).await?;
+    assert!(columns.is_empty());
+    let columns = read_columns(vec![
+        col("database").eq(lit("default".as_bytes())),
+        col("table").eq(lit("no_such_table".as_bytes())),
+    ])
+    .await?;
+    assert!(columns.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_columns_table_type_metadata() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    execute_command(
+        ctx.clone(),
+        "create table default.t (a int, b double, c varchar) engine = Memory",
+    )
+    .await?;
+
+    let table = ColumnsTable::create(1);
+    let push_downs = Extras {
+        filters: vec![col("database")
+            .eq(lit("default".as_bytes()))
+            .and(col("table").eq(lit("t".as_bytes())))],
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+    assert_eq!(block.num_rows(), 3);
+
+    let ordinal_positions = block.try_column_by_name("ordinal_position")?;
+    let character_maximum_lengths = block.try_column_by_name("character_maximum_length")?;
+    let numeric_precisions = block.try_column_by_name("numeric_precision")?;
+    let numeric_scales = block.try_column_by_name("numeric_scale")?;
+    let expected = vec![
+        (1u64, DataValue::UInt64(10), DataValue::UInt64(0)),
+        (2u64, DataValue::UInt64(22), DataValue::Null),
+        (3u64, DataValue::Null, DataValue::Null),
+    ];
+    for (row, (ordinal_position, numeric_precision, numeric_scale)) in
+        expected.into_iter().enumerate()
+    {
+        assert_eq!(ordinal_positions.get_u64(row)?, ordinal_position);
+        assert_eq!(character_maximum_lengths.get_checked(row)?, DataValue::Null);
+        assert_eq!(numeric_precisions.get_checked(row)?, numeric_precision);
+        assert_eq!(numeric_scales.get_checked(row)?, numeric_scale);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_columns_table_cluster_key() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(
+        ctx.clone(),
+        "create table default.t (a int, b int, c int) cluster by (a, b + 1)",
+    )
+    .await?;
+    execute_command(ctx.clone(), "create table default.t2 (d int)").await?;
+
+    let table = ColumnsTable::create(1);
+    let push_downs = Extras {
+        filters: vec![col("database").eq(lit("default".as_bytes()))],
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let tables = block.try_column_by_name("table")?;
+    let names = block.try_column_by_name("name")?;
+    let is_cluster_keys = block.try_column_by_name("is_cluster_key")?;
+    let rows = (0..block.num_rows())
+        .map(|row| {
+            Ok(format!(
+                "{}.{}={}",
+                tables.get_checked(row)?,
+                names.get_checked(row)?,
+                is_cluster_keys.get_checked(row)?
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // The columns referenced by an expression of the cluster key are part of it.
+    assert_eq!(rows, vec![
+        "t.a=true",
+        "t.b=true",
+        "t.c=false",
+        "t2.d=false"
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_columns_table_comment() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(
+        ctx.clone(),
+        "create table default.t (a int comment 'the id', b int)",
+    )
+    .await?;
+
+    let table = ColumnsTable::create(1);
+    let push_downs = Extras {
+        filters: vec![col("database")
+            .eq(lit("default".as_bytes()))
+            .and(col("table").eq(lit("t".as_bytes())))],
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+    assert_eq!(block.num_rows(), 2);
+
+    // The column without a comment has an empty one.
+    let comments = block.try_column_by_name("comment")?;
+    assert_eq!(
+        comments.get_checked(0)?,
+        DataValue::String(b"the id".to_vec())
+    );
+    assert_eq!(comments.get_checked(1)?, DataValue::String(vec![]));
+
     Ok(())
 }
@@ -14,6 +14,10 @@
 
 use common_base::base::tokio;
 use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Expression;
+use common_planners::Extras;
 use common_storage::StorageParams;
 use common_storage::StorageS3Config;
 use databend_query::sessions::TableContext;
@@ -86,7 +90,9 @@ async fn test_configs_table() -> Result<()> {
         "| query   | jwt_key_file                         |                                |             |",
         "| query   | management_mode                      | false                          |             |",
         "| query   | max_active_sessions                  | 256                            |             |",
+        "| query   | max_clustering_history_size          | 10000                          |             |",
         "| query   | max_query_log_size                   | 10000                          |             |",
+        "| query   | max_vacuum_history_size              | 10000                          |             |",
         "| query   | metric_api_address                   | 127.0.0.1:7070                 |             |",
         "| query   | mysql_handler_host                   | 127.0.0.1                      |             |",
         "| query   | mysql_handler_port                   | 3307                           |             |",
@@ -95,6 +101,7 @@ async fn test_configs_table() -> Result<()> {
         "| query   | rpc_tls_query_service_domain_name    | localhost                      |             |",
         "| query   | rpc_tls_server_cert                  |                                |             |",
         "| query   | rpc_tls_server_key                   |                                |             |",
+        "| query   | share_protected_databases            | system                         |             |",
         "| query   | table_cache_block_meta_count         | 102400                         |             |",
         "| query   | table_cache_enabled                  | false                          |             |",
         "| query   | table_cache_segment_count            | 10240                          |             |",
@@ -150,8 +157,11 @@ async fn test_configs_table_redact() -> Result<()> {
         bucket: "test".to_string(),
         access_key_id: "access_key_id".to_string(),
         secret_access_key: "secret_access_key".to_string(),
+        master_key: "master_key".to_string(),
         ..Default::default()
     });
+    // The embedded meta does not check the password, it is only shown masked.
+    conf.meta.password = "meta_password".to_string();
     let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
     ctx.get_settings().set_max_threads(8)?;
 
@@ -185,7 +195,7 @@ async fn test_configs_table_redact() -> Result<()> {
         "| meta    | client_timeout_in_second             | 10                             |             |",
         "| meta    | embedded_dir                         | ./.databend/meta_embedded      |             |",
         "| meta    | endpoints                            |                                |             |",
-        "| meta    | password                             |                                |             |",
+        "| meta    | password                             | ******ord                      |             |",
         "| meta    | rpc_tls_meta_server_root_ca_cert     |                                |             |",
         "| meta    | rpc_tls_meta_service_domain_name     | localhost                      |             |",
         "| meta    | username                             | root                           |             |",
@@ -212,7 +222,9 @@ async fn test_configs_table_redact() -> Result<()> {
         "| query   | jwt_key_file                         |                                |             |",
         "| query   | management_mode                      | false                          |             |",
         "| query   | max_active_sessions                  | 256                            |             |",
+        "| query   | max_clustering_history_size          | 10000                          |             |",
         "| query   | max_query_log_size                   | 10000                          |             |",
+        "| query   | max_vacuum_history_size              | 10000                          |             |",
         "| query   | metric_api_address                   | 127.0.0.1:7070                 |             |",
         "| query   | mysql_handler_host                   | 127.0.0.1                      |             |",
         "| query   | mysql_handler_port                   | 3307                           |             |",
@@ -221,6 +233,7 @@ async fn test_configs_table_redact() -> Result<()> {
         "| query   | rpc_tls_query_service_domain_name    | localhost                      |             |",
         "| query   | rpc_tls_server_cert                  |                                |             |",
         "| query   | rpc_tls_server_key                   |                                |             |",
+        "| query   | share_protected_databases            | system                         |             |",
         "| query   | table_cache_block_meta_count         | 102400                         |             |",
         "| query   | table_cache_enabled                  | false                          |             |",
         "| query   | table_cache_segment_count            | 10240                          |             |",
@@ -249,7 +262,7 @@ async fn test_configs_table_redact() -> Result<()> {
         "| storage | s3.bucket                            | test                           |             |",
         "| storage | s3.enable_virtual_host_style         | false                          |             |",
         &endpoint_url_link,
-        "| storage | s3.master_key                        |                                |             |",
+        "| storage | s3.master_key                        | ******key                      |             |",
         "| storage | s3.region                            | us-east-2                      |             |",
         "| storage | s3.root                              |                                |             |",
         "| storage | s3.secret_access_key                 | ******key                      |             |",
@@ -260,3 +273,39 @@ async fn test_configs_table_redact() -> Result<()> {
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_configs_table_name_like_prefix() -> Result<()> {
+    let conf = crate::tests::ConfigBuilder::create().config();
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    let table = ConfigsTable::create(1);
+    let push_downs = Extras {
+        filters: vec![Expression::create_binary_expression("like", vec![
+            col("name"),
+            lit("s3.%".as_bytes()),
+        ])],
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+
+    let expected = vec![
+        "+---------+------------------------------+--------------------------+-------------+",
+        "| group   | name                         | value                    | description |",
+        "+---------+------------------------------+--------------------------+-------------+",
+        "| storage | s3.access_key_id             |                          |             |",
+        "| storage | s3.bucket                    |                          |             |",
+        "| storage | s3.enable_virtual_host_style | false                    |             |",
+        "| storage | s3.endpoint_url              | https://s3.amazonaws.com |             |",
+        "| storage | s3.master_key                |                          |             |",
+        "| storage | s3.region                    |                          |             |",
+        "| storage | s3.root                      |                          |             |",
+        "| storage | s3.secret_access_key         |                          |             |",
+        "+---------+------------------------------+--------------------------+-------------+",
+    ];
+
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+    Ok(())
+}
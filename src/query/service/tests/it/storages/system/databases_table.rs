@@ -28,16 +28,17 @@ async fn test_tables_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 1);
+    assert_eq!(block.num_columns(), 3);
 
+    // The default database has no engine in its meta, it is of the DEFAULT engine.
     let expected = vec![
-        "+--------------------+",
-        "| name               |",
-        "+--------------------+",
-        "| INFORMATION_SCHEMA |",
-        "| default            |",
-        "| system             |",
-        "+--------------------+",
+        "+--------------------+---------+---------+",
+        "| name               | catalog | engine  |",
+        "+--------------------+---------+---------+",
+        "| INFORMATION_SCHEMA | default | SYSTEM  |",
+        "| default            | default | DEFAULT |",
+        "| system             | default | SYSTEM  |",
+        "+--------------------+---------+---------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
 
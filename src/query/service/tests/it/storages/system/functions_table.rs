@@ -19,6 +19,8 @@ use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 
+use crate::storages::system::check_schema_reused;
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_functions_table() -> Result<()> {
     let ctx = crate::tests::create_query_context().await?;
@@ -28,6 +30,22 @@ async fn test_functions_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 8);
+    assert_eq!(block.num_columns(), 9);
+
+    let names = block.try_column_by_name("name")?;
+    let is_deterministic = block.try_column_by_name("is_deterministic")?;
+    let deterministic_of = |name: &str| {
+        (0..block.num_rows())
+            .find(|row| names.get(*row).to_string() == name)
+            .map(|row| is_deterministic.get(row).as_bool())
+            .unwrap()
+    };
+    assert!(!deterministic_of("rand")?);
+    assert!(deterministic_of("abs")?);
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_functions_table_schema_reused() -> Result<()> {
+    check_schema_reused(FunctionsTable::create(1)).await
+}
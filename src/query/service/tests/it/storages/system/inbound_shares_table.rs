@@ -0,0 +1,163 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::chrono::Utc;
+use common_exception::Result;
+use common_meta_api::KVApi;
+use common_meta_api::KVApiKey;
+use common_meta_api::ShareApi;
+use common_meta_app::share::AddShareAccountsReq;
+use common_meta_app::share::CreateShareReq;
+use common_meta_app::share::ShareId;
+use common_meta_app::share::ShareNameIdent;
+use common_meta_types::MatchSeq;
+use common_meta_types::Operation;
+use common_meta_types::UpsertKVReq;
+use databend_query::sessions::TableContext;
+use databend_query::storages::system::InboundSharesTable;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_inbound_shares_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+    let meta_api = ctx.get_user_manager().get_meta_store_client();
+
+    // Two provider tenants share to the current tenant.
+    let share_on = Utc::now();
+    for (provider, share) in [("provider1", "share1"), ("provider2", "share2")] {
+        let share_name = ShareNameIdent {
+            tenant: provider.to_string(),
+            share_name: share.to_string(),
+        };
+        meta_api
+            .create_share(CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on: share_on,
+                request_id: None,
+            })
+            .await?;
+        meta_api
+            .add_share_tenants(AddShareAccountsReq {
+                share_name,
+                if_exists: false,
+                accounts: vec![tenant.clone()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+                share_on,
+            })
+            .await?;
+    }
+
+    let table = InboundSharesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+    assert_eq!(block.num_columns(), 4);
+
+    let provider_tenants = block.try_column_by_name("provider_tenant")?;
+    let share_names = block.try_column_by_name("share_name")?;
+    let database_names = block.try_column_by_name("database_name")?;
+    let shared_ons = block.try_column_by_name("shared_on")?;
+    let mut rows = vec![];
+    for row in 0..block.num_rows() {
+        // No database is granted to the shares yet.
+        assert!(database_names.get_checked(row)?.is_null());
+        assert_eq!(shared_ons.get_i64(row)?, share_on.timestamp_micros());
+        rows.push(format!(
+            "{}.{}",
+            provider_tenants.get_checked(row)?,
+            share_names.get_checked(row)?
+        ));
+    }
+    rows.sort();
+    assert_eq!(rows, vec!["provider1.share1", "provider2.share2"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_inbound_shares_table_skip_corrupt_share() -> Result<()> {
+    // Use another tenant, not to see the shares of the other tests.
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.tenant_id = "test_corrupt_share".to_string();
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+    let tenant = ctx.get_tenant();
+    let meta_api = ctx.get_user_manager().get_meta_store_client();
+
+    let share_on = Utc::now();
+    let mut share_ids = vec![];
+    for share in ["share1", "share2", "share3"] {
+        let share_name = ShareNameIdent {
+            tenant: "provider".to_string(),
+            share_name: share.to_string(),
+        };
+        let reply = meta_api
+            .create_share(CreateShareReq {
+                if_not_exists: false,
+                share_name: share_name.clone(),
+                comment: None,
+                create_on: share_on,
+                request_id: None,
+            })
+            .await?;
+        share_ids.push(reply.share_id);
+        meta_api
+            .add_share_tenants(AddShareAccountsReq {
+                share_name,
+                if_exists: false,
+                accounts: vec![tenant.clone()],
+                check_cycle: false,
+                chunk_size: None,
+                update_existing: false,
+                share_on,
+            })
+            .await?;
+    }
+
+    // Corrupt share2 by removing its meta, the account of the tenant is left.
+    let id_key = ShareId {
+        share_id: share_ids[1],
+    };
+    meta_api
+        .upsert_kv(UpsertKVReq::new(
+            &id_key.to_key(),
+            MatchSeq::Any,
+            Operation::Delete,
+            None,
+        ))
+        .await?;
+
+    let table = InboundSharesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    // The healthy shares are still listed.
+    let share_names = block.try_column_by_name("share_name")?;
+    let mut rows = (0..block.num_rows())
+        .map(|row| Ok(share_names.get_checked(row)?.to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    rows.sort();
+    assert_eq!(rows, vec!["share1", "share3"]);
+
+    Ok(())
+}
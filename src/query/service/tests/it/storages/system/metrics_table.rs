@@ -12,15 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_base::base::tokio;
 use common_datablocks::pretty_format_blocks;
+use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_metrics::init_default_metrics_recorder;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Expression;
+use common_planners::Extras;
+use databend_query::sessions::TableContext;
 use databend_query::storages::system::MetricsTable;
 use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 
+use crate::storages::system::remote_nodes::create_cluster_context;
+use crate::storages::system::remote_nodes::MockRemoteNodeReader;
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_metrics_table() -> Result<()> {
     init_default_metrics_recorder();
@@ -34,7 +45,7 @@ async fn test_metrics_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 4);
+    assert_eq!(block.num_columns(), 5);
     assert!(block.num_rows() >= 1);
 
     let output = pretty_format_blocks(result.as_slice())?;
@@ -44,3 +55,84 @@ async fn test_metrics_table() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_metrics_table_metric_like_prefix() -> Result<()> {
+    init_default_metrics_recorder();
+    metrics::counter!("metricsprefixa.count", 1);
+    metrics::counter!("metricsprefixb.count", 1);
+
+    let ctx = crate::tests::create_query_context().await?;
+    let table = MetricsTable::create(1);
+    let push_downs = Extras {
+        filters: vec![Expression::create_binary_expression("like", vec![
+            col("metric"),
+            lit("metricsprefixa%".as_bytes()),
+        ])],
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let metrics = block.try_column_by_name("metric")?;
+    let names = (0..block.num_rows())
+        .map(|row| Ok(metrics.get_checked(row)?.to_string()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(names, vec!["metricsprefixa_count".to_string()]);
+
+    Ok(())
+}
+
+// Returns the (node, value) of the metric.
+fn metric_rows(block: &DataBlock, metric: &str) -> Result<Vec<(String, String)>> {
+    let metrics = block.try_column_by_name("metric")?;
+    let values = block.try_column_by_name("value")?;
+    let nodes = block.try_column_by_name("node")?;
+
+    let mut rows = vec![];
+    for row in 0..block.num_rows() {
+        if metrics.get_checked(row)?.to_string() == metric {
+            rows.push((
+                nodes.get_checked(row)?.to_string(),
+                values.get_checked(row)?.to_string(),
+            ));
+        }
+    }
+    rows.sort();
+    Ok(rows)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_metrics_table_cluster() -> Result<()> {
+    init_default_metrics_recorder();
+    metrics::counter!("test.test_metrics_table_cluster_count", 1);
+
+    let ctx = create_cluster_context().await?;
+    ctx.get_settings().set_metrics_cluster(true)?;
+    let table = MetricsTable::create_with_remote_reader(1, Arc::new(MockRemoteNodeReader));
+
+    // The rows of the unreachable node are omitted.
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+    assert_eq!(
+        metric_rows(&block, "test_test_metrics_table_cluster_count")?,
+        vec![
+            ("node1".to_string(), "1.0".to_string()),
+            ("node2".to_string(), "2.0".to_string()),
+        ]
+    );
+
+    // The counters are summed over the nodes.
+    ctx.get_settings().set_metrics_cluster_sum_counters(true)?;
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+    assert_eq!(
+        metric_rows(&block, "test_test_metrics_table_cluster_count")?,
+        vec![("".to_string(), "3.0".to_string())]
+    );
+
+    Ok(())
+}
@@ -20,10 +20,44 @@ mod credits_table;
 mod databases_table;
 mod engines_table;
 mod functions_table;
+mod inbound_shares_table;
 mod metrics_table;
+mod processes_table;
+mod query_log_table;
+mod query_profile_table;
+mod remote_nodes;
 mod roles_table;
 mod settings_table;
+mod share_privileges_table;
+mod shares_table;
+mod stage_usage_table;
 mod stages_table;
+mod table_options_table;
 mod tables_table;
 mod tracing_table;
 mod users_table;
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use databend_query::storages::Table;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+/// Reads `table` twice and checks every block carries the schema of the table, not a copy.
+async fn check_schema_reused(table: Arc<dyn Table>) -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+
+    for _ in 0..2 {
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let blocks = stream.try_collect::<Vec<_>>().await?;
+        assert!(!blocks.is_empty());
+        for block in blocks {
+            assert!(Arc::ptr_eq(block.schema(), &table.schema()));
+        }
+    }
+
+    Ok(())
+}
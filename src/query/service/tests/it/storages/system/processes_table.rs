@@ -0,0 +1,292 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::interpreters::InterpreterFactoryV2;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::sql::PlanParser;
+use databend_query::sql::Planner;
+use databend_query::storages::system::ProcessesTable;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+/// Returns the (session_started_on, last_query_start_time) of the current session.
+async fn read_session_times(ctx: Arc<QueryContext>) -> Result<(DataValue, DataValue)> {
+    let table = ProcessesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+    assert_eq!(block.num_columns(), 20);
+
+    let session_id = ctx.get_current_session().get_id();
+    let ids = block.try_column_by_name("id")?;
+    let started_ons = block.try_column_by_name("session_started_on")?;
+    let last_query_start_times = block.try_column_by_name("last_query_start_time")?;
+    for row in 0..block.num_rows() {
+        if ids.get_checked(row)? == DataValue::String(session_id.as_bytes().to_vec()) {
+            return Ok((
+                started_ons.get_checked(row)?,
+                last_query_start_times.get_checked(row)?,
+            ));
+        }
+    }
+    panic!("session {} is not in system.processes", session_id);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_session_times() -> Result<()> {
+    let (_session, ctx) = crate::tests::create_attached_query_context().await?;
+
+    // No query has run in the session yet.
+    let (started_on, last_query_start_time) = read_session_times(ctx.clone()).await?;
+    assert!(!started_on.is_null());
+    assert!(last_query_start_time.is_null());
+
+    let plan = PlanParser::parse(ctx.clone(), "select number from numbers_mt(10)").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    interpreter.start().await?;
+    let stream = interpreter.execute().await?;
+    stream.try_collect::<Vec<_>>().await?;
+    interpreter.finish().await?;
+
+    // The session keeps the start time of its last query.
+    let (_, last_query_start_time) = read_session_times(ctx.clone()).await?;
+    assert!(!last_query_start_time.is_null());
+    assert!(last_query_start_time.as_i64()? >= started_on.as_i64()?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_peak_memory_usage() -> Result<()> {
+    let (_session, ctx) = crate::tests::create_attached_query_context().await?;
+
+    let plan = PlanParser::parse(
+        ctx.clone(),
+        "select number from numbers_mt(1000000) order by number desc",
+    )
+    .await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    interpreter.start().await?;
+    let stream = interpreter.execute().await?;
+    stream.try_collect::<Vec<_>>().await?;
+    interpreter.finish().await?;
+
+    let table = ProcessesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let session_id = ctx.get_current_session().get_id();
+    let ids = block.try_column_by_name("id")?;
+    let memory_usages = block.try_column_by_name("memory_usage")?;
+    let peak_memory_usages = block.try_column_by_name("peak_memory_usage")?;
+    let row = (0..block.num_rows())
+        .find(|row| {
+            ids.get_checked(*row).unwrap() == DataValue::String(session_id.as_bytes().to_vec())
+        })
+        .unwrap();
+
+    // The query context is attached to the session, so its memory tracker is reported.
+    let peak_memory_usage = peak_memory_usages.get_checked(row)?;
+    assert!(!peak_memory_usage.is_null());
+    assert!(peak_memory_usage.as_i64()? >= memory_usages.get_checked(row)?.as_i64()?);
+
+    Ok(())
+}
+
+/// Returns the given column of the current session.
+async fn read_session_column(ctx: Arc<QueryContext>, column: &str) -> Result<DataValue> {
+    let table = ProcessesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let session_id = ctx.get_current_session().get_id();
+    let ids = block.try_column_by_name("id")?;
+    let values = block.try_column_by_name(column)?;
+    for row in 0..block.num_rows() {
+        if ids.get_checked(row)? == DataValue::String(session_id.as_bytes().to_vec()) {
+            return values.get_checked(row);
+        }
+    }
+    panic!("session {} is not in system.processes", session_id);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_query() -> Result<()> {
+    let (_session, ctx) = crate::tests::create_attached_query_context().await?;
+
+    // No query is attached to the session yet.
+    assert!(read_session_column(ctx.clone(), "query").await?.is_null());
+
+    let query = "select number from numbers_mt(10) where number > 5";
+    ctx.attach_query_str(query);
+    assert_eq!(
+        read_session_column(ctx.clone(), "query").await?,
+        DataValue::String(query.as_bytes().to_vec())
+    );
+
+    // The query text is truncated by max_process_query_length.
+    ctx.get_settings().set_max_process_query_length(6)?;
+    assert_eq!(
+        read_session_column(ctx.clone(), "query").await?,
+        DataValue::String(b"select".to_vec())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_database() -> Result<()> {
+    let (_session, ctx) = crate::tests::create_attached_query_context().await?;
+
+    assert_eq!(
+        read_session_column(ctx.clone(), "database").await?,
+        DataValue::String(b"default".to_vec())
+    );
+
+    let mut planner = Planner::new(ctx.clone());
+    for query in ["create database db1", "use db1"] {
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let interpreter = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let stream = interpreter.execute().await?;
+        stream.try_collect::<Vec<_>>().await?;
+    }
+
+    // The session reports the database selected by `USE`.
+    assert_eq!(
+        read_session_column(ctx.clone(), "database").await?,
+        DataValue::String(b"db1".to_vec())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_handler() -> Result<()> {
+    let (session, ctx) = crate::tests::create_attached_query_context().await?;
+
+    // An internal session has no handler.
+    assert!(read_session_column(ctx.clone(), "handler").await?.is_null());
+
+    for (typ, handler) in [
+        (SessionType::MySQL, "MySQL"),
+        (SessionType::HTTPQuery, "HTTP"),
+        (SessionType::ClickHouseHttpHandler, "ClickHouse"),
+        (SessionType::FlightRPC, "FlightSQL"),
+    ] {
+        session.set_type(typ);
+        assert_eq!(
+            read_session_column(ctx.clone(), "handler").await?,
+            DataValue::String(handler.as_bytes().to_vec())
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_cpu_time() -> Result<()> {
+    let (_session, ctx) = crate::tests::create_attached_query_context().await?;
+    ctx.get_settings().set_enable_query_profiling(true)?;
+
+    // No query has been executed yet.
+    assert!(read_session_column(ctx.clone(), "cpu_time_ms")
+        .await?
+        .is_null());
+
+    let plan = PlanParser::parse(
+        ctx.clone(),
+        "select number from numbers_mt(1000000) order by number desc",
+    )
+    .await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    interpreter.start().await?;
+    let stream = interpreter.execute().await?;
+    stream.try_collect::<Vec<_>>().await?;
+    interpreter.finish().await?;
+
+    let cpu_time_ms = read_session_column(ctx.clone(), "cpu_time_ms").await?;
+    assert!(cpu_time_ms.as_u64()? > 0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_progress_percent() -> Result<()> {
+    let (_session, ctx) = crate::tests::create_attached_query_context().await?;
+
+    // No read plan has been built, so there is no estimate.
+    assert!(read_session_column(ctx.clone(), "progress_percent")
+        .await?
+        .is_null());
+
+    // The statistics of numbers_mt give the exact rows to scan.
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner
+        .plan_sql("select number from numbers_mt(10000)")
+        .await?;
+    let interpreter = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+    let mut stream = interpreter.execute().await?;
+
+    let mut last_percent = 0.0;
+    while let Some(block) = stream.try_next().await? {
+        assert!(block.num_rows() > 0);
+        let percent = read_session_column(ctx.clone(), "progress_percent")
+            .await?
+            .as_f64()?;
+        assert!(percent >= last_percent);
+        assert!(percent <= 100.0);
+        last_percent = percent;
+    }
+
+    // All the estimated rows are scanned.
+    assert_eq!(last_percent, 100.0);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_processes_table_cpu_time_without_profiling() -> Result<()> {
+    let (_session, ctx) = crate::tests::create_attached_query_context().await?;
+    ctx.get_settings().set_enable_query_profiling(false)?;
+
+    let plan = PlanParser::parse(
+        ctx.clone(),
+        "select number from numbers_mt(1000000) order by number desc",
+    )
+    .await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    interpreter.start().await?;
+    let stream = interpreter.execute().await?;
+    stream.try_collect::<Vec<_>>().await?;
+    interpreter.finish().await?;
+
+    // The CPU clock is not read without profiling, the time is unknown rather than zero.
+    assert!(read_session_column(ctx.clone(), "cpu_time_ms")
+        .await?
+        .is_null());
+
+    Ok(())
+}
@@ -0,0 +1,546 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use common_arrow::arrow::io::ipc::read::read_stream_metadata;
+use common_arrow::arrow::io::ipc::read::StreamReader;
+use common_arrow::arrow::io::ipc::read::StreamState;
+use common_base::base::tokio;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::chrono::Duration as ChronoDuration;
+use common_datavalues::chrono::Utc;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::sort;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::KillPlan;
+use databend_query::interpreters::Interpreter;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::interpreters::InterpreterFactoryV2;
+use databend_query::interpreters::InterpreterQueryLog;
+use databend_query::interpreters::KillInterpreter;
+use databend_query::sessions::SessionType;
+use databend_query::sessions::TableContext;
+use databend_query::sql::PlanParser;
+use databend_query::sql::Planner;
+use databend_query::storages::system::QueryLogSink;
+use databend_query::storages::system::QueryLogTable;
+use databend_query::storages::system::QueryLogTimeWindow;
+use databend_query::storages::Table;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+fn query_log_block(schema: &DataSchemaRef, event_time: i64) -> Result<DataBlock> {
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|f| match f.name().as_str() {
+            "event_time" => Ok(Series::from_data(vec![event_time])),
+            _ => f
+                .data_type()
+                .create_column(&[f.data_type().default_value()]),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DataBlock::create(schema.clone(), columns))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_time_window() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let query_log = QueryLogTable::create(1, 100);
+    let schema = query_log.log_schema();
+
+    let blocks = (1..=10)
+        .map(|event_time| query_log_block(&schema, event_time * 1000))
+        .collect::<Vec<_>>();
+    query_log
+        .append_data(ctx.clone(), Box::pin(futures::stream::iter(blocks)))
+        .await?;
+    let table: Arc<dyn Table> = Arc::new(query_log);
+
+    let event_times = |blocks: &[DataBlock]| -> Result<Vec<i64>> {
+        let mut event_times = vec![];
+        for block in blocks {
+            let column = block.try_column_by_name("event_time")?;
+            for row in 0..block.num_rows() {
+                event_times.push(column.get_i64(row)?);
+            }
+        }
+        Ok(event_times)
+    };
+
+    // event_time > 3000 AND event_time <= 8000 ORDER BY event_time DESC LIMIT 3
+    {
+        let push_downs = Extras {
+            filters: vec![
+                col("event_time").gt(lit(3000i64)),
+                col("event_time").lt_eq(lit(8000i64)),
+            ],
+            limit: Some(3),
+            order_by: vec![sort("event_time", false, false)],
+            ..Extras::default()
+        };
+        let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(event_times(&result)?, vec![8000, 7000, 6000]);
+    }
+
+    // Window without limit returns every row inside it, newest first.
+    {
+        let push_downs = Extras {
+            filters: vec![lit(8000i64).gt_eq(col("event_time"))],
+            order_by: vec![sort("event_time", false, false)],
+            ..Extras::default()
+        };
+        let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(event_times(&result)?, vec![
+            8000, 7000, 6000, 5000, 4000, 3000, 2000, 1000
+        ]);
+    }
+
+    // The limit is not applied if other filters still have to be evaluated after the scan.
+    {
+        let push_downs = Extras {
+            filters: vec![
+                col("event_time").gt(lit(3000i64)),
+                col("query_id").eq(lit(b"no-such-query".as_slice())),
+            ],
+            limit: Some(1),
+            order_by: vec![sort("event_time", false, false)],
+            ..Extras::default()
+        };
+        let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(event_times(&result)?, vec![
+            10000, 9000, 8000, 7000, 6000, 5000, 4000
+        ]);
+    }
+
+    // A bound computed by a function is not folded, so every row is scanned and the limit
+    // is left to the executor.
+    {
+        let one_hour_ago = Expression::create_scalar_function("minus", vec![
+            Expression::create_scalar_function("now", vec![]),
+            lit(3_600_000_000i64),
+        ]);
+        let filters = vec![col("event_time").gt(one_hour_ago)];
+        assert_eq!(
+            QueryLogTimeWindow::from_filters(&filters),
+            (QueryLogTimeWindow::default(), false)
+        );
+
+        let push_downs = Extras {
+            filters,
+            limit: Some(3),
+            order_by: vec![sort("event_time", false, false)],
+            ..Extras::default()
+        };
+        let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+        let stream = table.read(ctx.clone(), &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(
+            event_times(&result)?,
+            (1..=10).map(|v| v * 1000).collect::<Vec<i64>>()
+        );
+    }
+
+    // Without push downs the rows are read in the logged order.
+    {
+        let source_plan = table.read_plan(ctx.clone(), None).await?;
+        let stream = table.read(ctx, &source_plan).await?;
+        let result = stream.try_collect::<Vec<_>>().await?;
+        assert_eq!(
+            event_times(&result)?,
+            (1..=10).map(|v| v * 1000).collect::<Vec<i64>>()
+        );
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_temporal_filter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let table = ctx
+        .get_table(CATALOG_DEFAULT, "system", "query_log")
+        .await?;
+    let schema = table.schema();
+    assert_eq!(
+        schema
+            .field_with_name("event_time")?
+            .data_type()
+            .data_type_id(),
+        TypeID::Timestamp
+    );
+
+    // One log of two hours ago and one of now.
+    let now = Utc::now();
+    let blocks = [now - ChronoDuration::hours(2), now]
+        .iter()
+        .map(|time| query_log_block(&schema, time.timestamp_micros()))
+        .collect::<Vec<_>>();
+    table
+        .append_data(ctx.clone(), Box::pin(futures::stream::iter(blocks)))
+        .await?;
+
+    let count = |query: &'static str| {
+        let ctx = ctx.clone();
+        async move {
+            let mut planner = Planner::new(ctx.clone());
+            let (plan, _, _) = planner.plan_sql(query).await?;
+            let interpreter = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+            let stream = interpreter.execute().await?;
+            let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+            block.column(0).get_u64(0)
+        }
+    };
+
+    assert_eq!(
+        count("select count(*) from system.query_log where event_time > now() - interval 1 hour")
+            .await?,
+        1
+    );
+    assert_eq!(
+        count("select count(*) from system.query_log where event_time <= now() - interval 1 hour")
+            .await?,
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_duration_and_exception() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.attach_query_str("select * from t_not_exists");
+
+    let start = SystemTime::now();
+    let query_log = InterpreterQueryLog::create(ctx.clone(), "SelectPlan".to_string());
+    query_log.log_start(start, None).await?;
+    query_log
+        .log_finish(
+            start + Duration::from_millis(5),
+            Some(ErrorCode::UnknownTable("t_not_exists")),
+        )
+        .await?;
+
+    let query =
+        "select log_type, exception_code, exception_text, query_duration_ms from system.query_log";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+    assert_eq!(block.num_rows(), 2);
+
+    // The start log: the query has no error yet and no duration.
+    assert_eq!(block.column(0).get_i64(0)?, 1);
+    assert_eq!(block.column(1).get_checked(0)?, DataValue::Null);
+    assert_eq!(block.column(2).get_checked(0)?, DataValue::Null);
+    assert_eq!(block.column(3).get_checked(0)?, DataValue::Null);
+
+    // The error log.
+    assert_eq!(block.column(0).get_i64(1)?, 3);
+    assert_eq!(
+        block.column(1).get_i64(1)?,
+        ErrorCode::UnknownTable("").code() as i64
+    );
+    let exception_text = block.column(2).get_checked(1)?.to_string();
+    assert!(
+        exception_text.contains("t_not_exists"),
+        "{}",
+        exception_text
+    );
+    assert_eq!(block.column(3).get_u64(1)?, 5);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_aborted_by() -> Result<()> {
+    let (session, ctx) = crate::tests::create_attached_query_context().await?;
+    ctx.attach_query_str("select * from numbers(100000000)");
+
+    let query_log = InterpreterQueryLog::create(ctx.clone(), "SelectPlan".to_string());
+    query_log.log_start(SystemTime::now(), None).await?;
+
+    // Kill the running query from another session.
+    let killer = session
+        .get_session_manager()
+        .create_session(SessionType::Dummy)
+        .await?;
+    killer.set_current_user(ctx.get_current_user()?);
+    let killer_ctx = killer.create_query_context().await?;
+    let interpreter = KillInterpreter::try_create(killer_ctx, KillPlan {
+        id: session.get_id(),
+        kill_connection: false,
+    })?;
+    interpreter.execute().await?;
+
+    query_log
+        .log_finish(SystemTime::now(), ctx.get_error_value())
+        .await?;
+
+    let query = "select log_type, exception_code, aborted_by from system.query_log";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+    assert_eq!(block.num_rows(), 2);
+
+    // The start log.
+    assert_eq!(block.column(0).get_i64(0)?, 1);
+    assert_eq!(block.column(2).get_checked(0)?, DataValue::Null);
+
+    // The killed query is logged as aborted, with who killed it.
+    assert_eq!(block.column(0).get_i64(1)?, 4);
+    assert_eq!(
+        block.column(1).get_i64(1)?,
+        ErrorCode::AbortedQuery("").code() as i64
+    );
+    assert_eq!(
+        block.column(2).get_checked(1)?,
+        DataValue::String(b"KILL QUERY by 'root'@'127.0.0.1'".to_vec())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_export_arrow_ipc() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.attach_query_str("select * from t_not_exists");
+
+    let start = SystemTime::now();
+    let query_log = InterpreterQueryLog::create(ctx.clone(), "SelectPlan".to_string());
+    query_log.log_start(start, None).await?;
+    query_log
+        .log_finish(
+            start + Duration::from_millis(5),
+            Some(ErrorCode::UnknownTable("t_not_exists")),
+        )
+        .await?;
+
+    let table = ctx
+        .get_table(CATALOG_DEFAULT, "system", "query_log")
+        .await?;
+    let query_log_table: &QueryLogTable = table.as_any().downcast_ref().unwrap();
+    let schema = query_log_table.log_schema();
+    let logged = DataBlock::concat_blocks(&Vec::from(query_log_table.store().all()))?;
+    assert_eq!(logged.num_rows(), 2);
+
+    // Pull the IPC stream through the table function.
+    let plan = PlanParser::parse(ctx.clone(), "select ipc from query_log_ipc()").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+    assert_eq!(block.num_rows(), 1);
+    let ipc = block.column(0).get_checked(0)?.as_string()?;
+
+    let mut reader = Cursor::new(ipc);
+    let metadata = read_stream_metadata(&mut reader)?;
+
+    let mut decoded = vec![];
+    for state in StreamReader::new(reader, metadata) {
+        match state? {
+            StreamState::Some(chunk) => decoded.push(DataBlock::from_chunk(&schema, &chunk)?),
+            StreamState::Waiting => unreachable!("the IPC stream is complete"),
+        }
+    }
+    let decoded = DataBlock::concat_blocks(&decoded)?;
+
+    // The decoded rows are the same as the logged ones.
+    assert_eq!(decoded.num_rows(), logged.num_rows());
+    for (i, field) in schema.fields().iter().enumerate() {
+        for row in 0..logged.num_rows() {
+            assert_eq!(
+                decoded.column(i).get_checked(row)?,
+                logged.column(i).get_checked(row)?,
+                "column {}, row {}",
+                field.name(),
+                row
+            );
+        }
+    }
+
+    Ok(())
+}
+
+struct MockQueryLogSink {
+    tx: tokio::sync::mpsc::UnboundedSender<DataBlock>,
+    // Blocks the writes until it is notified, to simulate a slow sink.
+    gate: Option<Arc<tokio::sync::Notify>>,
+}
+
+#[async_trait::async_trait]
+impl QueryLogSink for MockQueryLogSink {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    async fn write(&self, block: DataBlock) -> Result<()> {
+        if let Some(gate) = &self.gate {
+            gate.notified().await;
+        }
+        self.tx
+            .send(block)
+            .map_err(|cause| ErrorCode::UnexpectedError(cause.to_string()))
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_sink() -> Result<()> {
+    let query_log = QueryLogTable::create(1, 100);
+    let schema = query_log.log_schema();
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    query_log
+        .store()
+        .add_sink(Arc::new(MockQueryLogSink { tx, gate: None }), 16);
+
+    for event_time in 1..=3 {
+        query_log
+            .store()
+            .append(query_log_block(&schema, event_time)?)?;
+    }
+
+    // The sink receives the entries in the logged order.
+    for event_time in 1..=3 {
+        let block = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .map_err(|_| ErrorCode::Timeout("sink receives nothing"))?
+            .unwrap();
+        let column = block.try_column_by_name("event_time")?;
+        assert_eq!(column.get_i64(0)?, event_time);
+    }
+
+    // A slow sink does not block the append, the entries beyond its queue are dropped.
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let gate = Arc::new(tokio::sync::Notify::new());
+    let query_log = QueryLogTable::create(1, 100);
+    query_log.store().add_sink(
+        Arc::new(MockQueryLogSink {
+            tx,
+            gate: Some(gate.clone()),
+        }),
+        1,
+    );
+
+    for event_time in 1..=10 {
+        query_log
+            .store()
+            .append(query_log_block(&schema, event_time)?)?;
+    }
+    assert_eq!(query_log.store().all().len(), 10);
+
+    let mut received = 0;
+    loop {
+        gate.notify_one();
+        match tokio::time::timeout(Duration::from_millis(200), rx.recv()).await {
+            Ok(Some(_)) => received += 1,
+            _ => break,
+        }
+    }
+    assert!(received >= 1);
+    assert!(received < 10);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_read_killed() -> Result<()> {
+    let (session, ctx) = crate::tests::create_attached_query_context().await?;
+    let query_log = QueryLogTable::create(1, 1000);
+    let schema = query_log.log_schema();
+    let store = query_log.store();
+    for event_time in 1..=1000 {
+        store.append(query_log_block(&schema, event_time)?)?;
+    }
+    let table: Arc<dyn Table> = Arc::new(query_log);
+
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let mut stream = table.read(ctx.clone(), &source_plan).await?;
+    assert!(stream.try_next().await?.is_some());
+
+    // The client goes away, the query is killed partway.
+    session.force_kill_query();
+
+    // At most the blocks already produced are emitted, then the read stops.
+    let mut emitted = 1;
+    loop {
+        match stream.try_next().await {
+            Ok(Some(_)) => emitted += 1,
+            Ok(None) => break,
+            Err(e) => {
+                assert_eq!(e.code(), ErrorCode::AbortedQuery("").code());
+                break;
+            }
+        }
+    }
+    assert!(emitted < 1000);
+
+    // The store is not pinned by the killed read.
+    store.clear();
+    assert!(store.all().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_log_table_category_and_user_agent() -> Result<()> {
+    let (session, ctx) = crate::tests::create_attached_query_context().await?;
+    session.set_client_user_agent("databend-driver/0.1".to_string());
+
+    ctx.attach_query_str("  SELECT number FROM numbers(1)");
+    InterpreterQueryLog::create(ctx.clone(), "SelectPlan".to_string())
+        .log_start(SystemTime::now(), None)
+        .await?;
+    ctx.attach_query_str("kill query 'q1'");
+    InterpreterQueryLog::create(ctx.clone(), "KillPlan".to_string())
+        .log_start(SystemTime::now(), None)
+        .await?;
+
+    let query = "select query_kind, query_category, client_user_agent from system.query_log";
+    let plan = PlanParser::parse(ctx.clone(), query).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    let stream = interpreter.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+------------+----------------+---------------------+",
+        "| query_kind | query_category | client_user_agent   |",
+        "+------------+----------------+---------------------+",
+        "| KillPlan   | UNKNOWN        | databend-driver/0.1 |",
+        "| SelectPlan | SELECT         | databend-driver/0.1 |",
+        "+------------+----------------+---------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_catalog::catalog::CATALOG_DEFAULT;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Extras;
+use databend_query::interpreters::InterpreterFactory;
+use databend_query::interpreters::InterpreterFactoryV2;
+use databend_query::sessions::TableContext;
+use databend_query::sql::PlanParser;
+use databend_query::sql::Planner;
+use databend_query::storages::system::QueryProfileTable;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_profile_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_enable_query_profiling(true)?;
+    let query_id = ctx.get_id();
+
+    // Run a scan, its profile is logged when the query finishes.
+    let plan = PlanParser::parse(ctx.clone(), "select number from numbers_mt(10)").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    interpreter.start().await?;
+    let stream = interpreter.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    assert_eq!(DataBlock::concat_blocks(&result)?.num_rows(), 10);
+    interpreter.finish().await?;
+
+    let table = ctx
+        .get_table(CATALOG_DEFAULT, "system", "query_profile")
+        .await?;
+
+    // The profile of another query.
+    let query_profile: &QueryProfileTable = table.as_any().downcast_ref().unwrap();
+    query_profile.store().append(
+        "other-query",
+        DataBlock::create(table.schema(), vec![
+            Series::from_data(vec!["other-query"]),
+            Series::from_data(vec![0u64]),
+            Series::from_data(vec!["NumbersSourceTransform"]),
+            Series::from_data(vec![10u64]),
+            Series::from_data(vec![80u64]),
+            Series::from_data(vec![1u64]),
+            Series::from_data(vec![1u64]),
+        ]),
+    );
+
+    let push_downs = Extras {
+        filters: vec![col("query_id").eq(lit(query_id.as_bytes()))],
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+    assert!(block.num_rows() > 0);
+
+    let query_ids = block.try_column_by_name("query_id")?;
+    let operators = block.try_column_by_name("operator")?;
+    let output_rows = block.try_column_by_name("output_rows")?;
+    let mut scan_rows = 0;
+    for row in 0..block.num_rows() {
+        assert_eq!(
+            query_ids.get_checked(row)?,
+            DataValue::String(query_id.as_bytes().to_vec())
+        );
+        if operators.get_checked(row)? == DataValue::String(b"NumbersSourceTransform".to_vec()) {
+            scan_rows += output_rows.get_u64(row)?;
+        }
+    }
+    assert_eq!(scan_rows, 10);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_query_profile_join_query_log() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    ctx.get_settings().set_enable_query_profiling(true)?;
+    let query_id = ctx.get_id();
+
+    // Both the log and the profile of the query are written when it finishes.
+    let plan = PlanParser::parse(ctx.clone(), "select number from numbers_mt(10)").await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), plan)?;
+    interpreter.start().await?;
+    let stream = interpreter.execute().await?;
+    stream.try_collect::<Vec<_>>().await?;
+    interpreter.finish().await?;
+
+    let query = format!(
+        "select l.query_id, p.query_id, p.operator from system.query_log l \
+        join system.query_profile p on l.query_id = p.query_id \
+        where l.log_type = 2 and l.query_id = '{}'",
+        query_id
+    );
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _, _) = planner.plan_sql(&query).await?;
+    let interpreter = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+    let stream = interpreter.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+    assert!(block.num_rows() > 0);
+
+    let expected = DataValue::String(query_id.as_bytes().to_vec());
+    let mut operators = vec![];
+    for row in 0..block.num_rows() {
+        assert_eq!(block.column(0).get_checked(row)?, expected);
+        assert_eq!(block.column(1).get_checked(row)?, expected);
+        operators.push(block.column(2).get_checked(row)?);
+    }
+    assert!(operators.contains(&DataValue::String(b"NumbersSourceTransform".to_vec())));
+
+    Ok(())
+}
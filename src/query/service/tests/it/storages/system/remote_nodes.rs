@@ -0,0 +1,112 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Barrier;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::NodeInfo;
+use common_metrics::MetricSample;
+use common_metrics::MetricValue;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::TableContext;
+use databend_query::storages::system::read_remote_nodes;
+use databend_query::storages::system::RemoteNodeReader;
+
+use crate::tests::ClusterDescriptor;
+
+/// Creates the context of `node1` in a cluster of three nodes.
+pub async fn create_cluster_context() -> Result<Arc<QueryContext>> {
+    crate::tests::create_query_context_with_cluster(
+        ClusterDescriptor::new()
+            .with_node("node1", "127.0.0.1:9090")
+            .with_node("node2", "127.0.0.1:9091")
+            .with_node("node3", "127.0.0.1:9092")
+            .with_local_id("node1"),
+    )
+    .await
+}
+
+fn cannot_connect(node: &NodeInfo) -> ErrorCode {
+    ErrorCode::CannotConnectNode(format!("Cannot connect to {}", node.flight_address))
+}
+
+/// Simulates the flight service of the other nodes of `create_cluster_context`, `node3` is
+/// unreachable.
+pub struct MockRemoteNodeReader;
+
+#[async_trait::async_trait]
+impl RemoteNodeReader for MockRemoteNodeReader {
+    async fn read_log_lines(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        node: &NodeInfo,
+        _limit: Option<usize>,
+    ) -> Result<Vec<String>> {
+        match node.id.as_str() {
+            "node2" => Ok(vec![
+                "from node2".to_string(),
+                "from node2 again".to_string(),
+            ]),
+            _ => Err(cannot_connect(node)),
+        }
+    }
+
+    async fn read_metric_samples(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        node: &NodeInfo,
+    ) -> Result<Vec<MetricSample>> {
+        match node.id.as_str() {
+            "node2" => Ok(vec![MetricSample {
+                name: "test_test_metrics_table_cluster_count".to_string(),
+                kind: "counter".to_string(),
+                labels: HashMap::new(),
+                value: MetricValue::Counter(2.0),
+            }]),
+            _ => Err(cannot_connect(node)),
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_read_remote_nodes() -> Result<()> {
+    let ctx: Arc<dyn TableContext> = create_cluster_context().await?;
+
+    // The reads of `node2` and `node3` wait for each other, they only finish when they run
+    // concurrently.
+    let barrier = Arc::new(Barrier::new(2));
+    let read = read_remote_nodes(&ctx, |node| {
+        let barrier = barrier.clone();
+        async move {
+            barrier.wait().await;
+            match node.id.as_str() {
+                "node2" => Ok(format!("from {}", node.id)),
+                _ => Err(cannot_connect(&node)),
+            }
+        }
+    });
+    let res = tokio::time::timeout(Duration::from_secs(10), read)
+        .await
+        .expect("the nodes are not read concurrently");
+
+    // The local node is not read, the rows of the unreachable node are omitted.
+    assert_eq!(res, vec![("node2".to_string(), "from node2".to_string())]);
+
+    Ok(())
+}
@@ -45,18 +45,34 @@ async fn test_roles_table() -> Result<()> {
     let table = RolesTable::create(1);
     let source_plan = table.read_plan(ctx.clone(), None).await?;
 
-    let stream = table.read(ctx, &source_plan).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 2);
+    assert_eq!(block.num_columns(), 3);
+
+    let expected = vec![
+        "+-------+-----------------+------------+",
+        "| name  | inherited_roles | is_current |",
+        "+-------+-----------------+------------+",
+        "| test  | 0               | false      |",
+        "| test1 | 1               | false      |",
+        "+-------+-----------------+------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    // The role granted on the authentication phase is the current one.
+    ctx.get_current_session().set_auth_role("test1".to_string());
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
 
     let expected = vec![
-        "+-------+-----------------+",
-        "| name  | inherited_roles |",
-        "+-------+-----------------+",
-        "| test  | 0               |",
-        "| test1 | 1               |",
-        "+-------+-----------------+",
+        "+-------+-----------------+------------+",
+        "| name  | inherited_roles | is_current |",
+        "+-------+-----------------+------------+",
+        "| test  | 0               | false      |",
+        "| test1 | 1               | true       |",
+        "+-------+-----------------+------------+",
     ];
     common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
     Ok(())
@@ -0,0 +1,173 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::chrono::Duration;
+use common_datavalues::chrono::Utc;
+use common_exception::Result;
+use common_meta_api::ShareApi;
+use common_meta_app::share::CreateShareReq;
+use common_meta_app::share::GrantShareObjectReq;
+use common_meta_app::share::ShareGrantObjectName;
+use common_meta_app::share::ShareGrantObjectPrivilege;
+use common_meta_app::share::ShareNameIdent;
+use databend_query::sessions::TableContext;
+use databend_query::storages::system::SharesTable;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_shares_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(ctx.clone(), "create database share_db").await?;
+    execute_command(ctx.clone(), "create table share_db.t1 (a int)").await?;
+    execute_command(ctx.clone(), "create table share_db.t2 (a int)").await?;
+
+    // Grant the database and both of its tables to the share.
+    let meta_api = ctx.get_user_manager().get_meta_store_client();
+    let share_name = ShareNameIdent {
+        tenant: ctx.get_tenant(),
+        share_name: "share_counts".to_string(),
+    };
+    let create_on = Utc::now();
+    meta_api
+        .create_share(CreateShareReq {
+            if_not_exists: false,
+            share_name: share_name.clone(),
+            comment: None,
+            create_on,
+            request_id: None,
+        })
+        .await?;
+    let grants = [
+        (
+            ShareGrantObjectName::Database("share_db".to_string()),
+            ShareGrantObjectPrivilege::Usage,
+        ),
+        (
+            ShareGrantObjectName::Table("share_db".to_string(), "t1".to_string()),
+            ShareGrantObjectPrivilege::Select,
+        ),
+        (
+            ShareGrantObjectName::Table("share_db".to_string(), "t2".to_string()),
+            ShareGrantObjectPrivilege::Select,
+        ),
+    ];
+    for (object, privilege) in grants {
+        meta_api
+            .grant_share_object(GrantShareObjectReq {
+                share_name: share_name.clone(),
+                object,
+                grant_on: create_on,
+                privilege: privilege.into(),
+                protected_databases: vec![],
+            })
+            .await?;
+    }
+
+    let table = SharesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+    assert_eq!(block.num_columns(), 6);
+
+    let share_names = block.try_column_by_name("share_name")?;
+    let database_names = block.try_column_by_name("database_name")?;
+    let database_counts = block.try_column_by_name("database_count")?;
+    let table_counts = block.try_column_by_name("table_count")?;
+    let row = (0..block.num_rows())
+        .find(|row| share_names.get(*row).to_string() == "share_counts")
+        .unwrap();
+
+    assert_eq!(database_names.get_checked(row)?.to_string(), "share_db");
+    assert_eq!(database_counts.get_u64(row)?, 1);
+    assert_eq!(table_counts.get_u64(row)?, 2);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_shares_table_last_modified_on() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(ctx.clone(), "create database share_db").await?;
+
+    let meta_api = ctx.get_user_manager().get_meta_store_client();
+    let create_on = Utc::now() - Duration::hours(1);
+    for share_name in ["share_modified", "share_untouched"] {
+        meta_api
+            .create_share(CreateShareReq {
+                if_not_exists: false,
+                share_name: ShareNameIdent {
+                    tenant: ctx.get_tenant(),
+                    share_name: share_name.to_string(),
+                },
+                comment: None,
+                create_on,
+                request_id: None,
+            })
+            .await?;
+    }
+    meta_api
+        .grant_share_object(GrantShareObjectReq {
+            share_name: ShareNameIdent {
+                tenant: ctx.get_tenant(),
+                share_name: "share_modified".to_string(),
+            },
+            object: ShareGrantObjectName::Database("share_db".to_string()),
+            grant_on: Utc::now(),
+            privilege: ShareGrantObjectPrivilege::Usage.into(),
+            protected_databases: vec![],
+        })
+        .await?;
+
+    let table = SharesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let share_names = block.try_column_by_name("share_name")?;
+    let created_ons = block.try_column_by_name("created_on")?;
+    let last_modified_ons = block.try_column_by_name("last_modified_on")?;
+    let row_of = |name: &str| {
+        (0..block.num_rows())
+            .find(|row| share_names.get(*row).to_string() == name)
+            .unwrap()
+    };
+
+    // The grant advances last_modified_on past created_on, to the update_on of the listed meta.
+    let row = row_of("share_modified");
+    assert!(last_modified_ons.get_i64(row)? > created_ons.get_i64(row)?);
+    let shares = meta_api.list_outbound_shares(ctx.get_tenant()).await?;
+    let (_reply, share_meta) = shares
+        .iter()
+        .find(|(reply, _share_meta)| reply.share_name.share_name == "share_modified")
+        .unwrap();
+    assert_eq!(
+        last_modified_ons.get_i64(row)?,
+        share_meta.update_on.unwrap().timestamp_micros()
+    );
+
+    // A share never modified shows its created_on.
+    let row = row_of("share_untouched");
+    assert_eq!(last_modified_ons.get_i64(row)?, created_ons.get_i64(row)?);
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_meta_types::StageFile;
+use common_meta_types::StageType;
+use common_meta_types::UserStageInfo;
+use databend_query::sessions::TableContext;
+use databend_query::storages::system::StageUsageTable;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_stage_usage_table() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+    let user_mgr = ctx.get_user_manager();
+
+    for stage_name in ["empty_stage", "test_stage"] {
+        let stage_info = UserStageInfo {
+            stage_name: stage_name.to_string(),
+            stage_type: StageType::Internal,
+            ..Default::default()
+        };
+        user_mgr.add_stage(&tenant, stage_info, false).await?;
+    }
+
+    for (path, size) in [("books.csv", 100), ("test/books.csv", 200)] {
+        let stage_file = StageFile {
+            path: path.to_string(),
+            size,
+            ..Default::default()
+        };
+        user_mgr.add_file(&tenant, "test_stage", stage_file).await?;
+    }
+
+    let table = StageUsageTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let expected = vec![
+        "+-------------+-------+-------+-------------------------------+",
+        "| stage_name  | files | bytes | last_modified                 |",
+        "+-------------+-------+-------+-------------------------------+",
+        "| empty_stage | 0     | 0     | NULL                          |",
+        "| test_stage  | 2     | 300   | 1970-01-01 00:00:00.000 +0000 |",
+        "+-------------+-------+-------+-------------------------------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
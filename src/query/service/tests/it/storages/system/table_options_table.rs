@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Extras;
+use databend_query::storages::system::TableOptionsTable;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_table_options_table() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(
+        ctx.clone(),
+        "create table default.t (a int) engine = Fuse compression = 'lz4' aws_secret_key = 'my_secret_key'",
+    )
+    .await?;
+
+    let table = TableOptionsTable::create(1);
+    let push_downs = Extras {
+        filters: vec![col("database")
+            .eq(lit("default".as_bytes()))
+            .and(col("table").eq(lit("t".as_bytes())))],
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let databases = block.try_column_by_name("database")?;
+    let tables = block.try_column_by_name("table")?;
+    let keys = block.try_column_by_name("option_key")?;
+    let values = block.try_column_by_name("option_value")?;
+    let rows = (0..block.num_rows())
+        .map(|row| {
+            Ok(format!(
+                "{}.{}.{}={}",
+                databases.get_checked(row)?,
+                tables.get_checked(row)?,
+                keys.get_checked(row)?,
+                values.get_checked(row)?
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // The database id is internal, the secret is redacted.
+    assert_eq!(rows, vec![
+        "default.t.aws_secret_key=******key",
+        "default.t.compression=lz4",
+    ]);
+
+    Ok(())
+}
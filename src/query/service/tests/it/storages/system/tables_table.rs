@@ -13,12 +13,21 @@
 // limitations under the License.
 
 use common_base::base::tokio;
+use common_datablocks::DataBlock;
 use common_exception::Result;
+use common_planners::col;
+use common_planners::lit;
+use common_planners::Extras;
+use databend_query::sessions::TableContext;
 use databend_query::storages::system::TablesTableWithoutHistory;
 use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
 use futures::TryStreamExt;
 
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+use crate::tests::SessionManagerBuilder;
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_tables_table() -> Result<()> {
     let ctx = crate::tests::create_query_context().await?;
@@ -28,7 +37,7 @@ async fn test_tables_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 10);
+    assert_eq!(block.num_columns(), 17);
 
     // check column "dropped_on"
     for x in &result {
@@ -48,37 +57,232 @@ async fn test_tables_table() -> Result<()> {
     }
 
     let expected = vec![
-        // r"\+--------------------\+---------------------\+--------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+",
-        r"\+--------------------\+---------------------\+--------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+",
-        r"\| database           \| name                \| engine             \| cluster_by \| created_on                    \| num_rows \| data_size \| data_compressed_size \| index_size \|",
-        r"\+--------------------\+---------------------\+--------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+",
-        r"\| INFORMATION_SCHEMA \| COLUMNS             \| VIEW               \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| INFORMATION_SCHEMA \| KEYWORDS            \| VIEW               \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| INFORMATION_SCHEMA \| SCHEMATA            \| VIEW               \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| INFORMATION_SCHEMA \| TABLES              \| VIEW               \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| INFORMATION_SCHEMA \| VIEWS               \| VIEW               \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| clusters            \| SystemClusters     \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| columns             \| SystemColumns      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| configs             \| SystemConfigs      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| contributors        \| SystemContributors \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| credits             \| SystemCredits      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| databases           \| SystemDatabases    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| engines             \| SystemEngines      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| functions           \| SystemFunctions    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| metrics             \| SystemMetrics      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| one                 \| SystemOne          \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| processes           \| SystemProcesses    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| query_log           \| SystemQueryLog     \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| roles               \| SystemRoles        \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| settings            \| SystemSettings     \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| tables              \| SystemTables       \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| tables_with_history \| SystemTables       \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| tracing             \| SystemTracing      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| users               \| SystemUsers        \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\| system             \| stages              \| SystemStages       \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \|",
-        r"\+--------------------\+---------------------\+--------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+",
+        // r"\+--------------------\+---------------------\+-------------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+---------\+-------------\+-------------------\+-------------\+------------------\+--------------------\+------------------\+",
+        r"\+--------------------\+---------------------\+-------------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+---------\+-------------\+-------------------\+-------------\+------------------\+--------------------\+------------------\+",
+        r"\| database           \| name                \| engine                  \| cluster_by \| created_on                    \| num_rows \| data_size \| data_compressed_size \| index_size \| catalog \| snapshot_id \| snapshot_location \| is_external \| storage_location \| number_of_segments \| number_of_blocks \|",
+        r"\+--------------------\+---------------------\+-------------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+---------\+-------------\+-------------------\+-------------\+------------------\+--------------------\+------------------\+",
+        r"\| INFORMATION_SCHEMA \| COLUMNS             \| VIEW                    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| INFORMATION_SCHEMA \| KEYWORDS            \| VIEW                    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| INFORMATION_SCHEMA \| SCHEMATA            \| VIEW                    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| INFORMATION_SCHEMA \| TABLES              \| VIEW                    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| INFORMATION_SCHEMA \| VIEWS               \| VIEW                    \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| clusters            \| SystemClusters          \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| clustering_history  \| SystemClusteringHistory \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| columns             \| SystemColumns           \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| configs             \| SystemConfigs           \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| contributors        \| SystemContributors      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| credits             \| SystemCredits           \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| databases           \| SystemDatabases         \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| engines             \| SystemEngines           \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| functions           \| SystemFunctions         \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| inbound_shares      \| SystemInboundShares     \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| metrics             \| SystemMetrics           \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| one                 \| SystemOne               \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| processes           \| SystemProcesses         \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| query_log           \| SystemQueryLog          \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| query_profile       \| SystemQueryProfile      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| roles               \| SystemRoles             \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| settings            \| SystemSettings          \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| shares              \| SystemShares            \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| share_privileges    \| SystemSharePrivileges   \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| table_options       \| SystemTableOptions      \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| tables              \| SystemTables            \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| tables_with_history \| SystemTables            \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| tracing             \| SystemTracing           \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| users               \| SystemUsers             \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| vacuum_history      \| SystemVacuumHistory     \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| stage_usage         \| SystemStageUsage        \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\| system             \| stages              \| SystemStages            \|            \| \d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}\.\d{3} [\+-]\d{4} \| NULL     \| NULL      \| NULL                 \| NULL       \| default \| NULL        \| NULL              \| false       \| NULL             \| NULL               \| NULL             \|",
+        r"\+--------------------\+---------------------\+-------------------------\+------------\+-------------------------------\+----------\+-----------\+----------------------\+------------\+---------\+-------------\+-------------------\+-------------\+------------------\+--------------------\+------------------\+",
     ];
     common_datablocks::assert_blocks_sorted_eq_with_regex(expected, without_dropped.as_slice());
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tables_table_catalogs() -> Result<()> {
+    let sessions = SessionManagerBuilder::create()
+        .immutable_catalog("other")
+        .build()?;
+    let ctx = crate::tests::create_query_context_with_session(sessions).await?;
+
+    let table = TablesTableWithoutHistory::create(1);
+    let read_catalogs = |push_downs: Option<Extras>| {
+        let ctx = ctx.clone();
+        let table = table.clone();
+        async move {
+            let source_plan = table.read_plan(ctx.clone(), push_downs).await?;
+            let stream = table.read(ctx, &source_plan).await?;
+            let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+            let catalogs = block.try_column_by_name("catalog")?;
+            let databases = block.try_column_by_name("database")?;
+            (0..block.num_rows())
+                .map(|row| {
+                    Ok((
+                        catalogs.get_checked(row)?.to_string(),
+                        databases.get_checked(row)?.to_string(),
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()
+        }
+    };
+
+    // Both catalogs are listed without filter.
+    let rows = read_catalogs(None).await?;
+    assert!(rows.iter().any(|(catalog, _)| catalog == "default"));
+    assert!(rows.iter().any(|(catalog, _)| catalog == "other"));
+
+    // Only the tables of the immutable databases are in the other catalog.
+    let rows = read_catalogs(Some(Extras {
+        filters: vec![col("catalog").eq(lit("other".as_bytes()))],
+        ..Extras::default()
+    }))
+    .await?;
+    assert!(!rows.is_empty());
+    for (catalog, database) in rows {
+        assert_eq!(catalog, "other");
+        assert!(database == "system" || database == "INFORMATION_SCHEMA");
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tables_table_snapshot() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(ctx.clone(), "create table default.t (a int) engine = Fuse").await?;
+    execute_command(ctx.clone(), "insert into default.t values (1), (2)").await?;
+
+    let table = TablesTableWithoutHistory::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let databases = block.try_column_by_name("database")?;
+    let names = block.try_column_by_name("name")?;
+    let snapshot_ids = block.try_column_by_name("snapshot_id")?;
+    let snapshot_locations = block.try_column_by_name("snapshot_location")?;
+    let row = (0..block.num_rows())
+        .find(|row| {
+            databases.get(*row).to_string() == "default" && names.get(*row).to_string() == "t"
+        })
+        .unwrap();
+
+    // The snapshot is named after its id.
+    let snapshot_id = snapshot_ids.get_checked(row)?;
+    let snapshot_location = snapshot_locations.get_checked(row)?;
+    assert!(!snapshot_id.is_null());
+    assert!(snapshot_location
+        .to_string()
+        .contains(&format!("/_ss/{}", snapshot_id)));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tables_table_storage_location() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(ctx.clone(), "create table default.t (a int) engine = Fuse").await?;
+    execute_command(
+        ctx.clone(),
+        "create table default.t_ext (a int) engine = Fuse location = 's3://my_key:my_secret@bucket/path/'",
+    )
+    .await?;
+
+    let table = TablesTableWithoutHistory::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let databases = block.try_column_by_name("database")?;
+    let names = block.try_column_by_name("name")?;
+    let is_externals = block.try_column_by_name("is_external")?;
+    let storage_locations = block.try_column_by_name("storage_location")?;
+    let storage_of = |name: &str| {
+        let row = (0..block.num_rows())
+            .find(|row| {
+                databases.get(*row).to_string() == "default" && names.get(*row).to_string() == name
+            })
+            .unwrap();
+        (
+            is_externals.get(row).to_string(),
+            storage_locations.get(row).to_string(),
+        )
+    };
+
+    // A managed table is stored internally.
+    assert_eq!(storage_of("t"), ("false".to_string(), "NULL".to_string()));
+
+    // The credentials in the location are redacted.
+    assert_eq!(
+        storage_of("t_ext"),
+        ("true".to_string(), "s3://******@bucket/path/".to_string())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tables_table_segments_and_blocks() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    execute_command(ctx.clone(), "create table default.t (a int) engine = Fuse").await?;
+    execute_command(
+        ctx.clone(),
+        "create table default.t_empty (a int) engine = Fuse",
+    )
+    .await?;
+    // Each insert appends a segment of one block.
+    execute_command(ctx.clone(), "insert into default.t values (1), (2)").await?;
+    execute_command(ctx.clone(), "insert into default.t values (3)").await?;
+
+    let table = TablesTableWithoutHistory::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx.clone(), &source_plan).await?;
+    let block = DataBlock::concat_blocks(&stream.try_collect::<Vec<_>>().await?)?;
+
+    let databases = block.try_column_by_name("database")?;
+    let names = block.try_column_by_name("name")?;
+    let segments = block.try_column_by_name("number_of_segments")?;
+    let blocks = block.try_column_by_name("number_of_blocks")?;
+    let counts_of = |database: &str, name: &str| {
+        let row = (0..block.num_rows())
+            .find(|row| {
+                databases.get(*row).to_string() == database && names.get(*row).to_string() == name
+            })
+            .unwrap();
+        (segments.get(row).to_string(), blocks.get(row).to_string())
+    };
+
+    assert_eq!(
+        counts_of("default", "t"),
+        ("2".to_string(), "2".to_string())
+    );
+
+    // The counts are kept in the table meta when the snapshot is committed.
+    let tbl = ctx
+        .get_catalog("default")?
+        .get_table(fixture.default_tenant().as_str(), "default", "t")
+        .await?;
+    let statistics = &tbl.get_table_info().meta.statistics;
+    assert_eq!(statistics.number_of_segments, Some(2));
+    assert_eq!(statistics.number_of_blocks, Some(2));
+
+    // A fuse table never written has no segments.
+    assert_eq!(
+        counts_of("default", "t_empty"),
+        ("0".to_string(), "0".to_string())
+    );
+
+    // The other engines have no snapshot.
+    assert_eq!(
+        counts_of("system", "tables"),
+        ("NULL".to_string(), "NULL".to_string())
+    );
+
+    Ok(())
+}
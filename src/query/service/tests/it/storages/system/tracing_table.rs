@@ -12,16 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::VecDeque;
+use std::fs::OpenOptions;
+use std::io::Write;
 use std::sync::Arc;
+use std::time::Duration;
 
 use common_base::base::tokio;
+use common_datablocks::DataBlock;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
 use common_exception::Result;
+use common_planners::Expression;
+use common_planners::Extras;
+use databend_query::sessions::TableContext;
 use databend_query::storages::system::TracingTable;
+use databend_query::storages::system::TracingTableStream;
 use databend_query::storages::Table;
 use databend_query::storages::TableStreamReadWrap;
 use databend_query::storages::ToReadDataSourcePlan;
+use databend_query::table_functions::TracingFollowTable;
+use futures::StreamExt;
 use futures::TryStreamExt;
 
+use crate::storages::system::remote_nodes::create_cluster_context;
+use crate::storages::system::remote_nodes::MockRemoteNodeReader;
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_tracing_table() -> Result<()> {
     let ctx = crate::tests::create_query_context().await?;
@@ -31,8 +47,263 @@ async fn test_tracing_table() -> Result<()> {
     let stream = table.read(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 7);
+    assert_eq!(block.num_columns(), 9);
     assert!(block.num_rows() > 0);
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tracing_follow_table_function() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let table = TracingFollowTable::create("system", "tracing_follow", 1, None)?.as_table();
+    assert_eq!(table.schema(), TracingTable::create(1).schema());
+
+    // The follow mode streams until the pushed down limit is reached.
+    let push_downs = Extras {
+        limit: Some(1),
+        ..Extras::default()
+    };
+    let source_plan = table.read_plan(ctx.clone(), Some(push_downs)).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+    assert_eq!(block.num_rows(), 1);
+
+    // It takes no arguments.
+    let args = Some(vec![Expression::create_literal(DataValue::UInt64(1))]);
+    let res = TracingFollowTable::create("system", "tracing_follow", 1, args);
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::BadArguments("").code())
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tracing_table_fields() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let log_file = dir.path().join("databend-query.log");
+    std::fs::write(
+        &log_file,
+        concat!(
+            r#"{"v":0,"name":"databend-query","msg":"query started","level":30,"hostname":"databend","pid":1,"time":"2022-07-01T00:00:00.000000000+00:00","target":"databend_query::interpreters","query_id":"q-1","rows":3}"#,
+            "\n",
+            "plain text line\n",
+        ),
+    )?;
+
+    let table = TracingTable::create(1);
+    let log_files = VecDeque::from(vec![log_file.display().to_string()]);
+    let stream = TracingTableStream::try_create(table.schema(), log_files, usize::MAX)?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+    assert_eq!(block.num_rows(), 2);
+
+    let msgs = block.try_column_by_name("msg")?;
+    let fields = block.try_column_by_name("fields")?;
+    let times = block.try_column_by_name("time")?;
+
+    // The extra fields of the structured event are kept.
+    assert_eq!(msgs.get_checked(0)?.to_string(), "query started");
+    // 2022-07-01T00:00:00Z in microseconds.
+    assert_eq!(times.get_checked(0)?, DataValue::Int64(1656633600000000));
+    let value: serde_json::Value = serde_json::from_str(&fields.get_checked(0)?.to_string())?;
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "target": "databend_query::interpreters",
+            "query_id": "q-1",
+            "rows": 3,
+        })
+    );
+
+    // A plain text line has no structured fields.
+    assert_eq!(msgs.get_checked(1)?.to_string(), "plain text line");
+    assert_eq!(fields.get_checked(1)?.to_string(), "{}");
+    assert!(times.get_checked(1)?.is_null());
+
+    Ok(())
+}
+
+fn log_line(msg: &str) -> String {
+    format!(
+        r#"{{"v":0,"name":"databend-query","msg":"{}","level":20,"hostname":"databend","pid":1,"time":"2022-07-01T00:00:00.000000000+00:00"}}"#,
+        msg
+    )
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tracing_table_stream_follow() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let log_file = dir.path().join("databend-query.log");
+    std::fs::write(&log_file, format!("{}\n", log_line("existing")))?;
+
+    let table = TracingTable::create(1);
+    let log_files = VecDeque::from(vec![log_file.display().to_string()]);
+    let mut stream = TracingTableStream::try_create(table.schema(), log_files, 3)?
+        .with_follow(Duration::from_millis(10));
+
+    let block = stream.next().await.unwrap()?;
+    assert_eq!(block.num_rows(), 1);
+
+    // Append lines after the stream started, the second one is written in two parts.
+    let appended = log_line("appended");
+    let (head, tail) = appended.split_at(appended.len() / 2);
+    let writer = tokio::spawn({
+        let log_file = log_file.clone();
+        let head = head.to_string();
+        let tail = format!("{}\n{}\n", tail, log_line("appended again"));
+        async move {
+            let mut file = OpenOptions::new().append(true).open(log_file).unwrap();
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            file.write_all(head.as_bytes()).unwrap();
+            file.flush().unwrap();
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            file.write_all(tail.as_bytes()).unwrap();
+        }
+    });
+
+    let mut msgs = vec![];
+    while let Some(block) = stream.next().await {
+        let block = block?;
+        let column = block.try_column_by_name("msg")?;
+        for row in 0..block.num_rows() {
+            msgs.push(column.get_checked(row)?.to_string());
+        }
+    }
+    writer.await.unwrap();
+
+    // The stream ends once the limit is reached.
+    assert_eq!(msgs, vec!["appended", "appended again"]);
+
+    Ok(())
+}
+
+fn write_log_lines(path: &std::path::Path, msgs: &[&str]) -> Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for msg in msgs {
+        writeln!(file, "{}", log_line(msg))?;
+    }
+    Ok(())
+}
+
+fn block_msgs(block: &DataBlock) -> Result<Vec<String>> {
+    let column = block.try_column_by_name("msg")?;
+    (0..block.num_rows())
+        .map(|row| Ok(column.get_checked(row)?.to_string()))
+        .collect()
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tracing_table_stream_rotation() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let log_file = dir.path().join("databend-query.log");
+    let schema = TracingTable::create(1).schema();
+
+    // Rotated after the stream is created, before the lines are read.
+    {
+        write_log_lines(&log_file, &["1", "2"])?;
+        let log_files = VecDeque::from(vec![log_file.display().to_string()]);
+        let stream = TracingTableStream::try_create(schema.clone(), log_files, usize::MAX)?;
+
+        write_log_lines(&log_file, &["3"])?;
+        std::fs::rename(&log_file, dir.path().join("databend-query.log.1"))?;
+        write_log_lines(&log_file, &["4", "5"])?;
+
+        let mut msgs = vec![];
+        for block in stream.try_collect::<Vec<_>>().await? {
+            msgs.extend(block_msgs(&block)?);
+        }
+        assert_eq!(msgs, vec!["1", "2", "3", "4", "5"]);
+    }
+
+    // Rotated while following the active file.
+    {
+        std::fs::remove_file(&log_file)?;
+        write_log_lines(&log_file, &["1", "2"])?;
+        let log_files = VecDeque::from(vec![log_file.display().to_string()]);
+        let mut stream = TracingTableStream::try_create(schema, log_files, 6)?
+            .with_follow(Duration::from_millis(10));
+
+        let mut msgs = block_msgs(&stream.next().await.unwrap()?)?;
+        assert_eq!(msgs, vec!["1", "2"]);
+
+        write_log_lines(&log_file, &["3"])?;
+        msgs.extend(block_msgs(&stream.next().await.unwrap()?)?);
+
+        // The last line of the rotated file is written after its last read.
+        write_log_lines(&log_file, &["4"])?;
+        std::fs::rename(&log_file, dir.path().join("databend-query.log.2"))?;
+        write_log_lines(&log_file, &["5", "6"])?;
+
+        while let Some(block) = stream.next().await {
+            msgs.extend(block_msgs(&block?)?);
+        }
+        assert_eq!(msgs, vec!["1", "2", "3", "4", "5", "6"]);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tracing_table_stream_rotation_mid_read() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let log_file = dir.path().join("databend-query.log");
+    let schema = TracingTable::create(1).schema();
+
+    // Read in blocks of two lines without the follow mode, the same as system.tracing.
+    write_log_lines(&log_file, &["1", "2", "3"])?;
+    let log_files = VecDeque::from(vec![log_file.display().to_string()]);
+    let mut stream =
+        TracingTableStream::try_create(schema, log_files, usize::MAX)?.with_max_block_size(2);
+
+    let mut msgs = block_msgs(&stream.next().await.unwrap()?)?;
+    assert_eq!(msgs, vec!["1", "2"]);
+
+    // Rotated between two blocks, with a line written just before the rotation.
+    write_log_lines(&log_file, &["4"])?;
+    std::fs::rename(&log_file, dir.path().join("databend-query.log.1"))?;
+    write_log_lines(&log_file, &["5"])?;
+
+    while let Some(block) = stream.next().await {
+        let block = block?;
+        assert!(block.num_rows() <= 2);
+        msgs.extend(block_msgs(&block)?);
+    }
+    assert_eq!(msgs, vec!["1", "2", "3", "4", "5"]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_tracing_table_cluster() -> Result<()> {
+    let ctx = create_cluster_context().await?;
+    ctx.get_settings().set_tracing_cluster(true)?;
+
+    let table: Arc<dyn Table> =
+        Arc::new(TracingTable::create(1).with_remote_reader(Arc::new(MockRemoteNodeReader)));
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = DataBlock::concat_blocks(&result)?;
+
+    let msgs = block.try_column_by_name("msg")?;
+    let nodes = block.try_column_by_name("node")?;
+    let mut local_rows = 0;
+    let mut remote_msgs = vec![];
+    for row in 0..block.num_rows() {
+        match nodes.get_checked(row)?.to_string().as_str() {
+            "node1" => local_rows += 1,
+            "node2" => remote_msgs.push(msgs.get_checked(row)?.to_string()),
+            node => panic!("unexpected node {}", node),
+        }
+    }
+
+    // The rows of the unreachable node are omitted.
+    assert!(local_rows > 0);
+    assert_eq!(remote_msgs, vec!["from node2", "from node2 again"]);
+
+    Ok(())
+}
@@ -27,6 +27,7 @@ use databend_query::clusters::ClusterHelper;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::QueryContextShared;
 use databend_query::sessions::SessionManager;
+use databend_query::sessions::SessionRef;
 use databend_query::sessions::SessionType;
 use databend_query::sessions::TableContext;
 use databend_query::storages::StorageContext;
@@ -64,6 +65,29 @@ pub async fn create_query_context_with_session(
     Ok(context)
 }
 
+/// Creates a query context attached to its session, as the one of a running query.
+///
+/// The session is listed by the session manager as long as the returned ref is alive.
+pub async fn create_attached_query_context() -> Result<(SessionRef, Arc<QueryContext>)> {
+    let sessions = SessionManagerBuilder::create().build()?;
+    let session = sessions.create_session(SessionType::Dummy).await?;
+
+    // Set user with all privileges
+    let mut user_info = UserInfo::new("root", "127.0.0.1", AuthInfo::Password {
+        hash_method: PasswordHashMethod::Sha256,
+        hash_value: Vec::from("pass"),
+    });
+    user_info.grants.grant_privileges(
+        &GrantObject::Global,
+        UserPrivilegeSet::available_privileges_on_global(),
+    );
+    session.set_current_user(user_info);
+
+    let context = session.create_query_context().await?;
+    context.get_settings().set_max_threads(8)?;
+    Ok((session, context))
+}
+
 pub async fn create_query_context_with_config(
     config: Config,
     current_user: Option<UserInfo>,
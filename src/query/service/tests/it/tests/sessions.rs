@@ -17,24 +17,39 @@ use std::sync::Arc;
 use common_base::base::tokio::runtime::Runtime;
 use common_base::base::Thread;
 use common_exception::Result;
+use databend_query::catalogs::default::ImmutableCatalog;
+use databend_query::catalogs::Catalog;
 use databend_query::sessions::SessionManager;
 use databend_query::Config;
 
-async fn async_create_sessions(config: Config) -> Result<Arc<SessionManager>> {
-    let sessions = SessionManager::from_conf(config.clone()).await?;
+async fn async_create_sessions(
+    config: Config,
+    immutable_catalogs: Vec<String>,
+) -> Result<Arc<SessionManager>> {
+    let mut catalogs = Vec::with_capacity(immutable_catalogs.len());
+    for name in immutable_catalogs {
+        let catalog: Arc<dyn Catalog> =
+            Arc::new(ImmutableCatalog::try_create_with_config(&config).await?);
+        catalogs.push((name, catalog));
+    }
+    let sessions = SessionManager::from_conf_with_catalogs(config.clone(), catalogs).await?;
 
     let cluster_discovery = sessions.get_cluster_discovery();
     cluster_discovery.register_to_metastore(&config).await?;
     Ok(sessions)
 }
 
-fn sync_create_sessions(config: Config) -> Result<Arc<SessionManager>> {
+fn sync_create_sessions(
+    config: Config,
+    immutable_catalogs: Vec<String>,
+) -> Result<Arc<SessionManager>> {
     let runtime = Runtime::new()?;
-    runtime.block_on(async_create_sessions(config))
+    runtime.block_on(async_create_sessions(config, immutable_catalogs))
 }
 
 pub struct SessionManagerBuilder {
     config: Config,
+    immutable_catalogs: Vec<String>,
 }
 
 impl SessionManagerBuilder {
@@ -45,49 +60,79 @@ impl SessionManagerBuilder {
     }
 
     pub fn create_with_conf(config: Config) -> SessionManagerBuilder {
-        SessionManagerBuilder { config }
+        SessionManagerBuilder {
+            config,
+            immutable_catalogs: vec![],
+        }
+    }
+
+    /// Adds a catalog of the immutable databases, `system` and `INFORMATION_SCHEMA`, named `name`.
+    pub fn immutable_catalog(mut self, name: impl Into<String>) -> SessionManagerBuilder {
+        self.immutable_catalogs.push(name.into());
+        self
     }
 
     pub fn max_sessions(self, max_sessions: u64) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.max_active_sessions = max_sessions;
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn rpc_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.rpc_tls_server_key = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn rpc_tls_server_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.rpc_tls_server_cert = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn jwt_key_file(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.jwt_key_file = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn http_handler_result_time_out(self, value: impl Into<u64>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.http_handler_result_timeout_millis = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn http_handler_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.http_handler_tls_server_key = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn http_handler_tls_server_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.http_handler_tls_server_cert = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn http_handler_tls_server_root_ca_cert(
@@ -96,30 +141,43 @@ impl SessionManagerBuilder {
     ) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.http_handler_tls_server_root_ca_cert = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn api_tls_server_key(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.api_tls_server_key = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn api_tls_server_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.api_tls_server_cert = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn api_tls_server_root_ca_cert(self, value: impl Into<String>) -> SessionManagerBuilder {
         let mut new_config = self.config;
         new_config.query.api_tls_server_root_ca_cert = value.into();
-        SessionManagerBuilder::create_with_conf(new_config)
+        SessionManagerBuilder {
+            config: new_config,
+            ..self
+        }
     }
 
     pub fn build(self) -> Result<Arc<SessionManager>> {
         let config = self.config;
-        let handle = Thread::spawn(move || sync_create_sessions(config));
+        let immutable_catalogs = self.immutable_catalogs;
+        let handle = Thread::spawn(move || sync_create_sessions(config, immutable_catalogs));
         handle.join().unwrap()
     }
 }